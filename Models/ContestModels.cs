@@ -256,6 +256,35 @@ public sealed class Award : IHasId
     public string Citation { get; set; } = string.Empty;
 
     [JsonPropertyName("team_ids")] public List<string> TeamIds { get; set; } = [];
+
+    /// <summary>
+    ///     Problem a first-to-solve award is for, so the award overlay can show its label and balloon.
+    /// </summary>
+    [JsonPropertyName("problem_id")]
+    public string? ProblemId { get; set; }
+
+    /// <summary>
+    ///     Photo, or folder of photos shown as a slideshow, used instead of <c>teams/&lt;id&gt;</c>. Relative paths are
+    ///     resolved against the CDP folder.
+    /// </summary>
+    [JsonPropertyName("media_path")]
+    public string? MediaPath { get; set; }
+
+    /// <summary>
+    ///     Hex color of the overlay's info banner.
+    /// </summary>
+    [JsonPropertyName("banner_color")]
+    public string? BannerColor { get; set; }
+
+    /// <summary>
+    ///     Heading shown above the team name on the overlay, e.g. the sponsor of the award.
+    /// </summary>
+    public string? Title { get; set; }
+
+    /// <summary>
+    ///     Position of the award when a team has several; lower comes first, ties fall back to <see cref="Id" />.
+    /// </summary>
+    public int Order { get; set; }
 }
 
 public sealed class Contest
@@ -323,6 +352,15 @@ public sealed class ContestState
     [JsonPropertyName("leaderboard_finalized")]
     public List<TeamStatus> LeaderboardFinalized { get; set; } = [];
 
+    [JsonPropertyName("scoreboard_hash")] public string ScoreboardHash { get; set; } = string.Empty;
+
+    /// <summary>
+    ///     Teams shown on the board whose groups are not eligible for medals. They are greyed out and skipped by the
+    ///     medal zone.
+    /// </summary>
+    [JsonPropertyName("unofficial_team_ids")]
+    public HashSet<string> UnofficialTeamIds { get; set; } = new(StringComparer.Ordinal);
+
     public static ContestState New() => new();
 }
 
@@ -392,6 +430,10 @@ public sealed class TeamStatus : IComparable<TeamStatus>
             }
 
             problemStat.AttemptedDuringFreeze = submissionTime > contestFreezeTime.Value;
+            if (problemStat.AttemptedDuringFreeze)
+            {
+                problemStat.SubmissionsDuringFreeze += 1;
+            }
 
             if (contestStartTime is null)
             {
@@ -484,6 +526,12 @@ public sealed class ProblemStat
     [JsonPropertyName("submissions_before_solved")]
     public int SubmissionsBeforeSolved { get; set; }
 
+    /// <summary>
+    ///     The part of <see cref="SubmissionsBeforeSolved" /> made after the freeze.
+    /// </summary>
+    [JsonPropertyName("submissions_during_freeze")]
+    public int SubmissionsDuringFreeze { get; set; }
+
     [JsonPropertyName("first_ac_time")]
     [JsonConverter(typeof(OptionalDateTimeOffsetConverter))]
     public DateTimeOffset? FirstAcTime { get; set; }
@@ -1,18 +1,56 @@
+using System;
 using System.Collections.Generic;
+using System.Diagnostics;
+using System.Globalization;
+using System.Linq;
 using Tomlyn.Model;
 
 namespace Pyrite.Models;
 
 public sealed class PyriteConfig
 {
+    public string Language { get; set; } = "en";
     public List<string> FilterTeamSubmissions { get; set; } = [];
+    public List<string> HiddenProblems { get; set; } = [];
     public Dictionary<string, string> TeamGroupMap { get; set; } = [];
+
+    /// <summary>
+    ///     Team id to the name shown for it from <c>[team_names]</c>, e.g. to shorten a long official name. Wins over
+    ///     the feed's <c>name</c> and <c>display_name</c>.
+    /// </summary>
+    public Dictionary<string, string> TeamNames { get; set; } = [];
+
+    /// <summary>
+    ///     Group id to the name shown for the category from <c>[group_names]</c>.
+    /// </summary>
+    public Dictionary<string, string> GroupNames { get; set; } = [];
+
+    /// <summary>
+    ///     Named sets of eligible group ids from <c>[group_presets]</c>, offered in the medal setup.
+    /// </summary>
+    public Dictionary<string, List<string>> GroupPresets { get; set; } = [];
     public PresentationConfig Presentation { get; set; } = new();
+    public CertificateConfig Certificates { get; set; } = new();
+    public WebhookConfig Webhook { get; set; } = new();
+    public ContestApiConfig ContestApi { get; set; } = new();
+    public FontsConfig Fonts { get; set; } = new();
+    public AwardsConfig Awards { get; set; } = new();
+    public KeysConfig Keys { get; set; } = new();
 
     public static PyriteConfig Default()
     {
         return new PyriteConfig();
     }
+
+    /// <summary>
+    ///     Logs an invalid setting and returns <paramref name="fallback" />. Like <c>[presentation.colors]</c>, a bad
+    ///     value keeps its default instead of refusing the whole file; only TOML syntax errors do that.
+    /// </summary>
+    internal static T KeepDefault<T>(T fallback, string message)
+    {
+        Trace.WriteLine($"[PyriteConfig] {message} Using the default.");
+        return fallback;
+    }
 }
 
 public sealed class PresentationConfig
@@ -24,6 +62,75 @@ public sealed class PresentationConfig
     public string TeamPhotoExtension { get; set; } = "jpg";
     public string? TeamPhotoFallbackPath { get; set; }
 
+    /// <summary>
+    ///     Logo shown for organizations without one of their own, from <c>logo_fallback_path</c>.
+    /// </summary>
+    public string? LogoFallbackPath { get; set; }
+
+    /// <summary>
+    ///     Organization id to the logo shown when its own is missing, from <c>[presentation.logo_fallbacks]</c>. Wins
+    ///     over <see cref="LogoFallbackPath" />.
+    /// </summary>
+    public Dictionary<string, string> LogoFallbacks { get; set; } = [];
+    public int? WindowWidth { get; set; }
+    public int? WindowHeight { get; set; }
+    public bool WindowResizable { get; set; } = true;
+    public int? Monitor { get; set; }
+    public int? MirrorMonitor { get; set; }
+    public int? PresentTopTeams { get; set; }
+    public int? StartRank { get; set; }
+    public RevealOrder RevealOrder { get; set; } = RevealOrder.LeftToRight;
+    public bool ShowProblemSolveCounts { get; set; } = true;
+    public bool ChampionCelebration { get; set; } = true;
+    public bool SummaryScreen { get; set; }
+    public float PendingPulseSeconds { get; set; } = 1.5f;
+    public float CellFlipSeconds { get; set; } = 0.5f;
+    public float FocusPulseSeconds { get; set; } = 1.6f;
+    public float RankDeltaSeconds { get; set; } = 3f;
+    public bool ShowCountryFlags { get; set; } = true;
+    public OrganizationSubline OrganizationSubline { get; set; } = OrganizationSubline.FormalName;
+    public ProblemCellNotation CellNotation { get; set; } = ProblemCellNotation.AttemptsTime;
+    public bool MedalTintRows { get; set; } = true;
+    public float AwardFadeSeconds { get; set; } = 0.4f;
+    public float AwardKenBurnsSeconds { get; set; } = 12f;
+    public float AwardKenBurnsZoom { get; set; } = 1.15f;
+    public float AwardPhotoSeconds { get; set; } = 4f;
+    public InfoBarPosition InfoBar { get; set; } = InfoBarPosition.None;
+    public string? InfoBarLogoPath { get; set; }
+    public string? HoldingSlidePath { get; set; }
+    public List<string> IntroSlides { get; set; } = [];
+    public int CountdownSeconds { get; set; }
+    public string? CountdownText { get; set; }
+    public string? BackgroundImagePath { get; set; }
+    public float RowOpacity { get; set; } = 1f;
+
+    /// <summary>
+    ///     Starting stage zoom from <c>ui_scale</c>; ignored when <see cref="UiScaleAuto" /> is set.
+    /// </summary>
+    public float UiScale { get; set; } = 1f;
+
+    /// <summary>
+    ///     <c>ui_scale = "auto"</c>: size the stage to the window, taking 1920x1080 logical pixels as 100%.
+    /// </summary>
+    public bool UiScaleAuto { get; set; }
+
+    /// <summary>
+    ///     Memory budget for decoded logos in megabytes; logos of rows far off screen are evicted beyond it.
+    /// </summary>
+    public int ImageCacheMegabytes { get; set; } = 64;
+    public string ScreenshotDirectory { get; set; } = "screenshots";
+    public string RecordingDirectory { get; set; } = "recordings";
+    public int RecordingFramesPerSecond { get; set; } = 60;
+    public string? RecordingFfmpegPath { get; set; }
+    public bool ShowTeamLabels { get; set; }
+    public bool PreferTeamDisplayName { get; set; }
+    public bool MarqueeTeamNames { get; set; } = true;
+    public ColorPalette Palette { get; set; } = ColorPalette.Default;
+    public AutopilotConfig Autopilot { get; set; } = new();
+    public PhaseTimingConfig Timing { get; set; } = new();
+    public PresentationColorsConfig Colors { get; set; } = new();
+    public SoundConfig Sounds { get; set; } = new();
+
     public static PresentationConfig FromToml(TomlTable table)
     {
         var config = new PresentationConfig();
@@ -48,10 +155,219 @@ public sealed class PresentationConfig
         if (table.TryGetValue("team_photo_fallback_path", out var fallbackPath) && fallbackPath is string fallback)
             config.TeamPhotoFallbackPath = fallback;
 
+        if (table.TryGetValue("logo_fallback_path", out var logoFallbackPath) && logoFallbackPath is string logoFallback)
+            config.LogoFallbackPath = logoFallback;
+
+        if (table.TryGetValue("logo_fallbacks", out var logoFallbacksObject) && logoFallbacksObject is TomlTable logoFallbacksTable)
+            foreach (var kv in logoFallbacksTable)
+                if (kv.Value is string organizationLogo && !string.IsNullOrWhiteSpace(organizationLogo))
+                    config.LogoFallbacks[kv.Key] = organizationLogo.Trim();
+
+        if (table.TryGetValue("window_width", out var windowWidth) && windowWidth is long width && width > 0)
+            config.WindowWidth = (int)width;
+
+        if (table.TryGetValue("window_height", out var windowHeight) && windowHeight is long height && height > 0)
+            config.WindowHeight = (int)height;
+
+        if (table.TryGetValue("window_resizable", out var windowResizable) && windowResizable is bool resizable)
+            config.WindowResizable = resizable;
+
+        if (table.TryGetValue("monitor", out var monitor) && monitor is long monitorIndex && monitorIndex >= 0)
+            config.Monitor = (int)monitorIndex;
+
+        if (table.TryGetValue("mirror_monitor", out var mirrorMonitor) && mirrorMonitor is long mirrorMonitorIndex &&
+            mirrorMonitorIndex >= 0)
+            config.MirrorMonitor = (int)mirrorMonitorIndex;
+
+        if (table.TryGetValue("present_top_teams", out var presentTop) && presentTop is long topTeams && topTeams > 0)
+            config.PresentTopTeams = (int)topTeams;
+
+        if (table.TryGetValue("start_rank", out var startRank) && startRank is long startRankValue && startRankValue > 0)
+            config.StartRank = (int)startRankValue;
+
+        if (table.TryGetValue("reveal_order", out var revealOrder))
+            config.RevealOrder = revealOrder switch
+            {
+                "left_to_right" => RevealOrder.LeftToRight,
+                "least_impact" => RevealOrder.LeastImpact,
+                "submission_order" => RevealOrder.SubmissionOrder,
+                "operator" => RevealOrder.Operator,
+                _ => PyriteConfig.KeepDefault(config.RevealOrder,
+                    $"Invalid presentation.reveal_order '{revealOrder}'; expected left_to_right, least_impact, submission_order, or operator.")
+            };
+
+        if (table.TryGetValue("show_problem_solve_counts", out var showSolveCounts) && showSolveCounts is bool showCounts)
+            config.ShowProblemSolveCounts = showCounts;
+
+        if (table.TryGetValue("champion_celebration", out var championCelebration) && championCelebration is bool celebrate)
+            config.ChampionCelebration = celebrate;
+
+        if (table.TryGetValue("summary_screen", out var summaryScreen) && summaryScreen is bool showSummary)
+            config.SummaryScreen = showSummary;
+
+        if (table.TryGetValue("pending_pulse_seconds", out var pendingPulse))
+            config.PendingPulseSeconds = Math.Max(0, ConvertToFloat(pendingPulse, config.PendingPulseSeconds));
+
+        if (table.TryGetValue("cell_flip_seconds", out var cellFlip))
+            config.CellFlipSeconds = Math.Max(0, ConvertToFloat(cellFlip, config.CellFlipSeconds));
+
+        if (table.TryGetValue("focus_pulse_seconds", out var focusPulse))
+            config.FocusPulseSeconds = Math.Max(0, ConvertToFloat(focusPulse, config.FocusPulseSeconds));
+
+        if (table.TryGetValue("rank_delta_seconds", out var rankDelta))
+            config.RankDeltaSeconds = Math.Max(0, ConvertToFloat(rankDelta, config.RankDeltaSeconds));
+
+        if (table.TryGetValue("show_country_flags", out var showCountryFlags) && showCountryFlags is bool showFlags)
+            config.ShowCountryFlags = showFlags;
+
+        if (table.TryGetValue("screenshot_dir", out var screenshotDir) && screenshotDir is string screenshotDirectory &&
+            !string.IsNullOrWhiteSpace(screenshotDirectory))
+            config.ScreenshotDirectory = screenshotDirectory;
+
+        if (table.TryGetValue("recording_dir", out var recordingDir) && recordingDir is string recordingDirectory &&
+            !string.IsNullOrWhiteSpace(recordingDirectory))
+            config.RecordingDirectory = recordingDirectory;
+
+        if (table.TryGetValue("recording_fps", out var recordingFps) && recordingFps is long framesPerSecond)
+            config.RecordingFramesPerSecond = (int)Math.Clamp(framesPerSecond, 1, 240);
+
+        if (table.TryGetValue("recording_ffmpeg_path", out var ffmpeg) && ffmpeg is string ffmpegPath &&
+            !string.IsNullOrWhiteSpace(ffmpegPath))
+            config.RecordingFfmpegPath = ffmpegPath;
+
+        if (table.TryGetValue("show_team_labels", out var showTeamLabels) && showTeamLabels is bool showLabels)
+            config.ShowTeamLabels = showLabels;
+
+        if (table.TryGetValue("prefer_team_display_name", out var preferDisplayName) && preferDisplayName is bool preferDisplay)
+            config.PreferTeamDisplayName = preferDisplay;
+
+        if (table.TryGetValue("marquee_team_names", out var marqueeTeamNames) && marqueeTeamNames is bool marquee)
+            config.MarqueeTeamNames = marquee;
+
+        if (table.TryGetValue("organization_subline", out var subline))
+            config.OrganizationSubline = subline switch
+            {
+                "formal_name" => OrganizationSubline.FormalName,
+                "shortname" => OrganizationSubline.Shortname,
+                "name" => OrganizationSubline.Name,
+                "none" => OrganizationSubline.None,
+                _ => PyriteConfig.KeepDefault(config.OrganizationSubline,
+                    $"Invalid presentation.organization_subline '{subline}'; expected formal_name, shortname, name, or none.")
+            };
+
+        if (table.TryGetValue("cell_notation", out var cellNotation))
+            config.CellNotation = cellNotation switch
+            {
+                "attempts_time" => ProblemCellNotation.AttemptsTime,
+                "classic" => ProblemCellNotation.Classic,
+                _ => PyriteConfig.KeepDefault(config.CellNotation,
+                    $"Invalid presentation.cell_notation '{cellNotation}'; expected attempts_time or classic.")
+            };
+
+        if (table.TryGetValue("medal_tint_rows", out var medalTintRows) && medalTintRows is bool tintRows)
+            config.MedalTintRows = tintRows;
+
+        if (table.TryGetValue("award_fade_seconds", out var awardFade))
+            config.AwardFadeSeconds = Math.Max(0, ConvertToFloat(awardFade, config.AwardFadeSeconds));
+
+        if (table.TryGetValue("award_ken_burns_seconds", out var kenBurns))
+            config.AwardKenBurnsSeconds = Math.Max(0, ConvertToFloat(kenBurns, config.AwardKenBurnsSeconds));
+
+        if (table.TryGetValue("award_ken_burns_zoom", out var kenBurnsZoom))
+            config.AwardKenBurnsZoom = Math.Max(1, ConvertToFloat(kenBurnsZoom, config.AwardKenBurnsZoom));
+
+        if (table.TryGetValue("award_photo_seconds", out var awardPhoto))
+            config.AwardPhotoSeconds = Math.Max(0.5f, ConvertToFloat(awardPhoto, config.AwardPhotoSeconds));
+
+        if (table.TryGetValue("info_bar", out var infoBar))
+            config.InfoBar = infoBar switch
+            {
+                "none" => InfoBarPosition.None,
+                "top" => InfoBarPosition.Top,
+                "bottom" => InfoBarPosition.Bottom,
+                _ => PyriteConfig.KeepDefault(config.InfoBar,
+                    $"Invalid presentation.info_bar '{infoBar}'; expected none, top, or bottom.")
+            };
+
+        if (table.TryGetValue("info_bar_logo_path", out var infoBarLogo) && infoBarLogo is string infoBarLogoPath)
+            config.InfoBarLogoPath = infoBarLogoPath;
+
+        if (table.TryGetValue("holding_slide_path", out var holdingSlide) && holdingSlide is string holdingSlidePath)
+            config.HoldingSlidePath = holdingSlidePath;
+
+        if (table.TryGetValue("intro_slides", out var introSlides) && introSlides is TomlArray introSlideArray)
+            foreach (var value in introSlideArray)
+                if (value is string slide && !string.IsNullOrWhiteSpace(slide))
+                    config.IntroSlides.Add(slide);
+
+        if (table.TryGetValue("countdown_seconds", out var countdown) && countdown is long countdownSeconds &&
+            countdownSeconds > 0)
+            config.CountdownSeconds = (int)countdownSeconds;
+
+        if (table.TryGetValue("countdown_text", out var countdownTextObject) && countdownTextObject is string countdownText &&
+            !string.IsNullOrWhiteSpace(countdownText))
+        {
+            try
+            {
+                _ = string.Format(CultureInfo.InvariantCulture, countdownText, "0:00");
+                config.CountdownText = countdownText;
+            }
+            catch (FormatException)
+            {
+                PyriteConfig.KeepDefault(config.CountdownText,
+                    $"Invalid presentation.countdown_text '{countdownText}'; use {{0}} for the remaining time.");
+            }
+        }
+
+        if (table.TryGetValue("background_image_path", out var backgroundImage) && backgroundImage is string backgroundImagePath)
+            config.BackgroundImagePath = backgroundImagePath;
+
+        if (table.TryGetValue("row_opacity", out var rowOpacity))
+            config.RowOpacity = Math.Clamp(ConvertToFloat(rowOpacity, config.RowOpacity), 0, 1);
+
+        if (table.TryGetValue("ui_scale", out var uiScale))
+        {
+            if (uiScale is "auto")
+                config.UiScaleAuto = true;
+            else if (uiScale is string uiScaleText)
+                PyriteConfig.KeepDefault(config.UiScale,
+                    $"Invalid presentation.ui_scale '{uiScaleText}'; expected \"auto\" or a number.");
+            else
+                config.UiScale = Math.Clamp(ConvertToFloat(uiScale, config.UiScale), 0.5f, 3f);
+        }
+
+        if (table.TryGetValue("image_cache_mb", out var imageCache) && imageCache is long imageCacheMegabytes &&
+            imageCacheMegabytes > 0)
+            config.ImageCacheMegabytes = (int)Math.Min(imageCacheMegabytes, 16384);
+
+        if (table.TryGetValue("palette", out var palette))
+            config.Palette = palette switch
+            {
+                "default" => ColorPalette.Default,
+                "deuteranopia" => ColorPalette.Deuteranopia,
+                "high_contrast" => ColorPalette.HighContrast,
+                _ => PyriteConfig.KeepDefault(config.Palette,
+                    $"Invalid presentation.palette '{palette}'; expected default, deuteranopia, or high_contrast.")
+            };
+
+        // The palette preset is the base; explicit [presentation.colors] entries override it.
+        config.Colors = table.TryGetValue("colors", out var colorsObject) && colorsObject is TomlTable colorsTable
+            ? PresentationColorsConfig.FromToml(colorsTable, config.Palette)
+            : new PresentationColorsConfig().WithPalette(config.Palette);
+
+        if (table.TryGetValue("autopilot", out var autopilotObject) && autopilotObject is TomlTable autopilotTable)
+            config.Autopilot = AutopilotConfig.FromToml(autopilotTable);
+
+        if (table.TryGetValue("sounds", out var soundsObject) && soundsObject is TomlTable soundsTable)
+            config.Sounds = SoundConfig.FromToml(soundsTable);
+
+        if (table.TryGetValue("timing", out var timingObject) && timingObject is TomlTable timingTable)
+            config.Timing = PhaseTimingConfig.FromToml(timingTable);
+
         return config;
     }
 
-    private static float ConvertToFloat(object value, float fallback)
+    internal static float ConvertToFloat(object value, float fallback)
     {
         return value switch
         {
@@ -60,4 +376,817 @@ public sealed class PresentationConfig
             _ => fallback
         };
     }
-}
\ No newline at end of file
+}
+
+public enum ColorPalette
+{
+    Default,
+    Deuteranopia,
+    HighContrast
+}
+
+public enum InfoBarPosition
+{
+    None,
+    Top,
+    Bottom
+}
+
+/// <summary>
+///     Order in which a team's frozen cells are revealed by <c>Space</c>.
+/// </summary>
+public enum RevealOrder
+{
+    /// <summary>Scoreboard column order.</summary>
+    LeftToRight,
+
+    /// <summary>Failed attempts first, then solves from the latest (least penalty gain) to the earliest.</summary>
+    LeastImpact,
+
+    /// <summary>By the time of the team's last submission on each problem.</summary>
+    SubmissionOrder,
+
+    /// <summary>Column order, but clicking a frozen cell queues it for the next <c>Space</c> instead of revealing it.</summary>
+    Operator
+}
+
+/// <summary>
+///     How problem cells spell out a result. <see cref="Classic" /> is the traditional ICPC "+2 / 143" for a solve
+///     after two rejected tries at minute 143, and "−3" for three failed tries.
+/// </summary>
+public enum ProblemCellNotation
+{
+    AttemptsTime,
+    Classic
+}
+
+public enum OrganizationSubline
+{
+    FormalName,
+    Shortname,
+    Name,
+    None
+}
+
+public sealed class AutopilotConfig
+{
+    public float RevealDelaySeconds { get; set; } = 1.5f;
+    public float ResortDelaySeconds { get; set; } = 1.0f;
+    public float AwardDelaySeconds { get; set; } = 5.0f;
+    public float AdvanceDelaySeconds { get; set; } = 0.8f;
+
+    public static AutopilotConfig FromToml(TomlTable table)
+    {
+        var config = new AutopilotConfig();
+
+        if (table.TryGetValue("reveal_delay_seconds", out var reveal))
+            config.RevealDelaySeconds = PresentationConfig.ConvertToFloat(reveal, config.RevealDelaySeconds);
+
+        if (table.TryGetValue("resort_delay_seconds", out var resort))
+            config.ResortDelaySeconds = PresentationConfig.ConvertToFloat(resort, config.ResortDelaySeconds);
+
+        if (table.TryGetValue("award_delay_seconds", out var award))
+            config.AwardDelaySeconds = PresentationConfig.ConvertToFloat(award, config.AwardDelaySeconds);
+
+        if (table.TryGetValue("advance_delay_seconds", out var advance))
+            config.AdvanceDelaySeconds = PresentationConfig.ConvertToFloat(advance, config.AdvanceDelaySeconds);
+
+        return config;
+    }
+}
+
+/// <summary>
+///     Minimum pauses between reveal steps from <c>[presentation.timing]</c>, for manual advancing and autopilot alike;
+///     <c>Space</c> is ignored until a pause is over. All default to 0 (no pause).
+/// </summary>
+public sealed class PhaseTimingConfig
+{
+    /// <summary>
+    ///     After the focus moves to the next team.
+    /// </summary>
+    public float FocusPauseSeconds { get; set; }
+
+    /// <summary>
+    ///     After a cell is revealed.
+    /// </summary>
+    public float RevealPauseSeconds { get; set; }
+
+    /// <summary>
+    ///     Before the step that opens a team's award overlay.
+    /// </summary>
+    public float OverlayDelaySeconds { get; set; }
+
+    /// <summary>
+    ///     How long an award overlay stays up before it can be dismissed.
+    /// </summary>
+    public float OverlayMinSeconds { get; set; }
+
+    public static PhaseTimingConfig FromToml(TomlTable table)
+    {
+        return new PhaseTimingConfig
+        {
+            FocusPauseSeconds = ReadSeconds(table, "focus_pause_seconds"),
+            RevealPauseSeconds = ReadSeconds(table, "reveal_pause_seconds"),
+            OverlayDelaySeconds = ReadSeconds(table, "overlay_delay_seconds"),
+            OverlayMinSeconds = ReadSeconds(table, "overlay_min_seconds")
+        };
+    }
+
+    private static float ReadSeconds(TomlTable table, string key)
+    {
+        return table.TryGetValue(key, out var value) ? Math.Max(0, PresentationConfig.ConvertToFloat(value, 0)) : 0;
+    }
+}
+
+public sealed class SoundConfig
+{
+    public bool Muted { get; set; }
+    public string? PendingFocus { get; set; }
+    public string? Accepted { get; set; }
+    public string? Rejected { get; set; }
+    public string? RankJump { get; set; }
+    public string? Award { get; set; }
+
+    public static SoundConfig FromToml(TomlTable table)
+    {
+        var config = new SoundConfig();
+
+        if (table.TryGetValue("muted", out var mutedObject) && mutedObject is bool muted)
+            config.Muted = muted;
+
+        if (table.TryGetValue("pending_focus", out var pendingFocus) && pendingFocus is string pendingFocusPath)
+            config.PendingFocus = pendingFocusPath;
+
+        if (table.TryGetValue("accepted", out var accepted) && accepted is string acceptedPath)
+            config.Accepted = acceptedPath;
+
+        if (table.TryGetValue("rejected", out var rejected) && rejected is string rejectedPath)
+            config.Rejected = rejectedPath;
+
+        if (table.TryGetValue("rank_jump", out var rankJump) && rankJump is string rankJumpPath)
+            config.RankJump = rankJumpPath;
+
+        if (table.TryGetValue("award", out var award) && award is string awardPath)
+            config.Award = awardPath;
+
+        return config;
+    }
+}
+
+/// <summary>
+///     Presentation hotkeys that can be remapped under <c>[keys]</c>.
+/// </summary>
+public enum KeyAction
+{
+    Advance,
+    Undo,
+    SkipTeam,
+    Autopilot,
+    Blank,
+    RankComparison,
+    Mute,
+    Palette,
+    SlowerAnimations,
+    FasterAnimations,
+    NormalAnimations,
+    ZoomIn,
+    ZoomOut,
+    ZoomReset,
+    OperatorConsole,
+    Mirror,
+    Recording,
+    Screenshot,
+    Fullscreen,
+    Borderless,
+    NextMonitor,
+    RevealAll,
+    BackToSetup,
+    Help
+}
+
+/// <summary>
+///     Key gestures (e.g. <c>PageDown</c>, <c>Ctrl+Shift+F</c>) per <see cref="KeyAction" />. An action listed under
+///     <c>[keys]</c> uses only the configured gestures, e.g. for presenter remotes that send <c>PageDown</c> or
+///     <c>Right</c>; other actions keep their defaults unless a configured gesture takes their key.
+/// </summary>
+public sealed class KeysConfig
+{
+    /// <summary>
+    ///     The <c>[keys]</c> entry of each action.
+    /// </summary>
+    public static IReadOnlyDictionary<string, KeyAction> ActionNames { get; } = new Dictionary<string, KeyAction>(StringComparer.Ordinal)
+    {
+        ["advance"] = KeyAction.Advance,
+        ["undo"] = KeyAction.Undo,
+        ["skip_team"] = KeyAction.SkipTeam,
+        ["autopilot"] = KeyAction.Autopilot,
+        ["blank"] = KeyAction.Blank,
+        ["rank_comparison"] = KeyAction.RankComparison,
+        ["mute"] = KeyAction.Mute,
+        ["palette"] = KeyAction.Palette,
+        ["slower_animations"] = KeyAction.SlowerAnimations,
+        ["faster_animations"] = KeyAction.FasterAnimations,
+        ["normal_animations"] = KeyAction.NormalAnimations,
+        ["zoom_in"] = KeyAction.ZoomIn,
+        ["zoom_out"] = KeyAction.ZoomOut,
+        ["zoom_reset"] = KeyAction.ZoomReset,
+        ["operator_console"] = KeyAction.OperatorConsole,
+        ["mirror"] = KeyAction.Mirror,
+        ["recording"] = KeyAction.Recording,
+        ["screenshot"] = KeyAction.Screenshot,
+        ["fullscreen"] = KeyAction.Fullscreen,
+        ["borderless"] = KeyAction.Borderless,
+        ["next_monitor"] = KeyAction.NextMonitor,
+        ["reveal_all"] = KeyAction.RevealAll,
+        ["back_to_setup"] = KeyAction.BackToSetup,
+        ["help"] = KeyAction.Help
+    };
+
+    public static IReadOnlyDictionary<KeyAction, string[]> Defaults { get; } = new Dictionary<KeyAction, string[]>
+    {
+        [KeyAction.Advance] = ["Space"],
+        [KeyAction.Undo] = ["Back"],
+        [KeyAction.SkipTeam] = ["S"],
+        [KeyAction.Autopilot] = ["A"],
+        [KeyAction.Blank] = ["B"],
+        [KeyAction.RankComparison] = ["C"],
+        [KeyAction.Mute] = ["V"],
+        [KeyAction.Palette] = ["K"],
+        [KeyAction.SlowerAnimations] = ["OemOpenBrackets"],
+        [KeyAction.FasterAnimations] = ["OemCloseBrackets"],
+        [KeyAction.NormalAnimations] = ["OemBackslash", "OemPipe"],
+        [KeyAction.ZoomIn] = ["Ctrl+OemPlus", "Ctrl+Add"],
+        [KeyAction.ZoomOut] = ["Ctrl+OemMinus", "Ctrl+Subtract"],
+        [KeyAction.ZoomReset] = ["Ctrl+D0", "Ctrl+NumPad0"],
+        [KeyAction.OperatorConsole] = ["O"],
+        [KeyAction.Mirror] = ["P"],
+        [KeyAction.Recording] = ["F8"],
+        [KeyAction.Screenshot] = ["F9"],
+        [KeyAction.Fullscreen] = ["F11", "F12"],
+        [KeyAction.Borderless] = ["F10"],
+        [KeyAction.NextMonitor] = ["M"],
+        [KeyAction.RevealAll] = ["Ctrl+Shift+F"],
+        [KeyAction.BackToSetup] = ["Ctrl+Shift+Back"],
+        [KeyAction.Help] = ["F1"]
+    };
+
+    /// <summary>
+    ///     Actions remapped in <c>[keys]</c>; an empty list unbinds the action.
+    /// </summary>
+    public Dictionary<KeyAction, List<string>> Bindings { get; set; } = [];
+
+    public bool IsRemapped(KeyAction action)
+    {
+        return Bindings.ContainsKey(action);
+    }
+
+    /// <summary>
+    ///     The gestures that trigger <paramref name="action" />. Default gestures taken by a configured action are left
+    ///     out, so a remote's key can be reused without unbinding its default action first.
+    /// </summary>
+    public IReadOnlyList<string> GetGestures(KeyAction action)
+    {
+        if (Bindings.TryGetValue(action, out var configured)) return configured;
+
+        var taken = Bindings.Values.SelectMany(gestures => gestures).Select(Normalize).ToHashSet(StringComparer.Ordinal);
+        return Defaults[action].Where(gesture => !taken.Contains(Normalize(gesture))).ToList();
+    }
+
+    public static KeysConfig FromToml(TomlTable table)
+    {
+        var config = new KeysConfig();
+        var owners = new Dictionary<string, string>(StringComparer.Ordinal);
+
+        foreach (var (name, value) in table)
+        {
+            if (!ActionNames.TryGetValue(name, out var action))
+            {
+                Trace.WriteLine(
+                    $"[PyriteConfig] Unknown keys.{name}; expected one of {string.Join(", ", ActionNames.Keys)}. Ignoring it.");
+                continue;
+            }
+
+            var gestures = value switch
+            {
+                string gesture => new List<string> { gesture },
+                TomlArray array when array.All(item => item is string) => array.Cast<string>().ToList(),
+                _ => PyriteConfig.KeepDefault<List<string>?>(null,
+                    $"Invalid keys.{name}; expected a key such as \"PageDown\" or an array of keys.")
+            };
+            if (gestures is null) continue;
+
+            var bound = new List<string>();
+            foreach (var gesture in gestures)
+            {
+                if (string.IsNullOrWhiteSpace(gesture))
+                {
+                    Trace.WriteLine($"[PyriteConfig] Invalid keys.{name}; key names cannot be empty. Ignoring it.");
+                    continue;
+                }
+
+                if (owners.TryGetValue(Normalize(gesture), out var owner))
+                {
+                    Trace.WriteLine(
+                        $"[PyriteConfig] Key '{gesture}' is bound to both keys.{owner} and keys.{name}; keeping it for keys.{owner}.");
+                    continue;
+                }
+
+                owners[Normalize(gesture)] = name;
+                bound.Add(gesture.Trim());
+            }
+
+            // An empty array unbinds the action on purpose; one whose keys were all rejected keeps its defaults.
+            if (bound.Count > 0 || gestures.Count == 0) config.Bindings[action] = bound;
+        }
+
+        return config;
+    }
+
+    private static string Normalize(string gesture)
+    {
+        return gesture.Replace(" ", string.Empty, StringComparison.Ordinal).ToLowerInvariant();
+    }
+}
+
+/// <summary>
+///     Custom fonts for the presentation: TTF/OTF files relative to the CDP folder, the family to draw with, and the
+///     size of each text style. The embedded Noto stays the fallback for characters the custom font lacks.
+/// </summary>
+public sealed class FontsConfig
+{
+    public List<string> Files { get; set; } = [];
+
+    /// <summary>
+    ///     Family name inside <see cref="Files" />; the first family found when not set.
+    /// </summary>
+    public string? Family { get; set; }
+
+    public FontSizesConfig Sizes { get; set; } = new();
+
+    public static FontsConfig FromToml(TomlTable table)
+    {
+        var config = new FontsConfig();
+
+        if (table.TryGetValue("files", out var files) && files is TomlArray fileArray)
+            foreach (var value in fileArray)
+                if (value is string file && !string.IsNullOrWhiteSpace(file))
+                    config.Files.Add(file);
+
+        if (table.TryGetValue("family", out var family) && family is string familyName &&
+            !string.IsNullOrWhiteSpace(familyName))
+            config.Family = familyName.Trim();
+
+        if (table.TryGetValue("sizes", out var sizes) && sizes is TomlTable sizesTable)
+            config.Sizes = FontSizesConfig.FromToml(sizesTable);
+
+        return config;
+    }
+}
+
+/// <summary>
+///     Font sizes of the presentation's text styles, in the same units as the stage (before <c>ui_scale</c>).
+/// </summary>
+public sealed class FontSizesConfig
+{
+    public float Header { get; set; } = 18;
+    public float Rank { get; set; } = 16;
+    public float TeamName { get; set; } = 16;
+    public float Organization { get; set; } = 12;
+    public float Score { get; set; } = 16;
+    public float AwardTitle { get; set; } = 32;
+    public float AwardTeamName { get; set; } = 52;
+    public float AwardCitation { get; set; } = 40;
+    public float ChampionTeamName { get; set; } = 88;
+
+    public static FontSizesConfig FromToml(TomlTable table)
+    {
+        var config = new FontSizesConfig();
+        config.Header = ReadSize(table, "header", config.Header);
+        config.Rank = ReadSize(table, "rank", config.Rank);
+        config.TeamName = ReadSize(table, "team_name", config.TeamName);
+        config.Organization = ReadSize(table, "organization", config.Organization);
+        config.Score = ReadSize(table, "score", config.Score);
+        config.AwardTitle = ReadSize(table, "award_title", config.AwardTitle);
+        config.AwardTeamName = ReadSize(table, "award_team_name", config.AwardTeamName);
+        config.AwardCitation = ReadSize(table, "award_citation", config.AwardCitation);
+        config.ChampionTeamName = ReadSize(table, "champion_team_name", config.ChampionTeamName);
+        return config;
+    }
+
+    private static float ReadSize(TomlTable table, string key, float fallback)
+    {
+        if (!table.TryGetValue(key, out var value)) return fallback;
+
+        var size = PresentationConfig.ConvertToFloat(value, fallback);
+        return size > 0 ? size : fallback;
+    }
+}
+
+/// <summary>
+///     Defaults that pre-fill the medal setup from <c>[awards]</c>; unset values keep the built-in defaults.
+/// </summary>
+public sealed class AwardsConfig
+{
+    public int? GoldCount { get; set; }
+    public int? SilverCount { get; set; }
+    public int? BronzeCount { get; set; }
+    public string? GoldCitation { get; set; }
+    public string? SilverCitation { get; set; }
+    public string? BronzeCitation { get; set; }
+
+    /// <summary>
+    ///     Category champion template; <c>{group}</c> is the category name.
+    /// </summary>
+    public string? ChampionCitation { get; set; }
+
+    /// <summary>
+    ///     Citations are either a string or a table of strings keyed by language (<c>en</c>, <c>zh-CN</c>), of which
+    ///     the one for <paramref name="language" /> is used, falling back to <c>en</c>.
+    /// </summary>
+    public static AwardsConfig FromToml(TomlTable table, string language)
+    {
+        return new AwardsConfig
+        {
+            GoldCount = ReadCount(table, "gold"),
+            SilverCount = ReadCount(table, "silver"),
+            BronzeCount = ReadCount(table, "bronze"),
+            GoldCitation = ReadCitation(table, "gold_citation", language),
+            SilverCitation = ReadCitation(table, "silver_citation", language),
+            BronzeCitation = ReadCitation(table, "bronze_citation", language),
+            ChampionCitation = ReadCitation(table, "champion_citation", language)
+        };
+    }
+
+    private static int? ReadCount(TomlTable table, string key)
+    {
+        if (!table.TryGetValue(key, out var value)) return null;
+
+        if (value is long count && count >= 0) return (int)count;
+
+        return PyriteConfig.KeepDefault<int?>(null, $"Invalid awards.{key} '{value}'; expected a team count of 0 or more.");
+    }
+
+    private static string? ReadCitation(TomlTable table, string key, string language)
+    {
+        if (!table.TryGetValue(key, out var value)) return null;
+
+        var citation = value switch
+        {
+            string text => text,
+            TomlTable localized => localized.TryGetValue(language, out var text) || localized.TryGetValue("en", out text)
+                ? text as string
+                : null,
+            _ => PyriteConfig.KeepDefault<string?>(null,
+                $"Invalid awards.{key}; expected a string or a table of strings keyed by language.")
+        };
+        return string.IsNullOrWhiteSpace(citation) ? null : citation;
+    }
+}
+
+/// <summary>
+///     Where the final standings are posted when the presentation finishes; nothing is sent without <see cref="Url" />.
+/// </summary>
+public sealed class WebhookConfig
+{
+    public string? Url { get; set; }
+    public string HeaderName { get; set; } = "Authorization";
+
+    /// <summary>
+    ///     Value of <see cref="HeaderName" />, e.g. <c>Bearer &lt;token&gt;</c>; no header is sent when empty.
+    /// </summary>
+    public string? AuthHeader { get; set; }
+
+    public int TimeoutSeconds { get; set; } = 10;
+
+    public static WebhookConfig FromToml(TomlTable table)
+    {
+        var config = new WebhookConfig();
+
+        if (table.TryGetValue("url", out var urlObject) && urlObject is string url)
+        {
+            if (Uri.TryCreate(url, UriKind.Absolute, out var uri) &&
+                (uri.Scheme == Uri.UriSchemeHttp || uri.Scheme == Uri.UriSchemeHttps))
+                config.Url = url;
+            else
+                PyriteConfig.KeepDefault(config.Url, $"Invalid webhook.url '{url}'; expected an http(s) URL.");
+        }
+
+        if (table.TryGetValue("header_name", out var headerName) && headerName is string name &&
+            !string.IsNullOrWhiteSpace(name))
+            config.HeaderName = name;
+
+        if (table.TryGetValue("auth_header", out var authHeader) && authHeader is string auth)
+            config.AuthHeader = auth;
+
+        if (table.TryGetValue("timeout_seconds", out var timeout) && timeout is long seconds && seconds > 0)
+            config.TimeoutSeconds = (int)seconds;
+
+        return config;
+    }
+}
+
+/// <summary>
+///     The DOMjudge / CCS Contest API that awards are pushed to; pushing is unavailable without <see cref="Url" />.
+/// </summary>
+public sealed class ContestApiConfig
+{
+    /// <summary>
+    ///     Base URL of the API, e.g. <c>https://judge.example.com/api/v4</c>.
+    /// </summary>
+    public string? Url { get; set; }
+
+    /// <summary>
+    ///     Contest to update; the loaded contest's id when not set.
+    /// </summary>
+    public string? ContestId { get; set; }
+
+    public string? Username { get; set; }
+    public string? Password { get; set; }
+
+    /// <summary>
+    ///     Sent as a bearer token instead of <see cref="Username" /> and <see cref="Password" />.
+    /// </summary>
+    public string? Token { get; set; }
+
+    public int TimeoutSeconds { get; set; } = 30;
+
+    public static ContestApiConfig FromToml(TomlTable table)
+    {
+        var config = new ContestApiConfig();
+
+        if (table.TryGetValue("url", out var urlObject) && urlObject is string url)
+        {
+            if (Uri.TryCreate(url, UriKind.Absolute, out var uri) &&
+                (uri.Scheme == Uri.UriSchemeHttp || uri.Scheme == Uri.UriSchemeHttps))
+                config.Url = url.TrimEnd('/');
+            else
+                PyriteConfig.KeepDefault(config.Url, $"Invalid contest_api.url '{url}'; expected an http(s) URL.");
+        }
+
+        if (table.TryGetValue("contest_id", out var contestId) && contestId is string id && !string.IsNullOrWhiteSpace(id))
+            config.ContestId = id;
+
+        if (table.TryGetValue("username", out var username) && username is string user)
+            config.Username = user;
+
+        if (table.TryGetValue("password", out var password) && password is string secret)
+            config.Password = secret;
+
+        if (table.TryGetValue("token", out var token) && token is string bearer)
+            config.Token = bearer;
+
+        if (table.TryGetValue("timeout_seconds", out var timeout) && timeout is long seconds && seconds > 0)
+            config.TimeoutSeconds = (int)seconds;
+
+        return config;
+    }
+}
+
+public sealed class CertificateConfig
+{
+    /// <summary>
+    ///     PNG, JPEG, or PDF background; relative paths are resolved against the CDP folder. The first page of a PDF is
+    ///     rendered at <see cref="Dpi" /> through <see cref="PdftoppmPath" />.
+    /// </summary>
+    public string? Template { get; set; }
+
+    /// <summary>
+    ///     The Poppler <c>pdftoppm</c> executable for PDF templates; <c>pdftoppm</c> on the <c>PATH</c> when not set.
+    /// </summary>
+    public string? PdftoppmPath { get; set; }
+
+    public string OutputDirectory { get; set; } = "certificates";
+
+    /// <summary>
+    ///     Resolution of the template, which sets the PDF page size (a 3508x2480 template at 300 dpi is A4 landscape).
+    /// </summary>
+    public int Dpi { get; set; } = 300;
+
+    public CertificateFieldConfig? TeamName { get; set; }
+    public CertificateFieldConfig? OrganizationName { get; set; }
+    public CertificateFieldConfig? Citation { get; set; }
+    public CertificateFieldConfig? Rank { get; set; }
+
+    public static CertificateConfig FromToml(TomlTable table)
+    {
+        var config = new CertificateConfig();
+
+        if (table.TryGetValue("template", out var template) && template is string templatePath)
+            config.Template = templatePath;
+
+        if (table.TryGetValue("output_directory", out var outputDirectory) && outputDirectory is string output)
+            config.OutputDirectory = output;
+
+        if (table.TryGetValue("pdftoppm_path", out var pdftoppm) && pdftoppm is string pdftoppmPath &&
+            !string.IsNullOrWhiteSpace(pdftoppmPath))
+            config.PdftoppmPath = pdftoppmPath;
+
+        if (table.TryGetValue("dpi", out var dpiObject) && dpiObject is long dpi && dpi > 0)
+            config.Dpi = (int)dpi;
+
+        config.TeamName = ReadField(table, "team_name");
+        config.OrganizationName = ReadField(table, "org_name");
+        config.Citation = ReadField(table, "citation");
+        config.Rank = ReadField(table, "rank");
+        return config;
+    }
+
+    private static CertificateFieldConfig? ReadField(TomlTable table, string key)
+    {
+        if (!table.TryGetValue(key, out var value)) return null;
+
+        if (value is not TomlTable fieldTable)
+            return PyriteConfig.KeepDefault<CertificateFieldConfig?>(null,
+                $"Invalid certificates.{key}; expected a table with x and y.");
+
+        return CertificateFieldConfig.FromToml(fieldTable, key);
+    }
+}
+
+/// <summary>
+///     Where one text field goes on the certificate, in template pixels. <see cref="Y" /> is the top of the text and
+///     <see cref="X" /> its left edge, center, or right edge depending on <see cref="Align" />.
+/// </summary>
+public sealed class CertificateFieldConfig
+{
+    public double X { get; set; }
+    public double Y { get; set; }
+    public double FontSize { get; set; } = 48;
+    public string? FontFamily { get; set; }
+    public bool Bold { get; set; }
+    public string Color { get; set; } = "#000000";
+    public CertificateTextAlign Align { get; set; } = CertificateTextAlign.Center;
+
+    /// <summary>
+    ///     Text wider than this wraps onto further lines; <c>null</c> keeps it on one line.
+    /// </summary>
+    public double? MaxWidth { get; set; }
+
+    /// <summary>
+    ///     <c>null</c>, so the field is not drawn, when <c>x</c> or <c>y</c> is missing.
+    /// </summary>
+    public static CertificateFieldConfig? FromToml(TomlTable table, string key)
+    {
+        var config = new CertificateFieldConfig();
+        if (!table.TryGetValue("x", out var x) || !table.TryGetValue("y", out var y))
+            return PyriteConfig.KeepDefault<CertificateFieldConfig?>(null, $"certificates.{key} needs both x and y.");
+
+        config.X = PresentationConfig.ConvertToFloat(x, 0);
+        config.Y = PresentationConfig.ConvertToFloat(y, 0);
+
+        if (table.TryGetValue("font_size", out var fontSize))
+            config.FontSize = Math.Max(1, PresentationConfig.ConvertToFloat(fontSize, (float)config.FontSize));
+
+        if (table.TryGetValue("font_family", out var fontFamily) && fontFamily is string family)
+            config.FontFamily = family;
+
+        if (table.TryGetValue("bold", out var boldObject) && boldObject is bool bold)
+            config.Bold = bold;
+
+        if (table.TryGetValue("color", out var colorObject))
+        {
+            config.Color = colorObject is string raw && PresentationColorsConfig.TryNormalizeHexColor(raw, out var color)
+                ? color
+                : PyriteConfig.KeepDefault(config.Color,
+                    $"Invalid color '{colorObject}' for certificates.{key}.color; expected #RRGGBB or #AARRGGBB.");
+        }
+
+        if (table.TryGetValue("align", out var alignObject) && alignObject is string align)
+            config.Align = align.Trim().ToLowerInvariant() switch
+            {
+                "left" => CertificateTextAlign.Left,
+                "center" => CertificateTextAlign.Center,
+                "right" => CertificateTextAlign.Right,
+                _ => PyriteConfig.KeepDefault(config.Align,
+                    $"Invalid align '{align}' for certificates.{key}; expected left, center, or right.")
+            };
+
+        if (table.TryGetValue("max_width", out var maxWidth))
+            config.MaxWidth = Math.Max(1, PresentationConfig.ConvertToFloat(maxWidth, 0));
+
+        return config;
+    }
+}
+
+public enum CertificateTextAlign
+{
+    Left,
+    Center,
+    Right
+}
+
+public sealed class PresentationColorsConfig
+{
+    public string Background { get; set; } = "#111111";
+    public List<string> BackgroundGradient { get; set; } = [];
+    public string HeaderBackground { get; set; } = "#141414";
+    public string RowBackground { get; set; } = "#111111";
+    public string RowAlternateBackground { get; set; } = "#1E1E1E";
+    public string Text { get; set; } = "#FFFFFF";
+    public string Solved { get; set; } = "#31C950";
+    public string FirstSolve { get; set; } = "#0E7A36";
+    public string Rejected { get; set; } = "#FB2C36";
+    public string Pending { get; set; } = "#2B7FFF";
+    public string Untouched { get; set; } = "#62748E";
+    public string Focus { get; set; } = "#A7D8FF";
+    public string FocusGlow { get; set; } = "#FFFFFF";
+    public string GoldTint { get; set; } = "#33FFD700";
+    public string SilverTint { get; set; } = "#33C0C0C0";
+    public string BronzeTint { get; set; } = "#33CD7F32";
+
+    public static PresentationColorsConfig FromToml(TomlTable table, ColorPalette palette = ColorPalette.Default)
+    {
+        var config = new PresentationColorsConfig().WithPalette(palette);
+
+        config.Background = ReadColor(table, "background", config.Background);
+        config.BackgroundGradient = ReadColorList(table, "background_gradient");
+        config.HeaderBackground = ReadColor(table, "header_background", config.HeaderBackground);
+        config.RowBackground = ReadColor(table, "row_background", config.RowBackground);
+        config.RowAlternateBackground = ReadColor(table, "row_alternate_background", config.RowAlternateBackground);
+        config.Text = ReadColor(table, "text", config.Text);
+        config.Solved = ReadColor(table, "solved", config.Solved);
+        config.FirstSolve = ReadColor(table, "first_solve", config.FirstSolve);
+        config.Rejected = ReadColor(table, "rejected", config.Rejected);
+        config.Pending = ReadColor(table, "pending", config.Pending);
+        config.Untouched = ReadColor(table, "untouched", config.Untouched);
+        config.Focus = ReadColor(table, "focus", config.Focus);
+        config.FocusGlow = ReadColor(table, "focus_glow", config.FocusGlow);
+        config.GoldTint = ReadColor(table, "gold_tint", config.GoldTint);
+        config.SilverTint = ReadColor(table, "silver_tint", config.SilverTint);
+        config.BronzeTint = ReadColor(table, "bronze_tint", config.BronzeTint);
+
+        return config;
+    }
+
+    /// <summary>
+    ///     Returns a copy with the result and focus colors replaced by the preset. Other colors are kept.
+    /// </summary>
+    public PresentationColorsConfig WithPalette(ColorPalette palette)
+    {
+        var copy = (PresentationColorsConfig)MemberwiseClone();
+        copy.BackgroundGradient = [..BackgroundGradient];
+        (copy.Solved, copy.FirstSolve, copy.Rejected, copy.Pending, copy.Focus, copy.FocusGlow) = palette switch
+        {
+            // Okabe-Ito blue/vermillion/reddish purple stay distinct without red-green discrimination.
+            ColorPalette.Deuteranopia => ("#0072B2", "#004A75", "#D55E00", "#CC79A7", "#56B4E9", "#F0E442"),
+            ColorPalette.HighContrast => ("#00C000", "#006400", "#FF0000", "#0050FF", "#FFFF00", "#FFFFFF"),
+            _ => ("#31C950", "#0E7A36", "#FB2C36", "#2B7FFF", "#A7D8FF", "#FFFFFF")
+        };
+        return copy;
+    }
+
+    private static string ReadColor(TomlTable table, string key, string fallback)
+    {
+        if (!table.TryGetValue(key, out var value)) return fallback;
+
+        if (value is string raw && TryNormalizeHexColor(raw, out var normalized)) return normalized;
+
+        Trace.WriteLine(
+            $"[PresentationConfig] Invalid color '{value}' for presentation.colors.{key}; expected #RRGGBB or #AARRGGBB. Using {fallback}.");
+        return fallback;
+    }
+
+    private static List<string> ReadColorList(TomlTable table, string key)
+    {
+        if (!table.TryGetValue(key, out var value)) return [];
+
+        if (value is not TomlArray array)
+        {
+            Trace.WriteLine($"[PresentationConfig] Invalid presentation.colors.{key}; expected an array of colors. Ignoring it.");
+            return [];
+        }
+
+        var colors = new List<string>();
+        foreach (var item in array)
+        {
+            if (item is string raw && TryNormalizeHexColor(raw, out var normalized))
+            {
+                colors.Add(normalized);
+                continue;
+            }
+
+            Trace.WriteLine(
+                $"[PresentationConfig] Invalid color '{item}' in presentation.colors.{key}; expected #RRGGBB or #AARRGGBB. Ignoring it.");
+        }
+
+        return colors;
+    }
+
+    /// <summary>
+    ///     Accepts <c>RGB</c>, <c>RRGGBB</c> or <c>AARRGGBB</c> with an optional leading '#', and returns the
+    ///     canonical upper-case <c>#RRGGBB</c>/<c>#AARRGGBB</c> form.
+    /// </summary>
+    public static bool TryNormalizeHexColor(string raw, out string normalized)
+    {
+        normalized = string.Empty;
+        var hex = raw.Trim().TrimStart('#');
+        if (hex.Length == 3) hex = $"{hex[0]}{hex[0]}{hex[1]}{hex[1]}{hex[2]}{hex[2]}";
+
+        if ((hex.Length != 6 && hex.Length != 8) ||
+            !uint.TryParse(hex, NumberStyles.HexNumber, CultureInfo.InvariantCulture, out _))
+            return false;
+
+        normalized = "#" + hex.ToUpperInvariant();
+        return true;
+    }
+}
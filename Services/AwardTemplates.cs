@@ -0,0 +1,201 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.Linq;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Builds common awards from a finalized leaderboard. Ids follow the CLICS award ids (<c>first-to-solve-&lt;problem&gt;</c>,
+///     <c>group-winner-&lt;group&gt;</c>) so regenerating an award replaces the previous one instead of adding a copy.
+///     Freeze-period solves count, since the leaderboard is the finalized one.
+/// </summary>
+public static class AwardTemplates
+{
+    public const string FirstAcceptedId = "first-accepted";
+    public const string LastAcceptedId = "last-accepted";
+    public const string FirstToSolvePrefix = "first-to-solve-";
+    public const string HonorableMentionId = "honorable-mention";
+
+    private static readonly string[] MedalColors = ["gold", "silver", "bronze"];
+
+    public static List<Award> FirstSolves(IEnumerable<TeamStatus> leaderboard, IReadOnlyDictionary<string, Problem> problems)
+    {
+        var awards = new List<Award>();
+        var firstSolvers = ContestProcessor.FindFirstSolvers(leaderboard);
+        foreach (var problem in problems.Values
+                     .OrderBy(problem => problem.Ordinal)
+                     .ThenBy(problem => problem.Label, StringComparer.Ordinal))
+        {
+            if (!firstSolvers.TryGetValue(problem.Id, out var teamIds)) continue;
+
+            var label = string.IsNullOrWhiteSpace(problem.Label) ? problem.ShortName : problem.Label;
+            awards.Add(new Award
+            {
+                Id = FirstToSolvePrefix + problem.Id,
+                Citation = $"First to solve problem {label}",
+                TeamIds = teamIds.Order(StringComparer.Ordinal).ToList(),
+                ProblemId = problem.Id
+            });
+        }
+
+        return awards;
+    }
+
+    public static Award? FirstAccepted(IEnumerable<TeamStatus> leaderboard)
+    {
+        return AcceptedAt(leaderboard, FirstAcceptedId, "First accepted submission", latest: false);
+    }
+
+    public static Award? LastAccepted(IEnumerable<TeamStatus> leaderboard)
+    {
+        return AcceptedAt(leaderboard, LastAcceptedId, "Last accepted submission", latest: true);
+    }
+
+    /// <summary>
+    ///     One award per group for the best-ranked team of <paramref name="leaderboard" /> in that group. Groups without
+    ///     a team on the leaderboard get no award. <paramref name="citationTemplate" /> returns the citation template of
+    ///     a group, where <c>{group}</c> stands for the group name.
+    /// </summary>
+    public static List<Award> GroupChampions(
+        IReadOnlyList<TeamStatus> leaderboard,
+        IReadOnlyDictionary<string, Team> teams,
+        IEnumerable<Group> groups,
+        Func<Group, string> citationTemplate)
+    {
+        var awards = new List<Award>();
+        foreach (var group in groups)
+        {
+            var champion = leaderboard.FirstOrDefault(status =>
+                teams.TryGetValue(status.TeamId, out var team) &&
+                team.GroupIds.Contains(group.Id, StringComparer.Ordinal));
+            if (champion is null) continue;
+
+            awards.Add(new Award
+            {
+                Id = $"group-winner-{group.Id}",
+                Citation = citationTemplate(group).Replace("{group}", group.Name, StringComparison.Ordinal),
+                TeamIds = [champion.TeamId]
+            });
+        }
+
+        return awards;
+    }
+
+    /// <summary>
+    ///     All teams of <paramref name="leaderboard" /> that solved at least <paramref name="minSolved" /> problems but hold
+    ///     none of the medal awards in <paramref name="awards" />.
+    /// </summary>
+    public static Award? HonorableMention(
+        IEnumerable<TeamStatus> leaderboard,
+        IReadOnlyDictionary<string, Award> awards,
+        int minSolved)
+    {
+        var medalTeamIds = GetMedalTeamIds(awards);
+        var teamIds = leaderboard
+            .Where(team => team.TotalPoints >= Math.Max(1, minSolved) && !medalTeamIds.Contains(team.TeamId))
+            .Select(team => team.TeamId)
+            .ToList();
+
+        return teamIds.Count == 0
+            ? null
+            : new Award
+            {
+                Id = HonorableMentionId,
+                Citation = "Honorable Mention",
+                TeamIds = teamIds
+            };
+    }
+
+    /// <summary>
+    ///     Teams holding a gold, silver, or bronze medal. Pyrite writes <c>medal-&lt;color&gt;</c>; CLICS feeds use
+    ///     <c>&lt;color&gt;-medal</c>.
+    /// </summary>
+    public static HashSet<string> GetMedalTeamIds(IReadOnlyDictionary<string, Award> awards)
+    {
+        var teamIds = new HashSet<string>(StringComparer.Ordinal);
+        foreach (var color in MedalColors)
+        {
+            if (awards.TryGetValue($"medal-{color}", out var award)) teamIds.UnionWith(award.TeamIds);
+            if (awards.TryGetValue($"{color}-medal", out award)) teamIds.UnionWith(award.TeamIds);
+        }
+
+        return teamIds;
+    }
+
+    /// <summary>
+    ///     Fills <c>{team_name}</c>, <c>{org_name}</c>, <c>{rank}</c>, and <c>{solved}</c> in a citation for one of the
+    ///     award's teams. Placeholders without a value are left as they are.
+    /// </summary>
+    public static string ExpandCitation(string citation, string teamName, string? organizationName, int? rank, int? solved)
+    {
+        if (!citation.Contains('{')) return citation;
+
+        var expanded = citation
+            .Replace("{team_name}", teamName, StringComparison.Ordinal)
+            .Replace("{org_name}", organizationName ?? string.Empty, StringComparison.Ordinal);
+        if (rank is { } rankValue)
+            expanded = expanded.Replace("{rank}", rankValue.ToString(CultureInfo.InvariantCulture), StringComparison.Ordinal);
+        if (solved is { } solvedValue)
+            expanded = expanded.Replace("{solved}", solvedValue.ToString(CultureInfo.InvariantCulture), StringComparison.Ordinal);
+
+        return expanded;
+    }
+
+    public static bool IsMedalAward(string awardId)
+    {
+        return GetMedalColor(awardId) is not null;
+    }
+
+    /// <summary>
+    ///     <c>gold</c>, <c>silver</c>, or <c>bronze</c> for a medal award id, otherwise <c>null</c>.
+    /// </summary>
+    public static string? GetMedalColor(string awardId)
+    {
+        return MedalColors.FirstOrDefault(color =>
+            string.Equals(awardId, $"medal-{color}", StringComparison.Ordinal) ||
+            string.Equals(awardId, $"{color}-medal", StringComparison.Ordinal));
+    }
+
+    /// <summary>
+    ///     The problem a first-to-solve award is for: <see cref="Award.ProblemId" />, or the id suffix of awards from an
+    ///     event feed, which carry no problem id.
+    /// </summary>
+    public static string? GetFirstToSolveProblemId(Award award)
+    {
+        if (!string.IsNullOrWhiteSpace(award.ProblemId)) return award.ProblemId;
+
+        return award.Id.StartsWith(FirstToSolvePrefix, StringComparison.Ordinal)
+            ? award.Id[FirstToSolvePrefix.Length..]
+            : null;
+    }
+
+    private static Award? AcceptedAt(IEnumerable<TeamStatus> leaderboard, string id, string citation, bool latest)
+    {
+        DateTimeOffset? best = null;
+        var teamIds = new SortedSet<string>(StringComparer.Ordinal);
+        foreach (var team in leaderboard)
+        foreach (var stat in team.ProblemStats.Values)
+        {
+            if (!stat.Solved || stat.FirstAcTime is not { } acTime) continue;
+
+            if (best is null || (latest ? acTime > best.Value : acTime < best.Value))
+            {
+                best = acTime;
+                teamIds.Clear();
+            }
+
+            if (acTime == best.Value) teamIds.Add(team.TeamId);
+        }
+
+        return teamIds.Count == 0
+            ? null
+            : new Award
+            {
+                Id = id,
+                Citation = citation,
+                TeamIds = teamIds.ToList()
+            };
+    }
+}
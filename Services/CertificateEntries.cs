@@ -0,0 +1,57 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     One certificate per team of each award, in award order. Citations get their placeholders filled like on the
+///     award overlay; teams missing from the state get no certificate.
+/// </summary>
+public static class CertificateEntries
+{
+    public static List<CertificateEntry> Build(ContestState state)
+    {
+        var rowsByTeamId = StandingsExport.BuildRows(state).ToDictionary(row => row.TeamId, StringComparer.Ordinal);
+        var entries = new List<CertificateEntry>();
+        foreach (var award in state.Awards.Values
+                     .OrderBy(award => award.Order)
+                     .ThenBy(award => award.Id, StringComparer.Ordinal))
+        foreach (var teamId in award.TeamIds)
+        {
+            if (!rowsByTeamId.TryGetValue(teamId, out var row)) continue;
+
+            var organizationName = string.IsNullOrWhiteSpace(row.OrganizationName) ? null : row.OrganizationName;
+            entries.Add(new CertificateEntry(
+                award.Id,
+                teamId,
+                row.TeamName,
+                organizationName,
+                AwardTemplates.ExpandCitation(award.Citation, row.TeamName, organizationName, row.Rank, row.Solved),
+                row.Rank));
+        }
+
+        return entries;
+    }
+}
+
+public sealed record CertificateEntry(
+    string AwardId,
+    string TeamId,
+    string TeamName,
+    string? OrganizationName,
+    string Citation,
+    int? Rank)
+{
+    public string FileName
+    {
+        get
+        {
+            var invalid = Path.GetInvalidFileNameChars();
+            var name = new string($"{AwardId}-{TeamId}".Select(c => invalid.Contains(c) ? '_' : c).ToArray());
+            return $"{name}.pdf";
+        }
+    }
+}
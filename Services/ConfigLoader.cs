@@ -1,6 +1,7 @@
 using Pyrite.Models;
 using System;
 using System.IO;
+using System.Linq;
 using Tomlyn;
 using Tomlyn.Model;
 
@@ -8,34 +9,103 @@ namespace Pyrite.Services;
 
 public static class ConfigLoader
 {
+    public const string FileName = "config.toml";
+
+    public static string GetConfigPath(string cdpPath)
+    {
+        return Path.Combine(cdpPath, FileName);
+    }
+
     public static PyriteConfig LoadIfExists(string cdpPath)
     {
-        var configPath = Path.Combine(cdpPath, "config.toml");
+        var configPath = GetConfigPath(cdpPath);
         if (!File.Exists(configPath)) return PyriteConfig.Default();
 
-        var raw = File.ReadAllText(configPath);
-        if (!Toml.TryToModel<TomlTable>(raw, out var table, out var diagnostics) || table is null)
-        {
-            var diagnosticMessage = diagnostics is null ? "Unknown parse error" : string.Join(" | ", diagnostics);
-            throw new InvalidOperationException($"Invalid config.toml: {diagnosticMessage}");
-        }
+        return Parse(File.ReadAllText(configPath));
+    }
 
+    /// <summary>
+    ///     Reads the TOML text of a <c>config.toml</c>, throwing <see cref="InvalidOperationException" /> with a
+    ///     readable message when it is not valid TOML. Invalid settings are logged and keep their defaults.
+    /// </summary>
+    public static PyriteConfig Parse(string raw)
+    {
+        var table = ParseTable(raw);
         var config = PyriteConfig.Default();
 
+        if (table.TryGetValue("language", out var languageObject) && languageObject is string language)
+        {
+            if (Localizer.IsSupported(language))
+                config.Language = language;
+            else
+                PyriteConfig.KeepDefault(config.Language,
+                    $"Invalid language '{language}'; expected {Localizer.English} or {Localizer.SimplifiedChinese}.");
+        }
+
         if (table.TryGetValue("filter_team_submissions", out var filterTeams) && filterTeams is TomlArray filterArray)
             foreach (var value in filterArray)
                 if (value is string teamId)
                     config.FilterTeamSubmissions.Add(teamId);
 
+        if (table.TryGetValue("hidden_problems", out var hiddenProblems) && hiddenProblems is TomlArray hiddenArray)
+            foreach (var value in hiddenArray)
+                if (value is string problemId)
+                    config.HiddenProblems.Add(problemId);
+
         if (table.TryGetValue("team_group_map", out var mapObject) && mapObject is TomlTable mapTable)
             foreach (var kv in mapTable)
                 if (kv.Value is string groupId)
                     config.TeamGroupMap[kv.Key] = groupId;
 
+        if (table.TryGetValue("team_names", out var teamNamesObject) && teamNamesObject is TomlTable teamNamesTable)
+            foreach (var kv in teamNamesTable)
+                if (kv.Value is string teamName && !string.IsNullOrWhiteSpace(teamName))
+                    config.TeamNames[kv.Key] = teamName.Trim();
+
+        if (table.TryGetValue("group_names", out var groupNamesObject) && groupNamesObject is TomlTable groupNamesTable)
+            foreach (var kv in groupNamesTable)
+                if (kv.Value is string groupName && !string.IsNullOrWhiteSpace(groupName))
+                    config.GroupNames[kv.Key] = groupName.Trim();
+
+        if (table.TryGetValue("group_presets", out var presetsObject) && presetsObject is TomlTable presetsTable)
+            foreach (var kv in presetsTable)
+                if (kv.Value is TomlArray groupArray)
+                    config.GroupPresets[kv.Key] = groupArray.OfType<string>().ToList();
+
         if (table.TryGetValue("presentation", out var presentationObject) &&
             presentationObject is TomlTable presentationTable)
             config.Presentation = PresentationConfig.FromToml(presentationTable);
 
+        if (table.TryGetValue("certificates", out var certificatesObject) &&
+            certificatesObject is TomlTable certificatesTable)
+            config.Certificates = CertificateConfig.FromToml(certificatesTable);
+
+        if (table.TryGetValue("webhook", out var webhookObject) && webhookObject is TomlTable webhookTable)
+            config.Webhook = WebhookConfig.FromToml(webhookTable);
+
+        if (table.TryGetValue("contest_api", out var contestApiObject) && contestApiObject is TomlTable contestApiTable)
+            config.ContestApi = ContestApiConfig.FromToml(contestApiTable);
+
+        if (table.TryGetValue("fonts", out var fontsObject) && fontsObject is TomlTable fontsTable)
+            config.Fonts = FontsConfig.FromToml(fontsTable);
+
+        if (table.TryGetValue("awards", out var awardsObject) && awardsObject is TomlTable awardsTable)
+            config.Awards = AwardsConfig.FromToml(awardsTable, config.Language);
+
+        if (table.TryGetValue("keys", out var keysObject) && keysObject is TomlTable keysTable)
+            config.Keys = KeysConfig.FromToml(keysTable);
+
         return config;
     }
+
+    public static TomlTable ParseTable(string raw)
+    {
+        if (!Toml.TryToModel<TomlTable>(raw, out var table, out var diagnostics) || table is null)
+        {
+            var diagnosticMessage = diagnostics is null ? "Unknown parse error" : string.Join(" | ", diagnostics);
+            throw new InvalidOperationException($"Invalid config.toml: {diagnosticMessage}");
+        }
+
+        return table;
+    }
 }
\ No newline at end of file
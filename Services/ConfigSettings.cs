@@ -0,0 +1,438 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+using Tomlyn;
+using Tomlyn.Model;
+
+namespace Pyrite.Services;
+
+public enum SettingKind
+{
+    Text,
+    Integer,
+    Number,
+    Boolean,
+    Choice,
+    Color,
+
+    /// <summary>One item per line.</summary>
+    TextList,
+
+    /// <summary>One <c>key = value</c> pair per line.</summary>
+    TextMap,
+
+    /// <summary>One <c>key = item, item, ...</c> pair per line.</summary>
+    TextListMap
+}
+
+/// <summary>
+///     One editable <c>config.toml</c> key. <see cref="Section" /> is the dotted table path (empty for the top level);
+///     <see cref="DefaultText" /> is what Pyrite uses when the key is left out.
+/// </summary>
+public sealed record SettingDefinition(
+    string Section,
+    string Key,
+    SettingKind Kind,
+    string DefaultText = "",
+    IReadOnlyList<string>? Choices = null)
+{
+    public string Path => string.IsNullOrEmpty(Section) ? Key : $"{Section}.{Key}";
+
+    public bool IsMultiline => Kind is SettingKind.TextList or SettingKind.TextMap or SettingKind.TextListMap;
+}
+
+/// <summary>
+///     The settings editor's view of <c>config.toml</c>: every key Pyrite reads, as text. Saving edits the parsed TOML
+///     model in place, so keys and tables the editor does not know are kept, and runs the result through
+///     <see cref="ConfigLoader.Parse" /> before anything is written.
+/// </summary>
+public static class ConfigSettings
+{
+    private static readonly string[] Booleans = ["true", "false"];
+
+    private static readonly string[] CertificateFields = ["team_name", "org_name", "citation", "rank"];
+
+    public static IReadOnlyList<SettingDefinition> All { get; } = BuildDefinitions();
+
+    /// <summary>
+    ///     The current text of every setting; empty for keys that are not set.
+    /// </summary>
+    public static Dictionary<string, string> Read(string cdpPath)
+    {
+        var path = ConfigLoader.GetConfigPath(cdpPath);
+        var root = File.Exists(path) ? ConfigLoader.ParseTable(File.ReadAllText(path)) : new TomlTable();
+        var values = new Dictionary<string, string>(StringComparer.Ordinal);
+        foreach (var definition in All)
+            values[definition.Path] = FindTable(root, definition.Section, false) is { } table &&
+                                      table.TryGetValue(definition.Key, out var value)
+                ? FormatValue(definition, value)
+                : string.Empty;
+
+        return values;
+    }
+
+    /// <summary>
+    ///     Checks one value on its own: numbers, colors, choices, and the <c>key = value</c> lines.
+    /// </summary>
+    public static bool TryConvert(SettingDefinition definition, string text, out object? value, out string? error)
+    {
+        value = null;
+        error = null;
+        text = text.Trim();
+        if (text.Length == 0) return true;
+
+        switch (definition.Kind)
+        {
+            case SettingKind.Text:
+                value = text;
+                return true;
+            case SettingKind.Integer:
+                if (long.TryParse(text, NumberStyles.Integer, CultureInfo.InvariantCulture, out var integer))
+                {
+                    value = integer;
+                    return true;
+                }
+
+                error = "Expected a whole number.";
+                return false;
+            case SettingKind.Number:
+                // Numbers may also take keywords, e.g. ui_scale = "auto".
+                if (definition.Choices?.Contains(text, StringComparer.Ordinal) == true)
+                {
+                    value = text;
+                    return true;
+                }
+
+                if (double.TryParse(text, NumberStyles.Float, CultureInfo.InvariantCulture, out var number))
+                {
+                    value = number;
+                    return true;
+                }
+
+                error = "Expected a number, e.g. 0.5.";
+                return false;
+            case SettingKind.Boolean:
+            case SettingKind.Choice:
+                var choices = definition.Kind == SettingKind.Boolean ? Booleans : definition.Choices ?? [];
+                if (!choices.Contains(text, StringComparer.Ordinal))
+                {
+                    error = $"Expected one of {string.Join(", ", choices)}.";
+                    return false;
+                }
+
+                value = definition.Kind == SettingKind.Boolean ? text == "true" : text;
+                return true;
+            case SettingKind.Color:
+                if (PresentationColorsConfig.TryNormalizeHexColor(text, out var color))
+                {
+                    value = color;
+                    return true;
+                }
+
+                error = "Expected a color such as #RRGGBB or #AARRGGBB.";
+                return false;
+            case SettingKind.TextList:
+                var array = new TomlArray();
+                foreach (var line in SplitLines(text)) array.Add(line);
+                value = array;
+                return true;
+            case SettingKind.TextMap:
+            case SettingKind.TextListMap:
+                var map = new TomlTable();
+                foreach (var line in SplitLines(text))
+                {
+                    var separator = line.IndexOf('=');
+                    var key = separator > 0 ? line[..separator].Trim() : string.Empty;
+                    if (key.Length == 0)
+                    {
+                        error = $"Expected 'key = value' but got '{line}'.";
+                        return false;
+                    }
+
+                    var rest = line[(separator + 1)..].Trim();
+                    if (definition.Kind == SettingKind.TextMap)
+                    {
+                        map[key] = rest;
+                        continue;
+                    }
+
+                    var items = new TomlArray();
+                    foreach (var item in rest.Split(',', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries))
+                        items.Add(item);
+                    map[key] = items;
+                }
+
+                value = map;
+                return true;
+            default:
+                value = text;
+                return true;
+        }
+    }
+
+    /// <summary>
+    ///     Applies the edited values to <c>config.toml</c> (empty values remove the key), validates the whole file,
+    ///     and writes it, keeping the previous file as <c>config.toml.bak</c>. Returns the errors instead of
+    ///     writing when anything is invalid.
+    /// </summary>
+    public static List<string> Save(string cdpPath, IReadOnlyDictionary<string, string> values)
+    {
+        var path = ConfigLoader.GetConfigPath(cdpPath);
+        var errors = new List<string>();
+        TomlTable root;
+        try
+        {
+            root = File.Exists(path) ? ConfigLoader.ParseTable(File.ReadAllText(path)) : new TomlTable();
+        }
+        catch (Exception ex) when (ex is InvalidOperationException or IOException)
+        {
+            return [ex.Message];
+        }
+
+        foreach (var definition in All)
+        {
+            if (!values.TryGetValue(definition.Path, out var text)) continue;
+            if (!TryConvert(definition, text, out var value, out var error))
+            {
+                errors.Add($"{definition.Path}: {error}");
+                continue;
+            }
+
+            if (value is null)
+            {
+                FindTable(root, definition.Section, false)?.Remove(definition.Key);
+                continue;
+            }
+
+            FindTable(root, definition.Section, true)![definition.Key] = value;
+        }
+
+        if (errors.Count > 0) return errors;
+
+        var raw = Toml.FromModel(root);
+        try
+        {
+            ConfigLoader.Parse(raw);
+        }
+        catch (InvalidOperationException ex)
+        {
+            return [ex.Message];
+        }
+
+        if (File.Exists(path)) File.Copy(path, path + ".bak", true);
+        File.WriteAllText(path, raw);
+        return errors;
+    }
+
+    private static TomlTable? FindTable(TomlTable root, string section, bool create)
+    {
+        if (string.IsNullOrEmpty(section)) return root;
+
+        var table = root;
+        foreach (var name in section.Split('.'))
+        {
+            if (table.TryGetValue(name, out var child) && child is TomlTable childTable)
+            {
+                table = childTable;
+                continue;
+            }
+
+            if (!create) return null;
+
+            childTable = new TomlTable();
+            table[name] = childTable;
+            table = childTable;
+        }
+
+        return table;
+    }
+
+    private static string FormatValue(SettingDefinition definition, object value)
+    {
+        return value switch
+        {
+            bool flag => flag ? "true" : "false",
+            double number => number.ToString("R", CultureInfo.InvariantCulture),
+            long integer => integer.ToString(CultureInfo.InvariantCulture),
+            TomlArray array => string.Join('\n', array.Select(item => Convert.ToString(item, CultureInfo.InvariantCulture))),
+            TomlTable table when definition.Kind == SettingKind.TextListMap => string.Join('\n', table.Select(kv =>
+                $"{kv.Key} = {(kv.Value is TomlArray items ? string.Join(", ", items) : kv.Value)}")),
+            TomlTable table => string.Join('\n', table.Select(kv => $"{kv.Key} = {kv.Value}")),
+            _ => Convert.ToString(value, CultureInfo.InvariantCulture) ?? string.Empty
+        };
+    }
+
+    private static IEnumerable<string> SplitLines(string text)
+    {
+        return text.Split('\n', StringSplitOptions.RemoveEmptyEntries | StringSplitOptions.TrimEntries);
+    }
+
+    private static List<SettingDefinition> BuildDefinitions()
+    {
+        var colors = new PresentationColorsConfig();
+        var autopilot = new AutopilotConfig();
+        var sizes = new FontSizesConfig();
+        var definitions = new List<SettingDefinition>
+        {
+            new("", "language", SettingKind.Choice, Localizer.English, [Localizer.English, Localizer.SimplifiedChinese]),
+            new("", "filter_team_submissions", SettingKind.TextList),
+            new("", "hidden_problems", SettingKind.TextList),
+            new("", "team_group_map", SettingKind.TextMap),
+            new("", "team_names", SettingKind.TextMap),
+            new("", "group_names", SettingKind.TextMap),
+            new("", "group_presets", SettingKind.TextListMap),
+
+            new("presentation", "rows_per_page", SettingKind.Integer, "16"),
+            new("presentation", "scroll_animation_seconds", SettingKind.Number, "0.4"),
+            new("presentation", "row_fly_animation_seconds", SettingKind.Number, "0.6"),
+            new("presentation", "reveal_order", SettingKind.Choice, "left_to_right",
+                ["left_to_right", "least_impact", "submission_order", "operator"]),
+            new("presentation", "present_top_teams", SettingKind.Integer),
+            new("presentation", "start_rank", SettingKind.Integer),
+            new("presentation", "show_problem_solve_counts", SettingKind.Boolean, "true"),
+            new("presentation", "champion_celebration", SettingKind.Boolean, "true"),
+            new("presentation", "summary_screen", SettingKind.Boolean, "false"),
+            new("presentation", "medal_tint_rows", SettingKind.Boolean, "true"),
+            new("presentation", "show_country_flags", SettingKind.Boolean, "true"),
+            new("presentation", "show_team_labels", SettingKind.Boolean, "false"),
+            new("presentation", "prefer_team_display_name", SettingKind.Boolean, "false"),
+            new("presentation", "marquee_team_names", SettingKind.Boolean, "true"),
+            new("presentation", "organization_subline", SettingKind.Choice, "formal_name",
+                ["formal_name", "shortname", "name", "none"]),
+            new("presentation", "cell_notation", SettingKind.Choice, "attempts_time", ["attempts_time", "classic"]),
+            new("presentation", "info_bar", SettingKind.Choice, "none", ["none", "top", "bottom"]),
+            new("presentation", "info_bar_logo_path", SettingKind.Text),
+            new("presentation", "palette", SettingKind.Choice, "default", ["default", "deuteranopia", "high_contrast"]),
+            new("presentation", "row_opacity", SettingKind.Number, "1.0"),
+            new("presentation", "ui_scale", SettingKind.Number, "1.0", ["auto"]),
+            new("presentation", "image_cache_mb", SettingKind.Integer, "64"),
+            new("presentation", "background_image_path", SettingKind.Text),
+            new("presentation", "holding_slide_path", SettingKind.Text),
+            new("presentation", "intro_slides", SettingKind.TextList),
+            new("presentation", "countdown_seconds", SettingKind.Integer),
+            new("presentation", "countdown_text", SettingKind.Text),
+            new("presentation", "pending_pulse_seconds", SettingKind.Number, "1.5"),
+            new("presentation", "cell_flip_seconds", SettingKind.Number, "0.5"),
+            new("presentation", "focus_pulse_seconds", SettingKind.Number, "1.6"),
+            new("presentation", "rank_delta_seconds", SettingKind.Number, "3"),
+            new("presentation", "award_fade_seconds", SettingKind.Number, "0.4"),
+            new("presentation", "award_ken_burns_seconds", SettingKind.Number, "12"),
+            new("presentation", "award_ken_burns_zoom", SettingKind.Number, "1.15"),
+            new("presentation", "award_photo_seconds", SettingKind.Number, "4"),
+            new("presentation", "logo_extension", SettingKind.Text, "png"),
+            new("presentation", "team_photo_extension", SettingKind.Text, "jpg"),
+            new("presentation", "team_photo_fallback_path", SettingKind.Text),
+            new("presentation", "logo_fallback_path", SettingKind.Text),
+            new("presentation", "logo_fallbacks", SettingKind.TextMap),
+            new("presentation", "window_width", SettingKind.Integer),
+            new("presentation", "window_height", SettingKind.Integer),
+            new("presentation", "window_resizable", SettingKind.Boolean, "true"),
+            new("presentation", "monitor", SettingKind.Integer),
+            new("presentation", "mirror_monitor", SettingKind.Integer),
+            new("presentation", "screenshot_dir", SettingKind.Text, "screenshots"),
+            new("presentation", "recording_dir", SettingKind.Text, "recordings"),
+            new("presentation", "recording_fps", SettingKind.Integer, "60"),
+            new("presentation", "recording_ffmpeg_path", SettingKind.Text),
+
+            new("presentation.autopilot", "reveal_delay_seconds", SettingKind.Number, Format(autopilot.RevealDelaySeconds)),
+            new("presentation.autopilot", "resort_delay_seconds", SettingKind.Number, Format(autopilot.ResortDelaySeconds)),
+            new("presentation.autopilot", "award_delay_seconds", SettingKind.Number, Format(autopilot.AwardDelaySeconds)),
+            new("presentation.autopilot", "advance_delay_seconds", SettingKind.Number, Format(autopilot.AdvanceDelaySeconds)),
+
+            new("presentation.timing", "focus_pause_seconds", SettingKind.Number, "0"),
+            new("presentation.timing", "reveal_pause_seconds", SettingKind.Number, "0"),
+            new("presentation.timing", "overlay_delay_seconds", SettingKind.Number, "0"),
+            new("presentation.timing", "overlay_min_seconds", SettingKind.Number, "0"),
+
+            new("presentation.colors", "background", SettingKind.Color, colors.Background),
+            new("presentation.colors", "background_gradient", SettingKind.TextList),
+            new("presentation.colors", "header_background", SettingKind.Color, colors.HeaderBackground),
+            new("presentation.colors", "row_background", SettingKind.Color, colors.RowBackground),
+            new("presentation.colors", "row_alternate_background", SettingKind.Color, colors.RowAlternateBackground),
+            new("presentation.colors", "text", SettingKind.Color, colors.Text),
+            new("presentation.colors", "solved", SettingKind.Color, colors.Solved),
+            new("presentation.colors", "first_solve", SettingKind.Color, colors.FirstSolve),
+            new("presentation.colors", "rejected", SettingKind.Color, colors.Rejected),
+            new("presentation.colors", "pending", SettingKind.Color, colors.Pending),
+            new("presentation.colors", "untouched", SettingKind.Color, colors.Untouched),
+            new("presentation.colors", "focus", SettingKind.Color, colors.Focus),
+            new("presentation.colors", "focus_glow", SettingKind.Color, colors.FocusGlow),
+            new("presentation.colors", "gold_tint", SettingKind.Color, colors.GoldTint),
+            new("presentation.colors", "silver_tint", SettingKind.Color, colors.SilverTint),
+            new("presentation.colors", "bronze_tint", SettingKind.Color, colors.BronzeTint),
+
+            new("presentation.sounds", "muted", SettingKind.Boolean, "false"),
+            new("presentation.sounds", "pending_focus", SettingKind.Text),
+            new("presentation.sounds", "accepted", SettingKind.Text),
+            new("presentation.sounds", "rejected", SettingKind.Text),
+            new("presentation.sounds", "rank_jump", SettingKind.Text),
+            new("presentation.sounds", "award", SettingKind.Text),
+
+            new("fonts", "files", SettingKind.TextList),
+            new("fonts", "family", SettingKind.Text),
+            new("fonts.sizes", "header", SettingKind.Number, Format(sizes.Header)),
+            new("fonts.sizes", "rank", SettingKind.Number, Format(sizes.Rank)),
+            new("fonts.sizes", "team_name", SettingKind.Number, Format(sizes.TeamName)),
+            new("fonts.sizes", "organization", SettingKind.Number, Format(sizes.Organization)),
+            new("fonts.sizes", "score", SettingKind.Number, Format(sizes.Score)),
+            new("fonts.sizes", "award_title", SettingKind.Number, Format(sizes.AwardTitle)),
+            new("fonts.sizes", "award_team_name", SettingKind.Number, Format(sizes.AwardTeamName)),
+            new("fonts.sizes", "award_citation", SettingKind.Number, Format(sizes.AwardCitation)),
+            new("fonts.sizes", "champion_team_name", SettingKind.Number, Format(sizes.ChampionTeamName)),
+
+            new("awards", "gold", SettingKind.Integer),
+            new("awards", "silver", SettingKind.Integer),
+            new("awards", "bronze", SettingKind.Integer),
+            new("awards", "gold_citation", SettingKind.Text, "Gold Medal"),
+            new("awards", "silver_citation", SettingKind.Text, "Silver Medal"),
+            new("awards", "bronze_citation", SettingKind.Text, "Bronze Medal"),
+            new("awards", "champion_citation", SettingKind.Text, "Champion of {group}"),
+
+            new("certificates", "template", SettingKind.Text),
+            new("certificates", "output_directory", SettingKind.Text, "certificates"),
+            new("certificates", "dpi", SettingKind.Integer, "300"),
+            new("certificates", "pdftoppm_path", SettingKind.Text)
+        };
+
+        foreach (var field in CertificateFields)
+        {
+            var section = $"certificates.{field}";
+            definitions.Add(new SettingDefinition(section, "x", SettingKind.Number));
+            definitions.Add(new SettingDefinition(section, "y", SettingKind.Number));
+            definitions.Add(new SettingDefinition(section, "font_size", SettingKind.Number, "48"));
+            definitions.Add(new SettingDefinition(section, "font_family", SettingKind.Text));
+            definitions.Add(new SettingDefinition(section, "bold", SettingKind.Boolean, "false"));
+            definitions.Add(new SettingDefinition(section, "color", SettingKind.Color, "#000000"));
+            definitions.Add(new SettingDefinition(section, "align", SettingKind.Choice, "center", ["left", "center", "right"]));
+            definitions.Add(new SettingDefinition(section, "max_width", SettingKind.Number));
+        }
+
+        foreach (var (name, action) in KeysConfig.ActionNames)
+            definitions.Add(new SettingDefinition("keys", name, SettingKind.TextList,
+                string.Join(" / ", KeysConfig.Defaults[action])));
+
+        definitions.AddRange(
+        [
+            new SettingDefinition("webhook", "url", SettingKind.Text),
+            new SettingDefinition("webhook", "auth_header", SettingKind.Text),
+            new SettingDefinition("webhook", "header_name", SettingKind.Text, "Authorization"),
+            new SettingDefinition("webhook", "timeout_seconds", SettingKind.Integer, "10"),
+
+            new SettingDefinition("contest_api", "url", SettingKind.Text),
+            new SettingDefinition("contest_api", "contest_id", SettingKind.Text),
+            new SettingDefinition("contest_api", "username", SettingKind.Text),
+            new SettingDefinition("contest_api", "password", SettingKind.Text),
+            new SettingDefinition("contest_api", "token", SettingKind.Text),
+            new SettingDefinition("contest_api", "timeout_seconds", SettingKind.Integer, "30")
+        ]);
+        return definitions;
+    }
+
+    private static string Format(float value)
+    {
+        return value.ToString(CultureInfo.InvariantCulture);
+    }
+}
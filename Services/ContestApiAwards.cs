@@ -0,0 +1,71 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Net.Http;
+using System.Net.Http.Headers;
+using System.Text;
+using System.Text.Json.Nodes;
+using System.Threading;
+using System.Threading.Tasks;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Writes awards to the CLICS <c>awards</c> endpoint of a DOMjudge / CCS Contest API, one <c>PUT</c> per award,
+///     so the official system shows the awards decided in Pyrite. Awards that exist only on the server are left alone.
+/// </summary>
+public static class ContestApiAwards
+{
+    private static readonly HttpClient Client = new();
+
+    public static async Task<int> PushAsync(ContestApiConfig config, string contestId, IReadOnlyList<Award> awards)
+    {
+        if (string.IsNullOrWhiteSpace(config.Url))
+            throw new InvalidOperationException("No Contest API configured; set url in the [contest_api] section of config.toml.");
+
+        var pushed = 0;
+        foreach (var award in awards)
+        {
+            var body = new JsonObject
+            {
+                ["id"] = award.Id,
+                ["citation"] = award.Citation,
+                ["team_ids"] = new JsonArray(award.TeamIds.Select(teamId => (JsonNode?)JsonValue.Create(teamId)).ToArray())
+            };
+            var url = $"{config.Url}/contests/{Uri.EscapeDataString(contestId)}/awards/{Uri.EscapeDataString(award.Id)}";
+            using var request = new HttpRequestMessage(HttpMethod.Put, url);
+            request.Content = new StringContent(body.ToJsonString(), Encoding.UTF8, "application/json");
+            request.Headers.Authorization = GetAuthorization(config);
+
+            using var timeout = new CancellationTokenSource(TimeSpan.FromSeconds(config.TimeoutSeconds));
+            using var response = await Client.SendAsync(request, timeout.Token);
+            if (!response.IsSuccessStatusCode)
+            {
+                var detail = await response.Content.ReadAsStringAsync(timeout.Token);
+                throw new HttpRequestException(
+                    $"Award '{award.Id}' was rejected with {(int)response.StatusCode} {response.ReasonPhrase}: {Truncate(detail)}");
+            }
+
+            pushed += 1;
+        }
+
+        return pushed;
+    }
+
+    private static AuthenticationHeaderValue? GetAuthorization(ContestApiConfig config)
+    {
+        if (!string.IsNullOrWhiteSpace(config.Token)) return new AuthenticationHeaderValue("Bearer", config.Token);
+        if (string.IsNullOrEmpty(config.Username)) return null;
+
+        var credentials = Convert.ToBase64String(Encoding.UTF8.GetBytes($"{config.Username}:{config.Password}"));
+        return new AuthenticationHeaderValue("Basic", credentials);
+    }
+
+    private static string Truncate(string text)
+    {
+        const int maxLength = 200;
+        text = text.Trim();
+        return text.Length <= maxLength ? text : text[..maxLength] + "...";
+    }
+}
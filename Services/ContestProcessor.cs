@@ -1,7 +1,10 @@
 using Pyrite.Models;
 using System;
 using System.Collections.Generic;
+using System.Globalization;
 using System.Linq;
+using System.Security.Cryptography;
+using System.Text;
 
 namespace Pyrite.Services;
 
@@ -10,7 +13,9 @@ public static class ContestProcessor
     public static List<string> ValidateAndTransform(ContestState state, PyriteConfig config)
     {
         ApplySubmissionFilters(state, config);
+        ApplyProblemFilters(state, config);
         ApplyTeamGroupRemap(state, config);
+        ApplyNameOverrides(state, config);
 
         ValidateTeamGroups(state);
         ValidateAllSubmissionsJudged(state);
@@ -18,14 +23,74 @@ public static class ContestProcessor
         var (contestStart, contestFreeze) = GetContestTimes(state);
 
         var warnings = new List<string>();
-        var preFreezeMap = BuildInitialTeamStatusMap(state);
+        var preFreezeMap = BuildInitialTeamStatusMap(state, config);
         ApplyJudgementsToStatusMap(state, preFreezeMap, contestStart, contestFreeze, warnings);
 
         state.LeaderboardPreFreeze = ToSortedLeaderboard(preFreezeMap);
-        state.LeaderboardFinalized = ComputeFinalizedLeaderboard(state);
+        state.LeaderboardFinalized = ComputeFinalizedLeaderboard(state, config);
+        state.ScoreboardHash = ComputeScoreboardHash(state.LeaderboardFinalized);
         return warnings;
     }
 
+    /// <summary>
+    ///     The name shown for a team: its <c>[team_names]</c> override, else the feed's <c>display_name</c> when
+    ///     <c>prefer_team_display_name</c> is set, else its <c>name</c>.
+    /// </summary>
+    public static string GetTeamName(Team team, PyriteConfig config)
+    {
+        if (config.TeamNames.TryGetValue(team.Id, out var overrideName)) return overrideName;
+
+        return config.Presentation.PreferTeamDisplayName && !string.IsNullOrWhiteSpace(team.DisplayName)
+            ? team.DisplayName
+            : team.Name;
+    }
+
+    /// <summary>
+    ///     Hashes the rank order, team ids, points and penalties of a leaderboard so two machines can confirm
+    ///     they present identical standings. Only invariant, culture-independent text is fed into SHA-256.
+    /// </summary>
+    public static string ComputeScoreboardHash(IReadOnlyList<TeamStatus> leaderboard)
+    {
+        var builder = new StringBuilder();
+        for (var i = 0; i < leaderboard.Count; i++)
+        {
+            var team = leaderboard[i];
+            builder.Append(string.Create(CultureInfo.InvariantCulture,
+                $"{i + 1}\t{team.TeamId}\t{team.TotalPoints}\t{team.TotalPenalty}\n"));
+        }
+
+        var digest = SHA256.HashData(Encoding.UTF8.GetBytes(builder.ToString()));
+        return Convert.ToHexStringLower(digest);
+    }
+
+    /// <summary>
+    ///     Returns, per problem id, the team(s) with the earliest accepted submission. Teams that solved at the same
+    ///     instant all count as first to solve. Freeze-period solves are included.
+    /// </summary>
+    public static Dictionary<string, HashSet<string>> FindFirstSolvers(IEnumerable<TeamStatus> leaderboard)
+    {
+        var earliest = new Dictionary<string, DateTimeOffset>(StringComparer.Ordinal);
+        var solvers = new Dictionary<string, HashSet<string>>(StringComparer.Ordinal);
+
+        foreach (var team in leaderboard)
+        foreach (var (problemId, stat) in team.ProblemStats)
+        {
+            if (!stat.Solved || stat.FirstAcTime is not { } acTime) continue;
+
+            if (!earliest.TryGetValue(problemId, out var best) || acTime < best)
+            {
+                earliest[problemId] = acTime;
+                solvers[problemId] = new HashSet<string>(StringComparer.Ordinal) { team.TeamId };
+            }
+            else if (acTime == best)
+            {
+                solvers[problemId].Add(team.TeamId);
+            }
+        }
+
+        return solvers;
+    }
+
     private static void ApplySubmissionFilters(ContestState state, PyriteConfig config)
     {
         if (config.FilterTeamSubmissions.Count == 0) return;
@@ -48,6 +113,40 @@ public static class ContestProcessor
             .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal);
     }
 
+    /// <summary>
+    ///     Removes problems thrown out by the jury, with all their submissions, so neither leaderboard counts them.
+    /// </summary>
+    private static void ApplyProblemFilters(ContestState state, PyriteConfig config)
+    {
+        if (config.HiddenProblems.Count == 0) return;
+
+        var unknown = config.HiddenProblems.Where(problemId => !state.Problems.ContainsKey(problemId)).ToList();
+        if (unknown.Count > 0)
+            throw new InvalidOperationException(
+                $"hidden_problems contains unknown problem id(s): {string.Join(", ", unknown)}");
+
+        var hiddenSet = config.HiddenProblems.ToHashSet(StringComparer.Ordinal);
+
+        state.Problems = state.Problems
+            .Where(x => !hiddenSet.Contains(x.Key))
+            .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal);
+
+        var removedSubmissionIds = state.Submissions
+            .Where(x => hiddenSet.Contains(x.Value.ProblemId))
+            .Select(x => x.Key)
+            .ToHashSet(StringComparer.Ordinal);
+
+        if (removedSubmissionIds.Count == 0) return;
+
+        state.Submissions = state.Submissions
+            .Where(x => !removedSubmissionIds.Contains(x.Key))
+            .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal);
+
+        state.Judgements = state.Judgements
+            .Where(x => !removedSubmissionIds.Contains(x.Value.SubmissionId))
+            .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal);
+    }
+
     private static void ApplyTeamGroupRemap(ContestState state, PyriteConfig config)
     {
         if (config.TeamGroupMap.Count == 0) return;
@@ -76,6 +175,27 @@ public static class ContestProcessor
                 $"Invalid team_group_map entries ({errors.Count}): {string.Join(" | ", errors)}");
     }
 
+    /// <summary>
+    ///     Renames groups from <c>[group_names]</c> in place, so every category label (medal setup, exports, champion
+    ///     citations) shows the override. Team names are applied when the leaderboards are built.
+    /// </summary>
+    private static void ApplyNameOverrides(ContestState state, PyriteConfig config)
+    {
+        var errors = config.TeamNames.Keys
+            .Where(teamId => !state.Teams.ContainsKey(teamId))
+            .Select(teamId => $"team_names team '{teamId}' does not exist in event feed")
+            .Concat(config.GroupNames.Keys
+                .Where(groupId => !state.Groups.ContainsKey(groupId))
+                .Select(groupId => $"group_names group '{groupId}' does not exist in event feed"))
+            .ToList();
+
+        if (errors.Count > 0)
+            throw new InvalidOperationException(
+                $"Invalid name overrides ({errors.Count}): {string.Join(" | ", errors)}");
+
+        foreach (var (groupId, name) in config.GroupNames) state.Groups[groupId].Name = name;
+    }
+
     private static void ValidateAllSubmissionsJudged(ContestState state)
     {
         var judgedSubmissionIds = state.Judgements.Values
@@ -108,7 +228,7 @@ public static class ContestProcessor
                 $"Invalid team group data for {issues.Count} team(s): {string.Join(" | ", issues)}");
     }
 
-    private static Dictionary<string, TeamStatus> BuildInitialTeamStatusMap(ContestState state)
+    private static Dictionary<string, TeamStatus> BuildInitialTeamStatusMap(ContestState state, PyriteConfig config)
     {
         var teamStatusMap = new Dictionary<string, TeamStatus>(StringComparer.Ordinal);
 
@@ -123,7 +243,7 @@ public static class ContestProcessor
             var organizationId = team.OrganizationId
                                  ?? throw new InvalidOperationException($"Missing organization_id for team {team.Id}.");
 
-            teamStatusMap[team.Id] = new TeamStatus(team.Id, team.Name, organizationId, sortorder);
+            teamStatusMap[team.Id] = new TeamStatus(team.Id, GetTeamName(team, config), organizationId, sortorder);
         }
 
         return teamStatusMap;
@@ -192,11 +312,11 @@ public static class ContestProcessor
         }
     }
 
-    private static List<TeamStatus> ComputeFinalizedLeaderboard(ContestState state)
+    private static List<TeamStatus> ComputeFinalizedLeaderboard(ContestState state, PyriteConfig config)
     {
         var (contestStart, contestFreeze) = GetContestTimes(state);
 
-        var finalizedMap = BuildInitialTeamStatusMap(state);
+        var finalizedMap = BuildInitialTeamStatusMap(state, config);
         ApplyJudgementsToStatusMap(state, finalizedMap, contestStart, contestFreeze);
 
         RecomputeTeamTotals(finalizedMap);
@@ -0,0 +1,103 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+using System.Text;
+using System.Text.Json.Nodes;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Copies an event feed, keeping only what is left in a filtered <see cref="ContestState" />: events of removed
+///     teams, problems, submissions, judgements, and their runs and clarifications are dropped, team events carry the
+///     remapped groups, and the feed's awards are replaced by the current ones at the end. Everything else is copied
+///     as it is, so other tools see the same contest Pyrite presents.
+/// </summary>
+public static class EventFeedExport
+{
+    public static int Write(string sourcePath, ContestState state, string path)
+    {
+        if (string.Equals(Path.GetFullPath(sourcePath), Path.GetFullPath(path), StringComparison.Ordinal))
+            throw new InvalidOperationException("The filtered feed cannot overwrite the feed it is read from.");
+
+        var written = 0;
+        using var writer = new StreamWriter(path, false, new UTF8Encoding(false));
+        foreach (var line in File.ReadLines(sourcePath))
+        {
+            if (string.IsNullOrWhiteSpace(line)) continue;
+            if (JsonNode.Parse(line) is not JsonObject eventObject) continue;
+            if (!TryFilterEvent(eventObject, state)) continue;
+
+            writer.Write(eventObject.ToJsonString());
+            writer.Write('\n');
+            written += 1;
+        }
+
+        foreach (var award in state.Awards.Values
+                     .OrderBy(award => award.Order)
+                     .ThenBy(award => award.Id, StringComparer.Ordinal))
+        {
+            var data = new JsonObject
+            {
+                ["id"] = award.Id,
+                ["citation"] = award.Citation,
+                ["team_ids"] = new JsonArray(award.TeamIds.Select(teamId => (JsonNode?)JsonValue.Create(teamId)).ToArray())
+            };
+            var eventObject = new JsonObject { ["type"] = "awards", ["id"] = award.Id, ["data"] = data };
+            writer.Write(eventObject.ToJsonString());
+            writer.Write('\n');
+            written += 1;
+        }
+
+        return written;
+    }
+
+    /// <summary>
+    ///     Whether the event survives the filter; team events get their group ids rewritten in place.
+    /// </summary>
+    private static bool TryFilterEvent(JsonObject eventObject, ContestState state)
+    {
+        var type = GetString(eventObject, "type");
+        var data = eventObject["data"] as JsonObject;
+        var id = (data is null ? null : GetString(data, "id")) ?? GetString(eventObject, "id");
+
+        switch (type)
+        {
+            case "awards":
+                return false;
+            case "teams":
+                if (id is null || !state.Teams.TryGetValue(id, out var team)) return false;
+
+                if (data is not null)
+                    data["group_ids"] = new JsonArray(team.GroupIds.Select(groupId => (JsonNode?)JsonValue.Create(groupId)).ToArray());
+                return true;
+            case "problems":
+                return id is not null && state.Problems.ContainsKey(id);
+            case "submissions":
+                return id is not null && state.Submissions.ContainsKey(id);
+            case "judgements":
+                return id is not null && state.Judgements.ContainsKey(id);
+            case "runs":
+                return data is null || IsKept(GetString(data, "judgement_id"), state.Judgements);
+            case "accounts":
+                return data is null || IsKept(GetString(data, "team_id"), state.Teams);
+            case "clarifications":
+                return data is null ||
+                       (IsKept(GetString(data, "from_team_id"), state.Teams) &&
+                        IsKept(GetString(data, "to_team_id"), state.Teams));
+            default:
+                return true;
+        }
+    }
+
+    private static bool IsKept<T>(string? id, IReadOnlyDictionary<string, T> kept)
+    {
+        return string.IsNullOrEmpty(id) || kept.ContainsKey(id);
+    }
+
+    private static string? GetString(JsonObject node, string key)
+    {
+        return node[key] is JsonValue value && value.TryGetValue<string>(out var text) ? text : null;
+    }
+}
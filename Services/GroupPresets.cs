@@ -0,0 +1,56 @@
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.IO;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Saved category selections by name, kept in <c>group-presets.json</c> under the user's application data folder
+///     next to <see cref="RecentFolders" />, so a preset saved for one contest is offered for the next one. A missing or
+///     unreadable file is treated as no presets.
+/// </summary>
+public static class GroupPresets
+{
+    public static string FilePath =>
+        Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.ApplicationData), "Pyrite",
+            "group-presets.json");
+
+    public static Dictionary<string, List<string>> Load()
+    {
+        try
+        {
+            if (!File.Exists(FilePath)) return new Dictionary<string, List<string>>(StringComparer.Ordinal);
+
+            var presets = JsonSerializer.Deserialize(File.ReadAllText(FilePath),
+                GroupPresetsJsonContext.Default.DictionaryStringListString);
+            return presets is null
+                ? new Dictionary<string, List<string>>(StringComparer.Ordinal)
+                : new Dictionary<string, List<string>>(presets, StringComparer.Ordinal);
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException or JsonException)
+        {
+            Trace.WriteLine($"[GroupPresets] Failed to read {FilePath}: {ex.Message}");
+            return new Dictionary<string, List<string>>(StringComparer.Ordinal);
+        }
+    }
+
+    /// <summary>
+    ///     Adds or replaces the preset <paramref name="name" /> and saves the file; throws when it cannot be written.
+    /// </summary>
+    public static void Save(string name, List<string> groupIds)
+    {
+        var presets = Load();
+        presets[name] = groupIds;
+        Directory.CreateDirectory(Path.GetDirectoryName(FilePath)!);
+        File.WriteAllText(FilePath, JsonSerializer.Serialize(presets, GroupPresetsJsonContext.Default.DictionaryStringListString));
+    }
+}
+
+[JsonSourceGenerationOptions(WriteIndented = true)]
+[JsonSerializable(typeof(Dictionary<string, List<string>>))]
+internal sealed partial class GroupPresetsJsonContext : JsonSerializerContext
+{
+}
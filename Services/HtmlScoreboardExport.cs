@@ -0,0 +1,160 @@
+using Pyrite.Models;
+using System;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+using System.Net;
+using System.Text;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Writes the final standings as a single HTML file with inline styles and script, so it can be uploaded to any
+///     web server as it is. Clicking a column header sorts the table by that column.
+/// </summary>
+public static class HtmlScoreboardExport
+{
+    private const string Style =
+        """
+        body { font-family: system-ui, sans-serif; margin: 2rem; color: #222; background: #fafafa; }
+        h1 { font-size: 1.6rem; margin-bottom: 0.2rem; }
+        .subtitle { color: #666; margin-bottom: 1.5rem; }
+        table { border-collapse: collapse; background: #fff; }
+        th, td { border: 1px solid #ddd; padding: 0.3rem 0.5rem; text-align: center; white-space: nowrap; }
+        th { cursor: pointer; user-select: none; background: #eee; }
+        th.sorted-asc::after { content: " \25B2"; font-size: 0.7em; }
+        th.sorted-desc::after { content: " \25BC"; font-size: 0.7em; }
+        td.team { text-align: left; }
+        td.team .org { color: #666; font-size: 0.85em; }
+        td.solved { background: #c6efce; }
+        td.first-solve { background: #63be7b; font-weight: bold; }
+        td.failed { background: #ffc7ce; }
+        tr.gold td.rank, tr.gold td.team { background: #f3d27a; }
+        tr.silver td.rank, tr.silver td.team { background: #d0d5da; }
+        tr.bronze td.rank, tr.bronze td.team { background: #e3b58f; }
+        tr.unofficial { color: #888; }
+        .awards { color: #555; font-size: 0.85em; }
+        """;
+
+    private const string Script =
+        """
+        document.querySelectorAll("th").forEach((th, column) => th.addEventListener("click", () => {
+          const body = th.closest("table").tBodies[0];
+          const ascending = !th.classList.contains("sorted-asc");
+          document.querySelectorAll("th").forEach(other => other.classList.remove("sorted-asc", "sorted-desc"));
+          th.classList.add(ascending ? "sorted-asc" : "sorted-desc");
+          const key = row => row.cells[column].dataset.sort ?? row.cells[column].textContent.trim();
+          const rows = Array.from(body.rows).sort((a, b) => {
+            const x = key(a), y = key(b);
+            const nx = parseFloat(x), ny = parseFloat(y);
+            const order = !isNaN(nx) && !isNaN(ny) ? nx - ny : x.localeCompare(y);
+            return ascending ? order : -order;
+          });
+          rows.forEach(row => body.appendChild(row));
+        }));
+        """;
+
+    public static void Write(ContestState state, string path)
+    {
+        var problems = StandingsExport.GetOrderedProblems(state);
+        var rows = StandingsExport.BuildRows(state);
+        var title = new[] { state.Contest?.FormalName, state.Contest?.Name }
+            .FirstOrDefault(name => !string.IsNullOrWhiteSpace(name)) ?? "Final Standings";
+
+        var builder = new StringBuilder();
+        builder.AppendLine("<!DOCTYPE html>");
+        builder.AppendLine("<html>");
+        builder.AppendLine("<head>");
+        builder.AppendLine("<meta charset=\"utf-8\">");
+        builder.AppendLine("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">");
+        builder.AppendLine(CultureInfo.InvariantCulture, $"<title>{Encode(title)}</title>");
+        builder.AppendLine("<style>").AppendLine(Style).AppendLine("</style>");
+        builder.AppendLine("</head>");
+        builder.AppendLine("<body>");
+        builder.AppendLine(CultureInfo.InvariantCulture, $"<h1>{Encode(title)}</h1>");
+        builder.AppendLine("<div class=\"subtitle\">Final Standings</div>");
+        builder.AppendLine("<table>");
+        builder.Append("<thead><tr><th>Rank</th><th>Team</th><th>Solved</th><th>Penalty</th>");
+        foreach (var problem in problems)
+        {
+            var label = Encode(StandingsExport.GetProblemLabel(problem));
+            var name = Encode(problem.Name);
+            builder.Append(TryGetCssColor(problem.Rgb, out var color, out var textColor)
+                ? $"<th title=\"{name}\" style=\"background: {color}; color: {textColor}\">{label}</th>"
+                : $"<th title=\"{name}\">{label}</th>");
+        }
+
+        builder.AppendLine("<th>Awards</th></tr></thead>");
+        builder.AppendLine("<tbody>");
+        for (var i = 0; i < rows.Count; i++)
+        {
+            var row = rows[i];
+            var rowClass = row.IsUnofficial ? "unofficial" : GetMedalClass(row);
+            builder.Append(string.IsNullOrEmpty(rowClass) ? "<tr>" : $"<tr class=\"{rowClass}\">");
+            // Unranked rows sort by their board position so they stay next to their neighbors.
+            builder.Append(CultureInfo.InvariantCulture,
+                $"<td class=\"rank\" data-sort=\"{i + 1}\">{row.Rank?.ToString(CultureInfo.InvariantCulture) ?? string.Empty}</td>");
+            builder.Append(CultureInfo.InvariantCulture,
+                $"<td class=\"team\" data-sort=\"{Encode(row.TeamName)}\">{Encode(row.TeamName)}");
+            if (!string.IsNullOrWhiteSpace(row.OrganizationName))
+                builder.Append(CultureInfo.InvariantCulture, $"<br><span class=\"org\">{Encode(row.OrganizationName)}</span>");
+            builder.Append("</td>");
+            builder.Append(CultureInfo.InvariantCulture, $"<td>{row.Solved}</td><td>{row.Penalty}</td>");
+            foreach (var cell in row.Cells)
+            {
+                var cellClass = cell switch
+                {
+                    { IsFirstSolve: true } => " class=\"first-solve\"",
+                    { Solved: true } => " class=\"solved\"",
+                    { Attempted: true } => " class=\"failed\"",
+                    _ => string.Empty
+                };
+                // Solved cells sort before failed ones, then by solve time.
+                var sortKey = cell.Solved ? cell.Minute ?? 0 : 100000 + cell.Attempts;
+                builder.Append(CultureInfo.InvariantCulture,
+                    $"<td{cellClass} data-sort=\"{sortKey}\">{Encode(cell.ToResultText())}</td>");
+            }
+
+            var citations = row.Awards.Select(award => Encode(AwardTemplates.ExpandCitation(
+                award.Citation, row.TeamName, row.OrganizationName, row.Rank, row.Solved)));
+            builder.Append(CultureInfo.InvariantCulture,
+                $"<td class=\"awards\">{string.Join("<br>", citations)}</td>");
+            builder.AppendLine("</tr>");
+        }
+
+        builder.AppendLine("</tbody>");
+        builder.AppendLine("</table>");
+        builder.AppendLine("<script>").AppendLine(Script).AppendLine("</script>");
+        builder.AppendLine("</body>");
+        builder.AppendLine("</html>");
+
+        File.WriteAllText(path, builder.ToString(), new UTF8Encoding(false));
+    }
+
+    private static string GetMedalClass(StandingsRow row)
+    {
+        return row.Awards
+            .Select(award => AwardTemplates.GetMedalColor(award.Id))
+            .FirstOrDefault(color => color is not null) ?? string.Empty;
+    }
+
+    private static bool TryGetCssColor(string raw, out string color, out string textColor)
+    {
+        color = string.Empty;
+        textColor = string.Empty;
+        if (string.IsNullOrWhiteSpace(raw) || !PresentationColorsConfig.TryNormalizeHexColor(raw, out var normalized))
+            return false;
+
+        // Avalonia-style #AARRGGBB; CSS reads eight digits as #RRGGBBAA, so drop the alpha.
+        color = normalized.Length == 9 ? "#" + normalized[3..] : normalized;
+        var value = Convert.ToInt32(color[1..], 16);
+        var luminance = 0.299 * (value >> 16) + 0.587 * ((value >> 8) & 0xFF) + 0.114 * (value & 0xFF);
+        textColor = luminance > 150 ? "#000" : "#fff";
+        return true;
+    }
+
+    private static string Encode(string text)
+    {
+        return WebUtility.HtmlEncode(text);
+    }
+}
@@ -0,0 +1,111 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+using System.Text;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     The ICPC Tools / CCS tab-separated result files. Teams are identified by their ICPC id, falling back to the
+///     team id. Unofficial teams are left out, since the files have no notion of unranked teams.
+/// </summary>
+public static class IcpcResultsExport
+{
+    public const string ResultsFileName = "results.tsv";
+    public const string StandingsFileName = "standings.tsv";
+
+    public static void WriteAll(ContestState state, string directory)
+    {
+        var rows = StandingsExport.BuildRows(state).Where(row => !row.IsUnofficial).ToList();
+        WriteResults(state, rows, Path.Combine(directory, ResultsFileName));
+        WriteStandings(state, rows, Path.Combine(directory, StandingsFileName));
+    }
+
+    /// <summary>
+    ///     <c>results\t1</c>, then per team: id, rank, award, solved, penalty, last accepted minute, and group winner
+    ///     citation. The award is the team's medal citation, <c>Honorable</c> (without a rank) for an honorable
+    ///     mention, and <c>Ranked</c> otherwise.
+    /// </summary>
+    private static void WriteResults(ContestState state, IReadOnlyList<StandingsRow> rows, string path)
+    {
+        var builder = new StringBuilder("results\t1\n");
+        foreach (var row in rows)
+        {
+            var medal = row.Awards.FirstOrDefault(award => AwardTemplates.IsMedalAward(award.Id));
+            var isHonorable = medal is null && row.Awards.Any(award =>
+                string.Equals(award.Id, AwardTemplates.HonorableMentionId, StringComparison.Ordinal));
+            var groupWinner = row.Awards.FirstOrDefault(award =>
+                award.Id.StartsWith("group-winner-", StringComparison.Ordinal));
+
+            AppendLine(builder,
+            [
+                GetIcpcId(state, row.TeamId),
+                isHonorable ? string.Empty : row.Rank?.ToString(CultureInfo.InvariantCulture) ?? string.Empty,
+                medal is not null ? ExpandCitation(medal.Citation, row) : isHonorable ? "Honorable" : "Ranked",
+                row.Solved.ToString(CultureInfo.InvariantCulture),
+                row.Penalty.ToString(CultureInfo.InvariantCulture),
+                GetLastAcceptedMinute(row).ToString(CultureInfo.InvariantCulture),
+                groupWinner is null ? string.Empty : ExpandCitation(groupWinner.Citation, row)
+            ]);
+        }
+
+        File.WriteAllText(path, builder.ToString(), new UTF8Encoding(false));
+    }
+
+    private static string ExpandCitation(string citation, StandingsRow row)
+    {
+        return AwardTemplates.ExpandCitation(citation, row.TeamName, row.OrganizationName, row.Rank, row.Solved);
+    }
+
+    /// <summary>
+    ///     The scoreboard layout: <c>scoreboard\t1</c>, then per team: organization, id, rank, solved, penalty, last
+    ///     accepted minute, and an attempts / solve-minute pair per problem (minute <c>0</c> when unsolved).
+    /// </summary>
+    private static void WriteStandings(ContestState state, IReadOnlyList<StandingsRow> rows, string path)
+    {
+        var builder = new StringBuilder("scoreboard\t1\n");
+        foreach (var row in rows)
+        {
+            var fields = new List<string>
+            {
+                string.IsNullOrWhiteSpace(row.OrganizationName) ? row.TeamName : row.OrganizationName,
+                GetIcpcId(state, row.TeamId),
+                row.Rank?.ToString(CultureInfo.InvariantCulture) ?? string.Empty,
+                row.Solved.ToString(CultureInfo.InvariantCulture),
+                row.Penalty.ToString(CultureInfo.InvariantCulture),
+                GetLastAcceptedMinute(row).ToString(CultureInfo.InvariantCulture)
+            };
+            foreach (var cell in row.Cells)
+            {
+                fields.Add(cell.Attempts.ToString(CultureInfo.InvariantCulture));
+                fields.Add((cell.Solved ? cell.Minute ?? 0 : 0).ToString(CultureInfo.InvariantCulture));
+            }
+
+            AppendLine(builder, fields);
+        }
+
+        File.WriteAllText(path, builder.ToString(), new UTF8Encoding(false));
+    }
+
+    private static string GetIcpcId(ContestState state, string teamId)
+    {
+        return state.Teams.TryGetValue(teamId, out var team) && !string.IsNullOrWhiteSpace(team.IcpcId)
+            ? team.IcpcId
+            : teamId;
+    }
+
+    private static long GetLastAcceptedMinute(StandingsRow row)
+    {
+        return row.Cells.Where(cell => cell.Solved).Select(cell => cell.Minute ?? 0).DefaultIfEmpty(0).Max();
+    }
+
+    private static void AppendLine(StringBuilder builder, IEnumerable<string> fields)
+    {
+        // Tabs and newlines would break the columns; names rarely contain them, so they become spaces.
+        builder.AppendJoin('\t', fields.Select(field => field.Replace('\t', ' ').Replace('\r', ' ').Replace('\n', ' ')));
+        builder.Append('\n');
+    }
+}
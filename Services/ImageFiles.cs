@@ -0,0 +1,211 @@
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Image files Pyrite shows: team photos, logos, and award media. JPEG, PNG, BMP, and WebP are decoded by the
+///     renderer directly; AVIF is not, so it is converted to PNG in memory by ffmpeg first. Videos are shown as their
+///     first frame, taken the same way. The last few conversions are kept until their file changes.
+/// </summary>
+public static class ImageFiles
+{
+    public const string DefaultFfmpegPath = "ffmpeg";
+    public const string DefaultPdftoppmPath = "pdftoppm";
+
+    /// <summary>
+    ///     How long one ffmpeg conversion may take before it is killed, so a stuck ffmpeg cannot hang the stage.
+    /// </summary>
+    public const int FfmpegTimeoutMilliseconds = 5_000;
+
+    /// <summary>
+    ///     How long pdftoppm may take to render a PDF page; a page at print resolution takes longer than a frame.
+    /// </summary>
+    public const int PdftoppmTimeoutMilliseconds = 30_000;
+
+    /// <summary>
+    ///     How many ffmpeg conversions are kept, so an award overlay shown again does not run ffmpeg again.
+    /// </summary>
+    private const int ConvertedFrameCount = 16;
+
+    private static readonly Dictionary<string, ConvertedFrame> ConvertedFrames = new(StringComparer.Ordinal);
+    private static readonly Queue<string> ConvertedFrameOrder = new();
+
+    public static readonly string[] Extensions = [".jpg", ".jpeg", ".png", ".bmp", ".webp", ".avif"];
+
+    public static readonly string[] VideoExtensions = [".mp4", ".webm", ".mkv", ".mov"];
+
+    public static bool IsImage(string path)
+    {
+        return Extensions.Contains(Path.GetExtension(path), StringComparer.OrdinalIgnoreCase);
+    }
+
+    public static bool IsVideo(string path)
+    {
+        return VideoExtensions.Contains(Path.GetExtension(path), StringComparer.OrdinalIgnoreCase);
+    }
+
+    /// <summary>
+    ///     Whether <see cref="OpenRead" /> converts <paramref name="path" /> through ffmpeg; callers keep these off the
+    ///     UI thread.
+    /// </summary>
+    public static bool NeedsFfmpeg(string path)
+    {
+        return string.Equals(Path.GetExtension(path), ".avif", StringComparison.OrdinalIgnoreCase) || IsVideo(path);
+    }
+
+    /// <summary>
+    ///     The images in <paramref name="directory" /> in ordinal file name order; empty when it does not exist or
+    ///     cannot be read.
+    /// </summary>
+    public static List<string> ListDirectory(string directory)
+    {
+        if (!Directory.Exists(directory)) return [];
+
+        try
+        {
+            return Directory.EnumerateFiles(directory)
+                .Where(IsImage)
+                .OrderBy(path => path, StringComparer.Ordinal)
+                .ToList();
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
+        {
+            Trace.WriteLine($"[ImageFiles] Failed to list images in {directory}: {ex.Message}");
+            return [];
+        }
+    }
+
+    /// <summary>
+    ///     <c>&lt;directory&gt;/&lt;name&gt;.&lt;ext&gt;</c> for the first existing extension, trying
+    ///     <paramref name="preferredExtension" /> before the others in <see cref="Extensions" />.
+    /// </summary>
+    public static string? Find(string directory, string name, string? preferredExtension)
+    {
+        var preferred = preferredExtension?.Trim().TrimStart('.');
+        var extensions = string.IsNullOrEmpty(preferred)
+            ? Extensions
+            : Extensions.Where(extension => !string.Equals(extension, $".{preferred}", StringComparison.OrdinalIgnoreCase))
+                .Prepend($".{preferred}");
+
+        return extensions
+            .Select(extension => Path.Combine(directory, name + extension))
+            .FirstOrDefault(File.Exists);
+    }
+
+    /// <summary>
+    ///     Opens <paramref name="path" /> for decoding. AVIF files and videos are run through ffmpeg and returned as PNG
+    ///     data of their first frame; this throws when ffmpeg is missing, cannot read the file, or takes longer than
+    ///     <see cref="FfmpegTimeoutMilliseconds" />.
+    /// </summary>
+    public static Stream OpenRead(string path, string? ffmpegPath)
+    {
+        if (!NeedsFfmpeg(path)) return File.OpenRead(path);
+
+        var lastWriteTime = File.GetLastWriteTimeUtc(path);
+        lock (ConvertedFrames)
+        {
+            if (ConvertedFrames.TryGetValue(path, out var cached) && cached.LastWriteTime == lastWriteTime)
+                return new MemoryStream(cached.Png, false);
+        }
+
+        var png = ConvertWithFfmpeg(path, ffmpegPath);
+        lock (ConvertedFrames)
+        {
+            if (!ConvertedFrames.ContainsKey(path)) ConvertedFrameOrder.Enqueue(path);
+            ConvertedFrames[path] = new ConvertedFrame(lastWriteTime, png);
+            if (ConvertedFrameOrder.Count > ConvertedFrameCount) ConvertedFrames.Remove(ConvertedFrameOrder.Dequeue());
+        }
+
+        return new MemoryStream(png, false);
+    }
+
+    /// <summary>
+    ///     Drops the converted frame of <paramref name="path" />, or of every file when it is <c>null</c>, so the next
+    ///     <see cref="OpenRead" /> runs ffmpeg again.
+    /// </summary>
+    public static void ForgetConverted(string? path = null)
+    {
+        lock (ConvertedFrames)
+        {
+            if (path is null)
+            {
+                ConvertedFrames.Clear();
+                ConvertedFrameOrder.Clear();
+                return;
+            }
+
+            if (!ConvertedFrames.Remove(path)) return;
+            var remaining = ConvertedFrameOrder.Where(key => key != path).ToList();
+            ConvertedFrameOrder.Clear();
+            foreach (var key in remaining) ConvertedFrameOrder.Enqueue(key);
+        }
+    }
+
+    /// <summary>
+    ///     Renders the first page of the PDF at <paramref name="path" /> to PNG data at <paramref name="dpi" /> through
+    ///     pdftoppm (Poppler); this throws when pdftoppm is missing, cannot read the file, or takes longer than
+    ///     <see cref="PdftoppmTimeoutMilliseconds" />.
+    /// </summary>
+    public static byte[] RenderPdfPage(string path, string? pdftoppmPath, int dpi)
+    {
+        var startInfo = CreateStartInfo(string.IsNullOrWhiteSpace(pdftoppmPath) ? DefaultPdftoppmPath : pdftoppmPath);
+        foreach (var argument in new[]
+                 {
+                     "-png", "-r", dpi.ToString(CultureInfo.InvariantCulture), "-f", "1", "-l", "1", "-singlefile", path, "-"
+                 })
+            startInfo.ArgumentList.Add(argument);
+
+        return RunToPng(startInfo, "pdftoppm", "render", path, PdftoppmTimeoutMilliseconds);
+    }
+
+    private static byte[] ConvertWithFfmpeg(string path, string? ffmpegPath)
+    {
+        var startInfo = CreateStartInfo(string.IsNullOrWhiteSpace(ffmpegPath) ? DefaultFfmpegPath : ffmpegPath);
+        foreach (var argument in new[]
+                 {
+                     "-loglevel", "error", "-i", path, "-frames:v", "1", "-f", "image2pipe", "-c:v", "png", "-"
+                 })
+            startInfo.ArgumentList.Add(argument);
+
+        return RunToPng(startInfo, "ffmpeg", "decode", path, FfmpegTimeoutMilliseconds);
+    }
+
+    private static ProcessStartInfo CreateStartInfo(string fileName)
+    {
+        return new ProcessStartInfo(fileName)
+        {
+            UseShellExecute = false,
+            CreateNoWindow = true,
+            RedirectStandardOutput = true,
+            RedirectStandardError = true
+        };
+    }
+
+    private static byte[] RunToPng(ProcessStartInfo startInfo, string tool, string verb, string path, int timeoutMilliseconds)
+    {
+        using var process = Process.Start(startInfo) ??
+                            throw new InvalidOperationException($"Could not start {startInfo.FileName}.");
+        var errorTask = process.StandardError.ReadToEndAsync();
+        var output = new MemoryStream();
+        var stopwatch = Stopwatch.StartNew();
+        var copyTask = process.StandardOutput.BaseStream.CopyToAsync(output);
+        if (!copyTask.Wait(timeoutMilliseconds) ||
+            !process.WaitForExit(Math.Max(0, timeoutMilliseconds - (int)stopwatch.ElapsedMilliseconds)))
+        {
+            process.Kill(true);
+            throw new TimeoutException($"{tool} took longer than {timeoutMilliseconds / 1000} s to {verb} {path}.");
+        }
+
+        if (process.ExitCode != 0 || output.Length == 0)
+            throw new InvalidDataException($"{tool} could not {verb} {path}: {errorTask.Result.Trim()}");
+
+        return output.ToArray();
+    }
+
+    private sealed record ConvertedFrame(DateTime LastWriteTime, byte[] Png);
+}
@@ -0,0 +1,373 @@
+using System;
+using System.Collections.Generic;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Bundled string tables for <see cref="Localizer" />. Keys are grouped by stage: <c>main.</c>, <c>load_data.</c>,
+///     <c>set_medal.</c>, <c>operator.</c>, <c>present.</c>, and <c>hotkey.</c> for the presenter help overlay.
+/// </summary>
+internal static class LocalizedStrings
+{
+    public static readonly IReadOnlyDictionary<string, string> English = new Dictionary<string, string>(StringComparer.Ordinal)
+    {
+        ["main.previous"] = "Back",
+        ["main.next"] = "Next",
+        ["main.launch"] = "Launch",
+        ["main.stage.load_data.title"] = "Load Data",
+        ["main.stage.load_data.description"] = "Validate CDP input, parse event-feed.ndjson, and build standings.",
+        ["main.stage.set_medal.title"] = "Set Medal",
+        ["main.stage.set_medal.description"] = "Review ranking and assign medal citations, then launch presentation.",
+        ["main.stage.unknown.title"] = "Unknown Stage",
+        ["main.stage.presentation.title"] = "Present",
+
+        ["load_data.header"] = "Stage: load_data",
+        ["load_data.folder_watermark"] = "Select or drop a CDP folder (or .zip)",
+        ["load_data.select_folder"] = "Select CDP Folder",
+        ["load_data.scoreboard_hash"] = "Finalized scoreboard hash",
+        ["load_data.copy"] = "Copy",
+        ["load_data.warnings"] = "Warnings",
+        ["load_data.errors"] = "Errors",
+        ["load_data.settings"] = "Settings",
+        ["load_data.recent_folders"] = "Recent CDP folders",
+        ["load_data.image_cache"] = "Image cache: {0} decoded images ({1:F1} MB)",
+        ["load_data.clear_image_cache"] = "Clear Image Cache",
+
+        ["settings.title"] = "Pyrite - Settings",
+        ["settings.hint"] = "Edits config.toml in the CDP folder. Leave a field empty to use the default. Lists take one item per line; team_group_map takes one 'group = category' per line and group_presets one 'name = category, category' per line.",
+        ["settings.save"] = "Save",
+        ["settings.close"] = "Close",
+
+        ["set_medal.title"] = "Set Medal",
+        ["set_medal.description"] = "Configure medal settings for the presentation.",
+        ["set_medal.save"] = "Save Medals",
+        ["set_medal.load"] = "Load Medals",
+        ["set_medal.export"] = "Export",
+        ["set_medal.export_clics"] = "Export CLICS Awards",
+        ["set_medal.export_csv"] = "Export CSV",
+        ["set_medal.export_tsv"] = "Export TSV",
+        ["set_medal.export_xlsx"] = "Export Excel",
+        ["set_medal.export_html"] = "Export HTML Scoreboard",
+        ["set_medal.export_icpc"] = "Export ICPC TSV",
+        ["set_medal.export_results_json"] = "Export Results JSON",
+        ["set_medal.export_resolver_script"] = "Export Resolver Script",
+        ["set_medal.export_event_feed"] = "Export Event Feed",
+        ["set_medal.export_statistics_markdown"] = "Export Statistics Report (Markdown)",
+        ["set_medal.export_statistics_html"] = "Export Statistics Report (HTML)",
+        ["set_medal.export_result_cards"] = "Export Result Cards",
+        ["result_card.unofficial"] = "Unofficial",
+        ["result_card.summary"] = "{0} solved · {1} penalty",
+        ["set_medal.push_contest_api"] = "Push Awards to Contest API",
+        ["set_medal.certificates"] = "Generate Certificates",
+        ["set_medal.undo"] = "Undo (Ctrl+Z)",
+        ["set_medal.redo"] = "Redo (Ctrl+Y)",
+        ["set_medal.categories"] = "Categories for medal calculation",
+        ["set_medal.select_all"] = "Select All",
+        ["set_medal.clear_all"] = "Clear All",
+        ["set_medal.groups_hint"] = "Checked categories are eligible for medals. Unchecked categories marked Show stay on the board, greyed out.",
+        ["set_medal.shown"] = "Show",
+        ["set_medal.group_preset"] = "Category preset",
+        ["set_medal.apply_group_preset"] = "Apply",
+        ["set_medal.group_preset_name"] = "Preset name",
+        ["set_medal.save_group_preset"] = "Save Preset",
+        ["set_medal.setup"] = "Medal setup and preview",
+        ["set_medal.use_percentages"] = "Medal counts as percentages of eligible teams",
+        ["set_medal.share_ties"] = "Teams tied at a boundary share the higher medal",
+        ["set_medal.gold_percent"] = "Gold %",
+        ["set_medal.silver_percent"] = "Silver %",
+        ["set_medal.bronze_percent"] = "Bronze %",
+        ["set_medal.gold_count"] = "Gold count",
+        ["set_medal.silver_count"] = "Silver count",
+        ["set_medal.bronze_count"] = "Bronze count",
+        ["set_medal.gold_citation"] = "Gold citation",
+        ["set_medal.silver_citation"] = "Silver citation",
+        ["set_medal.bronze_citation"] = "Bronze citation",
+        ["set_medal.standings"] = "Finalized standings (greyed teams are not eligible)",
+        ["set_medal.apply"] = "Apply Medals",
+        ["set_medal.current"] = "Current medals",
+        ["set_medal.reorder_hint"] = "Drag a medal onto another to reorder. A team with several medals sees them in this order.",
+        ["set_medal.delete"] = "Delete",
+        ["set_medal.edit"] = "Edit",
+        ["set_medal.editing"] = "Edit award",
+        ["set_medal.team_id"] = "Team ID",
+        ["set_medal.award_title"] = "Overlay title (optional)",
+        ["set_medal.banner_color"] = "Banner color, e.g. #80000000 (optional)",
+        ["set_medal.media_path"] = "Photo or photo folder, relative to the CDP (optional)",
+        ["set_medal.add_team"] = "Add Team",
+        ["set_medal.remove_team"] = "Remove",
+        ["set_medal.save_edit"] = "Save",
+        ["set_medal.cancel_edit"] = "Cancel",
+        ["set_medal.manual"] = "Manual custom medal",
+        ["set_medal.medal_id"] = "Medal ID",
+        ["set_medal.citation"] = "Citation",
+        ["set_medal.citation_placeholders"] = "Citations may use {team_name}, {org_name}, {rank}, and {solved}; they are filled in for each team when the award is shown.",
+        ["set_medal.teams"] = "Teams",
+        ["set_medal.team_search"] = "Search by name, organization, or ID",
+        ["set_medal.all_categories"] = "All categories",
+        ["set_medal.add_update"] = "Add/Update Medal",
+        ["set_medal.templates"] = "Award templates",
+        ["set_medal.templates_hint"] = "Computed from the finalized standings of the checked categories. Generating again replaces the earlier award.",
+        ["set_medal.template.first_solves"] = "First Solve of Each Problem",
+        ["set_medal.template.first_accepted"] = "First Accepted",
+        ["set_medal.template.last_accepted"] = "Last Accepted",
+        ["set_medal.template.group_champions"] = "Category Champions",
+        ["set_medal.group_champion_citation"] = "Category champion citation ({group} is the category name)",
+        ["set_medal.honorable_mention"] = "Honorable Mention",
+        ["set_medal.honorable_mention_min_solved"] = "Honorable mention: minimum solved problems (teams without a medal)",
+        ["set_medal.group_champion_overrides"] = "Per-category citations (leave empty to use the one above)",
+        ["set_medal.conflicts"] = "Award conflicts with the event feed",
+        ["set_medal.conflicts_hint"] = "The event feed already has awards that clash with awards set here. Choose which source wins for each.",
+        ["set_medal.conflict_feed"] = "Event feed",
+        ["set_medal.conflict_local"] = "Local",
+        ["set_medal.use_feed"] = "Use Feed",
+        ["set_medal.use_local"] = "Use Local",
+        ["set_medal.use_all_feed"] = "Use Feed for All",
+        ["set_medal.use_all_local"] = "Use Local for All",
+        ["set_medal.remove_unknown_teams"] = "Remove Unknown Teams",
+
+        ["operator.title"] = "Pyrite - Operator Console",
+        ["operator.focused_team"] = "Focused team",
+        ["operator.next_action"] = "Next action",
+        ["operator.pending_problems"] = "Pending problems (hidden results)",
+        ["operator.upcoming_awards"] = "Upcoming awards",
+        ["operator.advance"] = "Advance ({0})",
+        ["operator.undo"] = "Undo ({0})",
+        ["operator.autopilot"] = "Autopilot ({0})",
+        ["operator.skip_team"] = "Skip team ({0})",
+        ["operator.back_to_setup"] = "Back to setup ({0})",
+        ["operator.reload_team_images"] = "Reload focused team's logo and photos",
+        ["operator.reload_images"] = "Reload all images",
+
+        ["present.rank"] = "Rank",
+        ["present.seat"] = "Seat",
+        ["present.team_problems"] = "Team / Problems",
+        ["present.solved"] = "Solved",
+        ["present.time"] = "Time",
+        ["present.muted"] = "MUTED",
+        ["present.auto"] = "AUTO",
+        ["present.auto_paused"] = "AUTO (paused)",
+        ["present.champion"] = "CHAMPION",
+        ["present.champion_summary"] = "{0} solved, {1} min",
+        ["present.progress"] = "{0} teams to reveal · {1} frozen cells",
+        ["present.was_rank"] = "was #{0}",
+        ["present.hotkeys"] = "Presenter hotkeys",
+        ["present.countdown"] = "Ceremony starts in {0}",
+        ["present.summary.title"] = "Contest summary",
+        ["present.summary.climbers"] = "Biggest climbers during the freeze",
+        ["present.summary.no_climbers"] = "No team moved up during the freeze",
+        ["present.summary.fastest_solve"] = "Fastest solve",
+        ["present.summary.fastest_solve_value"] = "{0} by {1} at {2} min",
+        ["present.summary.most_attempted"] = "Most attempted problem",
+        ["present.summary.most_attempted_value"] = "{0} with {1} attempts",
+        ["present.intro.statistics"] = "Contest statistics",
+        ["present.intro.teams"] = "Teams",
+        ["present.intro.problems"] = "Problems",
+        ["present.intro.submissions"] = "Submissions",
+        ["present.intro.solved_before_freeze"] = "Solved before the freeze",
+        ["present.intro.frozen_cells"] = "Results still frozen",
+
+        ["hotkey.advance"] = "Advance: reveal, resort, show award, or move up",
+        ["hotkey.undo"] = "Undo the last step",
+        ["hotkey.click_cell"] = "Reveal that frozen cell of the focused team",
+        ["hotkey.manual_scroll"] = "Scroll the board by hand (Space scrolls back)",
+        ["hotkey.skip_team"] = "Reveal all pending cells of the focused team",
+        ["hotkey.autopilot"] = "Toggle autopilot (Space pauses it)",
+        ["hotkey.blank"] = "Blank the screen / holding slide",
+        ["hotkey.rank_comparison"] = "Show pre-freeze ranks",
+        ["hotkey.mute"] = "Mute or unmute sound effects",
+        ["hotkey.palette"] = "Cycle color palette (default, deuteranopia, high contrast)",
+        ["hotkey.animation_speed"] = "Slower / faster / reset animation speed",
+        ["hotkey.stage_scale"] = "Zoom the stage out / in / reset",
+        ["hotkey.operator_console"] = "Operator console window",
+        ["hotkey.mirror"] = "Mirror window",
+        ["hotkey.recording"] = "Start or stop recording",
+        ["hotkey.screenshot"] = "Save a screenshot",
+        ["hotkey.fullscreen"] = "Toggle full screen",
+        ["hotkey.borderless"] = "Toggle borderless window",
+        ["hotkey.next_monitor"] = "Move to the next monitor",
+        ["hotkey.reveal_all"] = "Jump to the final standings (rehearsal)",
+        ["hotkey.back_to_setup"] = "Leave the presentation and go back to the award setup",
+        ["hotkey.help"] = "Show or hide this help"
+    };
+
+    public static readonly IReadOnlyDictionary<string, string> SimplifiedChinese = new Dictionary<string, string>(StringComparer.Ordinal)
+    {
+        ["main.previous"] = "返回",
+        ["main.next"] = "下一步",
+        ["main.launch"] = "开始展示",
+        ["main.stage.load_data.title"] = "加载数据",
+        ["main.stage.load_data.description"] = "校验 CDP 目录，解析 event-feed.ndjson 并生成排行榜。",
+        ["main.stage.set_medal.title"] = "设置奖牌",
+        ["main.stage.set_medal.description"] = "核对排名并设置奖牌称号，然后开始滚榜。",
+        ["main.stage.unknown.title"] = "未知阶段",
+        ["main.stage.presentation.title"] = "滚榜展示",
+
+        ["load_data.header"] = "阶段：加载数据",
+        ["load_data.folder_watermark"] = "选择或拖入 CDP 目录（或 .zip）",
+        ["load_data.select_folder"] = "选择 CDP 目录",
+        ["load_data.scoreboard_hash"] = "最终榜单哈希",
+        ["load_data.copy"] = "复制",
+        ["load_data.warnings"] = "警告",
+        ["load_data.errors"] = "错误",
+        ["load_data.settings"] = "设置",
+        ["load_data.recent_folders"] = "最近使用的 CDP 目录",
+        ["load_data.image_cache"] = "图片缓存：{0} 张已解码图片（{1:F1} MB）",
+        ["load_data.clear_image_cache"] = "清空图片缓存",
+
+        ["settings.title"] = "Pyrite - 设置",
+        ["settings.hint"] = "编辑 CDP 目录中的 config.toml。留空即使用默认值。列表每行一项；team_group_map 每行一条 “组 = 类别”，group_presets 每行一条 “名称 = 类别, 类别”。",
+        ["settings.save"] = "保存",
+        ["settings.close"] = "关闭",
+
+        ["set_medal.title"] = "设置奖牌",
+        ["set_medal.description"] = "为滚榜展示配置奖牌。",
+        ["set_medal.save"] = "保存奖牌",
+        ["set_medal.load"] = "加载奖牌",
+        ["set_medal.export"] = "导出",
+        ["set_medal.export_clics"] = "导出 CLICS 奖项",
+        ["set_medal.export_csv"] = "导出 CSV",
+        ["set_medal.export_tsv"] = "导出 TSV",
+        ["set_medal.export_xlsx"] = "导出 Excel",
+        ["set_medal.export_html"] = "导出 HTML 榜单",
+        ["set_medal.export_icpc"] = "导出 ICPC TSV",
+        ["set_medal.export_results_json"] = "导出结果 JSON",
+        ["set_medal.export_resolver_script"] = "导出揭榜脚本",
+        ["set_medal.export_event_feed"] = "导出事件流",
+        ["set_medal.export_statistics_markdown"] = "导出统计报告（Markdown）",
+        ["set_medal.export_statistics_html"] = "导出统计报告（HTML）",
+        ["set_medal.export_result_cards"] = "导出成绩卡片",
+        ["result_card.unofficial"] = "非正式",
+        ["result_card.summary"] = "解题 {0} · 罚时 {1}",
+        ["set_medal.push_contest_api"] = "推送奖项到 Contest API",
+        ["set_medal.certificates"] = "生成证书",
+        ["set_medal.undo"] = "撤销 (Ctrl+Z)",
+        ["set_medal.redo"] = "重做 (Ctrl+Y)",
+        ["set_medal.categories"] = "参与奖牌计算的组别",
+        ["set_medal.select_all"] = "全选",
+        ["set_medal.clear_all"] = "全不选",
+        ["set_medal.groups_hint"] = "勾选的组别参与奖牌计算。未勾选但标记为显示的组别仍会以灰色显示在榜单上。",
+        ["set_medal.shown"] = "显示",
+        ["set_medal.group_preset"] = "组别预设",
+        ["set_medal.apply_group_preset"] = "应用",
+        ["set_medal.group_preset_name"] = "预设名称",
+        ["set_medal.save_group_preset"] = "保存预设",
+        ["set_medal.setup"] = "奖牌设置与预览",
+        ["set_medal.use_percentages"] = "按参评队伍百分比计算奖牌数",
+        ["set_medal.share_ties"] = "分数线上并列的队伍获得较高奖牌",
+        ["set_medal.gold_percent"] = "金牌 %",
+        ["set_medal.silver_percent"] = "银牌 %",
+        ["set_medal.bronze_percent"] = "铜牌 %",
+        ["set_medal.gold_count"] = "金牌数量",
+        ["set_medal.silver_count"] = "银牌数量",
+        ["set_medal.bronze_count"] = "铜牌数量",
+        ["set_medal.gold_citation"] = "金牌称号",
+        ["set_medal.silver_citation"] = "银牌称号",
+        ["set_medal.bronze_citation"] = "铜牌称号",
+        ["set_medal.standings"] = "最终排名（灰色队伍不参与评奖）",
+        ["set_medal.apply"] = "应用奖牌",
+        ["set_medal.current"] = "当前奖牌",
+        ["set_medal.reorder_hint"] = "将奖项拖到另一个奖项上即可调整顺序。获得多个奖项的队伍将按此顺序展示。",
+        ["set_medal.delete"] = "删除",
+        ["set_medal.edit"] = "编辑",
+        ["set_medal.editing"] = "编辑奖项",
+        ["set_medal.team_id"] = "队伍 ID",
+        ["set_medal.award_title"] = "展示标题（可选）",
+        ["set_medal.banner_color"] = "横幅颜色，如 #80000000（可选）",
+        ["set_medal.media_path"] = "照片或照片文件夹，相对于 CDP 目录（可选）",
+        ["set_medal.add_team"] = "添加队伍",
+        ["set_medal.remove_team"] = "移除",
+        ["set_medal.save_edit"] = "保存",
+        ["set_medal.cancel_edit"] = "取消",
+        ["set_medal.manual"] = "手动添加奖项",
+        ["set_medal.medal_id"] = "奖项 ID",
+        ["set_medal.citation"] = "称号",
+        ["set_medal.citation_placeholders"] = "称号中可使用 {team_name}、{org_name}、{rank} 和 {solved}，展示奖项时会替换为各队伍的信息。",
+        ["set_medal.teams"] = "队伍",
+        ["set_medal.team_search"] = "按队名、学校或 ID 搜索",
+        ["set_medal.all_categories"] = "全部组别",
+        ["set_medal.add_update"] = "添加/更新奖项",
+        ["set_medal.templates"] = "奖项模板",
+        ["set_medal.templates_hint"] = "根据勾选组别的最终排名计算。重新生成会替换之前的奖项。",
+        ["set_medal.template.first_solves"] = "各题一血",
+        ["set_medal.template.first_accepted"] = "全场首个通过",
+        ["set_medal.template.last_accepted"] = "全场最后通过",
+        ["set_medal.template.group_champions"] = "各组冠军",
+        ["set_medal.group_champion_citation"] = "组别冠军称号（{group} 代表组别名称）",
+        ["set_medal.honorable_mention"] = "优胜奖",
+        ["set_medal.honorable_mention_min_solved"] = "优胜奖：最少解题数（未获奖牌的队伍）",
+        ["set_medal.group_champion_overrides"] = "各组别单独称号（留空则使用上方称号）",
+        ["set_medal.conflicts"] = "与事件流中的奖项冲突",
+        ["set_medal.conflicts_hint"] = "事件流中已有的奖项与此处设置的奖项冲突，请逐项选择保留哪一方。",
+        ["set_medal.conflict_feed"] = "事件流",
+        ["set_medal.conflict_local"] = "本地",
+        ["set_medal.use_feed"] = "采用事件流",
+        ["set_medal.use_local"] = "采用本地",
+        ["set_medal.use_all_feed"] = "全部采用事件流",
+        ["set_medal.use_all_local"] = "全部采用本地",
+        ["set_medal.remove_unknown_teams"] = "移除未知队伍",
+
+        ["operator.title"] = "Pyrite - 操作台",
+        ["operator.focused_team"] = "当前队伍",
+        ["operator.next_action"] = "下一步操作",
+        ["operator.pending_problems"] = "待揭晓题目（隐藏结果）",
+        ["operator.upcoming_awards"] = "即将颁发的奖项",
+        ["operator.advance"] = "前进 ({0})",
+        ["operator.undo"] = "撤销 ({0})",
+        ["operator.autopilot"] = "自动播放 ({0})",
+        ["operator.skip_team"] = "揭晓整队 ({0})",
+        ["operator.back_to_setup"] = "返回设置 ({0})",
+        ["operator.reload_team_images"] = "重新加载当前队伍的标志和照片",
+        ["operator.reload_images"] = "重新加载全部图片",
+
+        ["present.rank"] = "排名",
+        ["present.seat"] = "座位",
+        ["present.team_problems"] = "队伍 / 题目",
+        ["present.solved"] = "解题数",
+        ["present.time"] = "罚时",
+        ["present.muted"] = "静音",
+        ["present.auto"] = "自动",
+        ["present.auto_paused"] = "自动（已暂停）",
+        ["present.champion"] = "冠军",
+        ["present.champion_summary"] = "解出 {0} 题，罚时 {1} 分钟",
+        ["present.progress"] = "待揭晓 {0} 支队伍 · {1} 个封榜格",
+        ["present.was_rank"] = "原第 {0} 名",
+        ["present.hotkeys"] = "滚榜快捷键",
+        ["present.countdown"] = "颁奖典礼将于 {0} 后开始",
+        ["present.summary.title"] = "比赛回顾",
+        ["present.summary.climbers"] = "封榜后排名上升最多",
+        ["present.summary.no_climbers"] = "封榜后没有队伍排名上升",
+        ["present.summary.fastest_solve"] = "最快通过",
+        ["present.summary.fastest_solve_value"] = "{1} 于第 {2} 分钟通过 {0}",
+        ["present.summary.most_attempted"] = "尝试最多的题目",
+        ["present.summary.most_attempted_value"] = "{0}，共 {1} 次尝试",
+        ["present.intro.statistics"] = "比赛数据",
+        ["present.intro.teams"] = "参赛队伍",
+        ["present.intro.problems"] = "题目",
+        ["present.intro.submissions"] = "提交总数",
+        ["present.intro.solved_before_freeze"] = "封榜前通过数",
+        ["present.intro.frozen_cells"] = "待揭晓结果",
+
+        ["hotkey.advance"] = "前进：揭晓、重排、颁奖或上移",
+        ["hotkey.undo"] = "撤销上一步",
+        ["hotkey.click_cell"] = "揭晓当前队伍被点击的封榜格",
+        ["hotkey.manual_scroll"] = "手动滚动榜单（Space 滚回）",
+        ["hotkey.skip_team"] = "一次揭晓当前队伍全部封榜格",
+        ["hotkey.autopilot"] = "开关自动播放（Space 暂停）",
+        ["hotkey.blank"] = "黑屏 / 显示等待画面",
+        ["hotkey.rank_comparison"] = "显示封榜前排名",
+        ["hotkey.mute"] = "开关音效",
+        ["hotkey.palette"] = "切换配色（默认、红绿色弱、高对比度）",
+        ["hotkey.animation_speed"] = "动画减速 / 加速 / 重置",
+        ["hotkey.stage_scale"] = "缩小 / 放大 / 重置画面",
+        ["hotkey.operator_console"] = "操作台窗口",
+        ["hotkey.mirror"] = "镜像窗口",
+        ["hotkey.recording"] = "开始或停止录制",
+        ["hotkey.screenshot"] = "保存截图",
+        ["hotkey.fullscreen"] = "切换全屏",
+        ["hotkey.borderless"] = "切换无边框窗口",
+        ["hotkey.next_monitor"] = "移动到下一个显示器",
+        ["hotkey.reveal_all"] = "直接跳到最终排名（彩排用）",
+        ["hotkey.back_to_setup"] = "退出滚榜，返回奖项设置",
+        ["hotkey.help"] = "显示或隐藏本帮助"
+    };
+}
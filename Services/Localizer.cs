@@ -0,0 +1,64 @@
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Key to string lookup for UI text. The language is chosen by <c>language</c> in <c>config.toml</c> and can change
+///     after the UI is built (the config is read per CDP folder), so views subscribe to <see cref="LanguageChanged" />.
+///     Missing translations fall back to English, then to the key itself.
+/// </summary>
+public sealed class Localizer
+{
+    public const string English = "en";
+    public const string SimplifiedChinese = "zh-CN";
+
+    private static readonly IReadOnlyDictionary<string, IReadOnlyDictionary<string, string>> Tables =
+        new Dictionary<string, IReadOnlyDictionary<string, string>>(StringComparer.OrdinalIgnoreCase)
+        {
+            [English] = LocalizedStrings.English,
+            [SimplifiedChinese] = LocalizedStrings.SimplifiedChinese
+        };
+
+    private IReadOnlyDictionary<string, string> _table = LocalizedStrings.English;
+
+    private Localizer()
+    {
+    }
+
+    public static Localizer Instance { get; } = new();
+
+    public string Language { get; private set; } = English;
+
+    public string this[string key]
+    {
+        get
+        {
+            if (_table.TryGetValue(key, out var value)) return value;
+            return LocalizedStrings.English.TryGetValue(key, out var fallback) ? fallback : key;
+        }
+    }
+
+    public event Action? LanguageChanged;
+
+    public static bool IsSupported(string language)
+    {
+        return Tables.ContainsKey(language);
+    }
+
+    public string Format(string key, params object?[] args)
+    {
+        return string.Format(CultureInfo.InvariantCulture, this[key], args);
+    }
+
+    public void SetLanguage(string? language)
+    {
+        var table = Tables.GetValueOrDefault(language ?? English) ?? LocalizedStrings.English;
+        if (ReferenceEquals(table, _table)) return;
+
+        _table = table;
+        Language = ReferenceEquals(table, LocalizedStrings.English) ? English : language!;
+        LanguageChanged?.Invoke();
+    }
+}
@@ -0,0 +1,91 @@
+using Avalonia.Media.Imaging;
+using Avalonia.Platform;
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Decoded media for the present screen and the award overlay. Country flags the CDP provides for an organization
+///     (see <see cref="OrganizationLogos.ResolveCountryFlag" />) win; otherwise they come from the sprite pack embedded
+///     at <c>Assets/Flags/&lt;alpha-3&gt;.png</c>, keyed by the ISO 3166-1 alpha-3 codes used by CLICS
+///     <c>nationality</c> and <c>country</c> fields.
+/// </summary>
+public static class Media
+{
+    private const int FlagDecodeWidth = 64;
+
+    private static readonly Dictionary<string, Bitmap?> PackFlags = new(StringComparer.OrdinalIgnoreCase);
+    private static readonly Dictionary<string, Bitmap?> FileFlags = new(StringComparer.Ordinal);
+
+    public static string? ResolveCountryCode(Team? team, Organization? organization)
+    {
+        var code = !string.IsNullOrWhiteSpace(team?.Nationality) ? team.Nationality : organization?.Country;
+        return string.IsNullOrWhiteSpace(code) ? null : code.Trim().ToUpperInvariant();
+    }
+
+    /// <summary>
+    ///     The flag at <paramref name="flagPath" /> when it decodes, else the pack's flag for
+    ///     <paramref name="countryCode" />.
+    /// </summary>
+    public static Bitmap? LoadCountryFlag(string? countryCode, string? flagPath, string? ffmpegPath)
+    {
+        if (string.IsNullOrWhiteSpace(flagPath)) return LoadCountryFlag(countryCode);
+
+        if (!FileFlags.TryGetValue(flagPath, out var bitmap))
+        {
+            try
+            {
+                using var stream = ImageFiles.OpenRead(flagPath, ffmpegPath);
+                bitmap = Bitmap.DecodeToWidth(stream, FlagDecodeWidth, BitmapInterpolationMode.MediumQuality);
+            }
+            catch (Exception ex)
+            {
+                Trace.WriteLine($"[Media] Failed to load flag {flagPath}: {ex.Message}");
+            }
+
+            FileFlags[flagPath] = bitmap;
+        }
+
+        return bitmap ?? LoadCountryFlag(countryCode);
+    }
+
+    /// <summary>
+    ///     The pack's flag for <paramref name="countryCode" />; <c>null</c> when the pack has none.
+    /// </summary>
+    public static Bitmap? LoadCountryFlag(string? countryCode)
+    {
+        if (string.IsNullOrWhiteSpace(countryCode)) return null;
+        if (PackFlags.TryGetValue(countryCode, out var cached)) return cached;
+
+        Bitmap? bitmap = null;
+        var uri = new Uri($"avares://Pyrite/Assets/Flags/{countryCode.ToLowerInvariant()}.png");
+        try
+        {
+            if (AssetLoader.Exists(uri))
+            {
+                using var stream = AssetLoader.Open(uri);
+                bitmap = Bitmap.DecodeToWidth(stream, FlagDecodeWidth, BitmapInterpolationMode.MediumQuality);
+            }
+        }
+        catch (Exception ex)
+        {
+            Trace.WriteLine($"[Media] Failed to load flag {countryCode}: {ex.Message}");
+        }
+
+        PackFlags[countryCode] = bitmap;
+        return bitmap;
+    }
+
+    /// <summary>
+    ///     Forgets and disposes the flags decoded from CDP files, so a reloaded or different CDP reads them again;
+    ///     callers stop showing them first. Pack flags are kept.
+    /// </summary>
+    public static void ClearCountryFlagFiles()
+    {
+        foreach (var bitmap in FileFlags.Values) bitmap?.Dispose();
+        FileFlags.Clear();
+    }
+}
@@ -0,0 +1,92 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Finds an organization's logo file in a CDP. The feed's <c>logo</c> references come first, largest declared
+///     size first: the href as a path relative to the CDP, then the declared file name (or the href's last segment)
+///     under <c>organizations/&lt;id&gt;/</c>. After that <c>organizations/&lt;id&gt;/logo.&lt;ext&gt;</c>, then the flat
+///     <c>affiliations/&lt;id&gt;.&lt;ext&gt;</c> layout, each with <c>logo_extension</c> first and then any other
+///     <see cref="ImageFiles.Extensions" />. When none exists, the organization's <c>logo_fallbacks</c> entry or else
+///     <c>logo_fallback_path</c> is used, relative to the CDP first and the working directory second. Country flags
+///     are found the same way from the feed's <c>country_flag</c> references and
+///     <c>organizations/&lt;id&gt;/country_flag.&lt;ext&gt;</c>.
+/// </summary>
+public static class OrganizationLogos
+{
+    public static string? Resolve(string? dataPath, string? organizationId, Organization? organization,
+        PresentationConfig presentation)
+    {
+        if (!string.IsNullOrWhiteSpace(dataPath) && !string.IsNullOrWhiteSpace(organizationId))
+        {
+            var logoPath = GetCandidates(dataPath, organizationId, organization?.Logo, "logo", presentation.LogoExtension)
+                .Append(ImageFiles.Find(Path.Combine(dataPath, "affiliations"), organizationId, presentation.LogoExtension))
+                .FirstOrDefault(path => path is not null && File.Exists(path));
+            if (logoPath is not null) return logoPath;
+        }
+
+        var fallbackPath = organizationId is null ? null : presentation.LogoFallbacks.GetValueOrDefault(organizationId);
+        return ResolveConfiguredPath(dataPath, fallbackPath) ??
+               ResolveConfiguredPath(dataPath, presentation.LogoFallbackPath);
+    }
+
+    public static string? ResolveCountryFlag(string? dataPath, string? organizationId, Organization? organization)
+    {
+        if (string.IsNullOrWhiteSpace(dataPath) || string.IsNullOrWhiteSpace(organizationId)) return null;
+
+        return GetCandidates(dataPath, organizationId, organization?.CountryFlags, "country_flag", null)
+            .FirstOrDefault(path => path is not null && File.Exists(path));
+    }
+
+    private static IEnumerable<string?> GetCandidates(string dataPath, string organizationId,
+        List<OrganizationImage>? images, string fileName, string? extension)
+    {
+        var organizationPath = Path.Combine(dataPath, "organizations", organizationId);
+        foreach (var image in (images ?? []).OrderByDescending(image => image.Width))
+        {
+            var href = image.Href.Trim();
+            var isUrl = Uri.TryCreate(href, UriKind.Absolute, out var uri) && !uri.IsFile;
+            if (href.Length > 0 && !isUrl && !Path.IsPathRooted(href))
+                yield return Path.Combine(dataPath, href.Replace('/', Path.DirectorySeparatorChar));
+
+            foreach (var declaredName in new[] { image.Filename, GetHrefFileName(href, isUrl ? uri : null) }.Distinct())
+            {
+                if (!IsPlainFileName(declaredName)) continue;
+
+                yield return Path.Combine(organizationPath, declaredName);
+            }
+        }
+
+        yield return ImageFiles.Find(organizationPath, fileName, extension);
+    }
+
+    private static string? ResolveConfiguredPath(string? dataPath, string? path)
+    {
+        if (string.IsNullOrWhiteSpace(path)) return null;
+        if (Path.IsPathRooted(path)) return File.Exists(path) ? path : null;
+
+        if (!string.IsNullOrWhiteSpace(dataPath))
+        {
+            var combinedPath = Path.Combine(dataPath, path);
+            if (File.Exists(combinedPath)) return combinedPath;
+        }
+
+        return File.Exists(path) ? Path.GetFullPath(path) : null;
+    }
+
+    private static string GetHrefFileName(string href, Uri? uri)
+    {
+        var path = (uri?.AbsolutePath ?? href).TrimEnd('/');
+        return Uri.UnescapeDataString(path[(path.LastIndexOf('/') + 1)..]);
+    }
+
+    private static bool IsPlainFileName(string? fileName)
+    {
+        return !string.IsNullOrWhiteSpace(fileName) && fileName is not ("." or "..") &&
+               fileName.IndexOfAny(Path.GetInvalidFileNameChars()) < 0;
+    }
+}
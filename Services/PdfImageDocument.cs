@@ -0,0 +1,71 @@
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.IO.Compression;
+using System.Text;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Writes a one-page PDF that shows a single RGB image filling the page. Enough for rendered certificates without
+///     pulling a PDF library into the AOT build.
+/// </summary>
+public static class PdfImageDocument
+{
+    /// <param name="rgb">Packed 8-bit RGB pixels, row by row from the top.</param>
+    /// <param name="dpi">Image resolution, which sets the page size in points.</param>
+    public static void Write(string path, int width, int height, byte[] rgb, int dpi)
+    {
+        var pageWidth = width * 72.0 / dpi;
+        var pageHeight = height * 72.0 / dpi;
+        var content = Encoding.ASCII.GetBytes(string.Create(CultureInfo.InvariantCulture,
+            $"q {pageWidth:0.##} 0 0 {pageHeight:0.##} 0 0 cm /Im0 Do Q"));
+
+        byte[] compressed;
+        using (var buffer = new MemoryStream())
+        {
+            using (var zlib = new ZLibStream(buffer, CompressionLevel.Optimal, leaveOpen: true))
+                zlib.Write(rgb);
+            compressed = buffer.ToArray();
+        }
+
+        using var stream = File.Create(path);
+        var offsets = new List<long>();
+        WriteAscii(stream, "%PDF-1.4\n");
+        WriteObject(stream, offsets, "<< /Type /Catalog /Pages 2 0 R >>");
+        WriteObject(stream, offsets, "<< /Type /Pages /Kids [3 0 R] /Count 1 >>");
+        WriteObject(stream, offsets, string.Create(CultureInfo.InvariantCulture,
+            $"<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {pageWidth:0.##} {pageHeight:0.##}] /Resources << /XObject << /Im0 5 0 R >> >> /Contents 4 0 R >>"));
+        WriteStreamObject(stream, offsets, string.Empty, content);
+        WriteStreamObject(stream, offsets, string.Create(CultureInfo.InvariantCulture,
+            $"/Type /XObject /Subtype /Image /Width {width} /Height {height} /ColorSpace /DeviceRGB /BitsPerComponent 8 /Filter /FlateDecode "), compressed);
+
+        var xrefOffset = stream.Position;
+        var xref = new StringBuilder();
+        xref.Append(CultureInfo.InvariantCulture, $"xref\n0 {offsets.Count + 1}\n0000000000 65535 f \n");
+        foreach (var offset in offsets) xref.Append(CultureInfo.InvariantCulture, $"{offset:D10} 00000 n \n");
+        xref.Append(CultureInfo.InvariantCulture,
+            $"trailer\n<< /Size {offsets.Count + 1} /Root 1 0 R >>\nstartxref\n{xrefOffset}\n%%EOF\n");
+        WriteAscii(stream, xref.ToString());
+    }
+
+    private static void WriteObject(Stream stream, List<long> offsets, string body)
+    {
+        offsets.Add(stream.Position);
+        WriteAscii(stream, $"{offsets.Count} 0 obj\n{body}\nendobj\n");
+    }
+
+    private static void WriteStreamObject(Stream stream, List<long> offsets, string dictionary, byte[] data)
+    {
+        offsets.Add(stream.Position);
+        WriteAscii(stream, string.Create(CultureInfo.InvariantCulture,
+            $"{offsets.Count} 0 obj\n<< {dictionary}/Length {data.Length} >>\nstream\n"));
+        stream.Write(data);
+        WriteAscii(stream, "\nendstream\nendobj\n");
+    }
+
+    private static void WriteAscii(Stream stream, string text)
+    {
+        stream.Write(Encoding.ASCII.GetBytes(text));
+    }
+}
@@ -0,0 +1,200 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     The results of a contest as Pyrite publishes them (results export, webhook), described in
+///     <c>Docs/result-schema.md</c>. Fields are only ever added within a <see cref="SchemaVersion" />; renaming,
+///     removing, or changing the meaning of a field bumps <see cref="CurrentSchemaVersion" />.
+/// </summary>
+public sealed class PyriteResult
+{
+    public const int CurrentSchemaVersion = 1;
+
+    [JsonPropertyName("schema_version")] public int SchemaVersion { get; set; } = CurrentSchemaVersion;
+
+    [JsonPropertyName("metadata")] public PyriteResultMetadata Metadata { get; set; } = new();
+
+    /// <summary>
+    ///     In board column order.
+    /// </summary>
+    [JsonPropertyName("problems")] public List<PyriteResultProblem> Problems { get; set; } = [];
+
+    /// <summary>
+    ///     Final standings of the presented teams, top first.
+    /// </summary>
+    [JsonPropertyName("scoreboard")] public List<PyriteResultRow> Scoreboard { get; set; } = [];
+
+    [JsonPropertyName("awards")] public List<PyriteResultAward> Awards { get; set; } = [];
+
+    public static PyriteResult Build(ContestState state)
+    {
+        var problems = StandingsExport.GetOrderedProblems(state);
+        var contest = state.Contest;
+        return new PyriteResult
+        {
+            Metadata = new PyriteResultMetadata
+            {
+                ContestId = contest?.Id ?? string.Empty,
+                ContestName = contest?.Name ?? string.Empty,
+                FormalName = contest?.FormalName ?? string.Empty,
+                StartTime = contest?.StartTime,
+                EndTime = contest?.EndTime,
+                FreezeTime = contest?.ScoreboardFreezeTime,
+                PenaltyTime = contest?.PenaltyTime ?? 0,
+                GeneratedAt = DateTimeOffset.Now
+            },
+            Problems = problems
+                .Select(problem => new PyriteResultProblem
+                {
+                    Id = problem.Id,
+                    Label = StandingsExport.GetProblemLabel(problem),
+                    Name = problem.Name,
+                    Rgb = string.IsNullOrWhiteSpace(problem.Rgb) ? null : problem.Rgb
+                })
+                .ToList(),
+            Scoreboard = StandingsExport.BuildRows(state)
+                .Select(row => new PyriteResultRow
+                {
+                    Rank = row.Rank,
+                    TeamId = row.TeamId,
+                    TeamName = row.TeamName,
+                    OrganizationId = state.Teams[row.TeamId].OrganizationId,
+                    Organization = row.OrganizationName,
+                    GroupIds = state.Teams[row.TeamId].GroupIds.ToList(),
+                    Solved = row.Solved,
+                    Penalty = row.Penalty,
+                    Unofficial = row.IsUnofficial,
+                    Problems = row.Cells
+                        .Select((cell, index) => new PyriteResultCell
+                        {
+                            ProblemId = problems[index].Id,
+                            Solved = cell.Solved,
+                            Attempts = cell.Attempts,
+                            Minute = cell.Minute,
+                            FirstToSolve = cell.IsFirstSolve
+                        })
+                        .ToList()
+                })
+                .ToList(),
+            Awards = state.Awards.Values
+                .OrderBy(award => award.Order)
+                .ThenBy(award => award.Id, StringComparer.Ordinal)
+                .Select(award => new PyriteResultAward
+                {
+                    Id = award.Id,
+                    Citation = award.Citation,
+                    TeamIds = award.TeamIds.ToList()
+                })
+                .ToList()
+        };
+    }
+
+    public string ToJson()
+    {
+        return JsonSerializer.Serialize(this, PyriteResultJsonContext.Default.PyriteResult);
+    }
+}
+
+public sealed class PyriteResultMetadata
+{
+    [JsonPropertyName("contest_id")] public string ContestId { get; set; } = string.Empty;
+
+    [JsonPropertyName("contest_name")] public string ContestName { get; set; } = string.Empty;
+
+    [JsonPropertyName("formal_name")] public string FormalName { get; set; } = string.Empty;
+
+    [JsonPropertyName("start_time")] public DateTimeOffset? StartTime { get; set; }
+
+    [JsonPropertyName("end_time")] public DateTimeOffset? EndTime { get; set; }
+
+    [JsonPropertyName("freeze_time")] public DateTimeOffset? FreezeTime { get; set; }
+
+    /// <summary>
+    ///     Minutes added per rejected submission on a solved problem.
+    /// </summary>
+    [JsonPropertyName("penalty_time")] public int PenaltyTime { get; set; }
+
+    [JsonPropertyName("generated_at")] public DateTimeOffset GeneratedAt { get; set; }
+
+    [JsonPropertyName("generator")] public string Generator { get; set; } = "Pyrite";
+}
+
+public sealed class PyriteResultProblem
+{
+    [JsonPropertyName("id")] public string Id { get; set; } = string.Empty;
+
+    [JsonPropertyName("label")] public string Label { get; set; } = string.Empty;
+
+    [JsonPropertyName("name")] public string Name { get; set; } = string.Empty;
+
+    [JsonPropertyName("rgb")] public string? Rgb { get; set; }
+}
+
+public sealed class PyriteResultRow
+{
+    /// <summary>
+    ///     <c>null</c> for unofficial teams.
+    /// </summary>
+    [JsonPropertyName("rank")] public int? Rank { get; set; }
+
+    [JsonPropertyName("team_id")] public string TeamId { get; set; } = string.Empty;
+
+    [JsonPropertyName("team_name")] public string TeamName { get; set; } = string.Empty;
+
+    [JsonPropertyName("organization_id")] public string? OrganizationId { get; set; }
+
+    [JsonPropertyName("organization")] public string Organization { get; set; } = string.Empty;
+
+    [JsonPropertyName("group_ids")] public List<string> GroupIds { get; set; } = [];
+
+    [JsonPropertyName("solved")] public int Solved { get; set; }
+
+    [JsonPropertyName("penalty")] public long Penalty { get; set; }
+
+    [JsonPropertyName("unofficial")] public bool Unofficial { get; set; }
+
+    /// <summary>
+    ///     One cell per entry of <see cref="PyriteResult.Problems" />, in the same order.
+    /// </summary>
+    [JsonPropertyName("problems")] public List<PyriteResultCell> Problems { get; set; } = [];
+}
+
+public sealed class PyriteResultCell
+{
+    [JsonPropertyName("problem_id")] public string ProblemId { get; set; } = string.Empty;
+
+    [JsonPropertyName("solved")] public bool Solved { get; set; }
+
+    /// <summary>
+    ///     Submissions up to and including the first accepted one.
+    /// </summary>
+    [JsonPropertyName("attempts")] public int Attempts { get; set; }
+
+    /// <summary>
+    ///     Contest minute of the first accepted submission; <c>null</c> when unsolved.
+    /// </summary>
+    [JsonPropertyName("minute")] public long? Minute { get; set; }
+
+    [JsonPropertyName("first_to_solve")] public bool FirstToSolve { get; set; }
+}
+
+public sealed class PyriteResultAward
+{
+    [JsonPropertyName("id")] public string Id { get; set; } = string.Empty;
+
+    [JsonPropertyName("citation")] public string Citation { get; set; } = string.Empty;
+
+    [JsonPropertyName("team_ids")] public List<string> TeamIds { get; set; } = [];
+}
+
+[JsonSourceGenerationOptions(WriteIndented = true)]
+[JsonSerializable(typeof(PyriteResult))]
+internal sealed partial class PyriteResultJsonContext : JsonSerializerContext
+{
+}
@@ -0,0 +1,69 @@
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.IO;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     The CDP folders and zips that were last parsed successfully, newest first, kept in <c>recent-folders.json</c>
+///     under the user's application data folder. A missing or unreadable file is treated as an empty list.
+/// </summary>
+public static class RecentFolders
+{
+    public const int MaxCount = 8;
+
+    private static string FilePath =>
+        Path.Combine(Environment.GetFolderPath(Environment.SpecialFolder.ApplicationData), "Pyrite",
+            "recent-folders.json");
+
+    public static List<string> Load()
+    {
+        try
+        {
+            if (!File.Exists(FilePath)) return [];
+
+            var folders = JsonSerializer.Deserialize(File.ReadAllText(FilePath), RecentFoldersJsonContext.Default.ListString);
+            return (folders ?? []).Where(path => !string.IsNullOrWhiteSpace(path)).Take(MaxCount).ToList();
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException or JsonException)
+        {
+            Trace.WriteLine($"[RecentFolders] Failed to read {FilePath}: {ex.Message}");
+            return [];
+        }
+    }
+
+    /// <summary>
+    ///     Moves <paramref name="folderPath" /> to the top of the list, saves it, and returns the new list.
+    /// </summary>
+    public static List<string> Add(string folderPath)
+    {
+        var fullPath = Path.GetFullPath(folderPath);
+        var folders = Load()
+            .Where(path => !string.Equals(path, fullPath, StringComparison.Ordinal))
+            .Prepend(fullPath)
+            .Take(MaxCount)
+            .ToList();
+
+        try
+        {
+            Directory.CreateDirectory(Path.GetDirectoryName(FilePath)!);
+            File.WriteAllText(FilePath, JsonSerializer.Serialize(folders, RecentFoldersJsonContext.Default.ListString));
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
+        {
+            Trace.WriteLine($"[RecentFolders] Failed to write {FilePath}: {ex.Message}");
+        }
+
+        return folders;
+    }
+}
+
+[JsonSourceGenerationOptions(WriteIndented = true)]
+[JsonSerializable(typeof(List<string>))]
+internal sealed partial class RecentFoldersJsonContext : JsonSerializerContext
+{
+}
@@ -0,0 +1,228 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json.Serialization;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Runs the resolver the way the presentation does (bottom-up, one frozen problem per step, resort after a solve,
+///     awards once a team has nothing left to reveal) and records every step, so broadcast graphics can replay the
+///     ceremony from the same computation. Steps below <see cref="PresentationConfig.StartRank" /> are marked
+///     <c>silent</c>, since the presentation applies them before the first <c>Space</c>.
+/// </summary>
+public static class ResolverScript
+{
+    public static ResolverScriptDocument Build(ContestState state, PresentationConfig config)
+    {
+        var problems = StandingsExport.GetOrderedProblems(state);
+        var columns = problems
+            .Select((problem, index) => (problem.Id, index))
+            .ToDictionary(x => x.Id, x => x.index, StringComparer.Ordinal);
+        var rows = GetPresentedLeaderboard(state, config).Select(CloneTeamStatus).ToList();
+        var pending = rows.ToDictionary(
+            team => team.TeamId,
+            team => new Queue<string>(OrderPendingProblems(team, columns, config.RevealOrder)),
+            StringComparer.Ordinal);
+        var document = new ResolverScriptDocument
+        {
+            ContestId = state.Contest?.Id ?? string.Empty,
+            RevealOrder = config.RevealOrder.ToString(),
+            InitialOrder = rows.Select(team => team.TeamId).ToList()
+        };
+
+        var focus = rows.FindLastIndex(team => pending[team.TeamId].Count > 0);
+        var startIndex = config.StartRank is { } startRank ? startRank - 1 : int.MaxValue;
+        if (focus >= 0) AddStep(document, "focus", rows[focus].TeamId, focus, focus > startIndex);
+
+        while (focus >= 0)
+        {
+            var team = rows[focus];
+            var silent = focus > startIndex;
+            if (pending[team.TeamId].TryDequeue(out var problemId))
+            {
+                var solved = team.ProblemStats.TryGetValue(problemId, out var stat) && ApplyFrozenResult(team, stat);
+                var reveal = AddStep(document, "reveal", team.TeamId, focus, silent);
+                reveal.ProblemId = problemId;
+                reveal.Solved = solved;
+                if (!solved) continue;
+
+                rows = rows.OrderBy(row => row).ToList();
+                var newIndex = rows.IndexOf(team);
+                if (newIndex == focus) continue;
+
+                var resort = AddStep(document, "resort", team.TeamId, newIndex, silent);
+                resort.FromRank = focus + 1;
+                resort.ToRank = newIndex + 1;
+                AddStep(document, "focus", rows[focus].TeamId, focus, silent);
+                continue;
+            }
+
+            if (!silent)
+            {
+                var awardIds = state.Awards.Values
+                    .Where(award => award.TeamIds.Contains(team.TeamId, StringComparer.Ordinal))
+                    .OrderBy(award => award.Order)
+                    .ThenBy(award => award.Id, StringComparer.Ordinal)
+                    .Select(award => award.Id)
+                    .ToList();
+                if (awardIds.Count > 0) AddStep(document, "award", team.TeamId, focus, silent).AwardIds = awardIds;
+            }
+
+            if (focus == 0) break;
+
+            focus -= 1;
+            AddStep(document, "focus", rows[focus].TeamId, focus, focus > startIndex);
+        }
+
+        document.FinalOrder = rows.Select(team => team.TeamId).ToList();
+        return document;
+    }
+
+    private static ResolverStep AddStep(ResolverScriptDocument document, string type, string teamId, int index, bool silent)
+    {
+        var step = new ResolverStep
+        {
+            Index = document.Steps.Count,
+            Type = type,
+            TeamId = teamId,
+            Rank = index + 1,
+            Silent = silent
+        };
+        document.Steps.Add(step);
+        return step;
+    }
+
+    /// <summary>
+    ///     Same trimming as the presentation's <c>present_top_teams</c>.
+    /// </summary>
+    private static List<TeamStatus> GetPresentedLeaderboard(ContestState state, PresentationConfig config)
+    {
+        if (config.PresentTopTeams is not { } topTeams || topTeams >= state.LeaderboardPreFreeze.Count)
+            return state.LeaderboardPreFreeze;
+
+        var presentableTeamIds = state.LeaderboardPreFreeze
+            .Select(team => team.TeamId)
+            .ToHashSet(StringComparer.Ordinal);
+        var presentedTeamIds = state.LeaderboardFinalized
+            .Where(team => presentableTeamIds.Contains(team.TeamId))
+            .Take(topTeams)
+            .Select(team => team.TeamId)
+            .ToHashSet(StringComparer.Ordinal);
+        return state.LeaderboardPreFreeze.Where(team => presentedTeamIds.Contains(team.TeamId)).ToList();
+    }
+
+    private static IEnumerable<string> OrderPendingProblems(
+        TeamStatus team,
+        IReadOnlyDictionary<string, int> columns,
+        RevealOrder revealOrder)
+    {
+        var pending = team.ProblemStats
+            .Where(kv => kv.Value.AttemptedDuringFreeze)
+            .OrderBy(kv => columns.GetValueOrDefault(kv.Key, int.MaxValue))
+            .ThenBy(kv => kv.Key, StringComparer.Ordinal);
+
+        return revealOrder switch
+        {
+            RevealOrder.LeastImpact => pending
+                .OrderBy(kv => kv.Value.Solved)
+                .ThenByDescending(kv => kv.Value.Solved ? kv.Value.Penalty : 0)
+                .Select(kv => kv.Key),
+            RevealOrder.SubmissionOrder => pending
+                .OrderBy(kv => kv.Value.LastSubmissionTime)
+                .Select(kv => kv.Key),
+            _ => pending.Select(kv => kv.Key)
+        };
+    }
+
+    private static bool ApplyFrozenResult(TeamStatus team, ProblemStat stat)
+    {
+        stat.AttemptedDuringFreeze = false;
+        if (!stat.Solved) return false;
+
+        team.TotalPoints += 1;
+        team.TotalPenalty += stat.Penalty;
+        if (stat.FirstAcTime.HasValue && (!team.LastAcTime.HasValue || stat.FirstAcTime > team.LastAcTime))
+            team.LastAcTime = stat.FirstAcTime;
+
+        return true;
+    }
+
+    private static TeamStatus CloneTeamStatus(TeamStatus source)
+    {
+        var clone = new TeamStatus(source.TeamId, source.TeamName, source.TeamAffiliation, source.Sortorder)
+        {
+            TotalPoints = source.TotalPoints,
+            TotalPenalty = source.TotalPenalty,
+            LastAcTime = source.LastAcTime
+        };
+        foreach (var (problemId, stat) in source.ProblemStats)
+            clone.ProblemStats[problemId] = new ProblemStat
+            {
+                Solved = stat.Solved,
+                AttemptedDuringFreeze = stat.AttemptedDuringFreeze,
+                Penalty = stat.Penalty,
+                SubmissionsBeforeSolved = stat.SubmissionsBeforeSolved,
+                SubmissionsDuringFreeze = stat.SubmissionsDuringFreeze,
+                FirstAcTime = stat.FirstAcTime,
+                LastSubmissionTime = stat.LastSubmissionTime
+            };
+
+        return clone;
+    }
+}
+
+public sealed class ResolverScriptDocument
+{
+    [JsonPropertyName("contest_id")] public string ContestId { get; set; } = string.Empty;
+
+    [JsonPropertyName("reveal_order")] public string RevealOrder { get; set; } = string.Empty;
+
+    /// <summary>
+    ///     Team ids in pre-freeze board order, top first.
+    /// </summary>
+    [JsonPropertyName("initial_order")] public List<string> InitialOrder { get; set; } = [];
+
+    [JsonPropertyName("steps")] public List<ResolverStep> Steps { get; set; } = [];
+
+    [JsonPropertyName("final_order")] public List<string> FinalOrder { get; set; } = [];
+}
+
+/// <summary>
+///     One step of the reveal: <c>focus</c> (the highlight moves to a team), <c>reveal</c> (a frozen problem is
+///     resolved), <c>resort</c> (the revealed team moves from <see cref="FromRank" /> to <see cref="ToRank" />), or
+///     <c>award</c>. <see cref="Rank" /> is the team's board position after the step.
+/// </summary>
+public sealed class ResolverStep
+{
+    [JsonPropertyName("index")] public int Index { get; set; }
+
+    [JsonPropertyName("type")] public string Type { get; set; } = string.Empty;
+
+    [JsonPropertyName("team_id")] public string TeamId { get; set; } = string.Empty;
+
+    [JsonPropertyName("rank")] public int Rank { get; set; }
+
+    [JsonPropertyName("silent")] public bool Silent { get; set; }
+
+    [JsonPropertyName("problem_id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? ProblemId { get; set; }
+
+    [JsonPropertyName("solved")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public bool? Solved { get; set; }
+
+    [JsonPropertyName("from_rank")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? FromRank { get; set; }
+
+    [JsonPropertyName("to_rank")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public int? ToRank { get; set; }
+
+    [JsonPropertyName("award_ids")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<string>? AwardIds { get; set; }
+}
@@ -0,0 +1,69 @@
+using Pyrite.Models;
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     One shareable result card per presented team, in final board order. Logos are looked up like on the
+///     scoreboard, see <see cref="OrganizationLogos" />.
+/// </summary>
+public static class ResultCards
+{
+    public static List<ResultCard> Build(ContestState state, string? cdpPath, PresentationConfig presentation)
+    {
+        var cards = new List<ResultCard>();
+        foreach (var row in StandingsExport.BuildRows(state))
+        {
+            var organizationName = string.IsNullOrWhiteSpace(row.OrganizationName) ? null : row.OrganizationName;
+            var organizationId = state.Teams.TryGetValue(row.TeamId, out var team) ? team.OrganizationId : null;
+            var medalColor = row.Awards
+                .Select(award => AwardTemplates.GetMedalColor(award.Id))
+                .FirstOrDefault(color => color is not null);
+            cards.Add(new ResultCard(
+                row.TeamId,
+                row.TeamName,
+                organizationName,
+                row.Rank,
+                row.Solved,
+                row.Penalty,
+                row.Cells,
+                row.Awards
+                    .Select(award => AwardTemplates.ExpandCitation(
+                        award.Citation, row.TeamName, organizationName, row.Rank, row.Solved))
+                    .ToList(),
+                medalColor,
+                OrganizationLogos.Resolve(cdpPath, organizationId,
+                    organizationId is null ? null : state.Organizations.GetValueOrDefault(organizationId),
+                    presentation)));
+        }
+
+        return cards;
+    }
+}
+
+/// <summary>
+///     <see cref="Rank" /> is <c>null</c> for unofficial teams; <see cref="Citations" /> have their placeholders filled.
+/// </summary>
+public sealed record ResultCard(
+    string TeamId,
+    string TeamName,
+    string? OrganizationName,
+    int? Rank,
+    int Solved,
+    long Penalty,
+    IReadOnlyList<StandingsCell> Cells,
+    IReadOnlyList<string> Citations,
+    string? MedalColor,
+    string? LogoPath)
+{
+    public string FileName
+    {
+        get
+        {
+            var invalid = Path.GetInvalidFileNameChars();
+            return new string(TeamId.Select(c => invalid.Contains(c) ? '_' : c).ToArray()) + ".png";
+        }
+    }
+}
@@ -0,0 +1,31 @@
+using Pyrite.Models;
+using System;
+using System.Net.Http;
+using System.Text;
+using System.Threading;
+using System.Threading.Tasks;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     POSTs the final results as a <see cref="PyriteResult" /> to <see cref="WebhookConfig.Url" />, e.g. for a school
+///     website or a chat bot.
+/// </summary>
+public static class ResultsWebhook
+{
+    private static readonly HttpClient Client = new();
+
+    public static async Task PostAsync(WebhookConfig config, ContestState state)
+    {
+        if (string.IsNullOrWhiteSpace(config.Url)) return;
+
+        using var request = new HttpRequestMessage(HttpMethod.Post, config.Url);
+        request.Content = new StringContent(PyriteResult.Build(state).ToJson(), Encoding.UTF8, "application/json");
+        if (!string.IsNullOrWhiteSpace(config.AuthHeader))
+            request.Headers.TryAddWithoutValidation(config.HeaderName, config.AuthHeader);
+
+        using var timeout = new CancellationTokenSource(TimeSpan.FromSeconds(config.TimeoutSeconds));
+        using var response = await Client.SendAsync(request, timeout.Token);
+        response.EnsureSuccessStatusCode();
+    }
+}
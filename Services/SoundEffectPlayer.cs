@@ -0,0 +1,141 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.ComponentModel;
+using System.Diagnostics;
+using System.IO;
+using System.Runtime.InteropServices;
+
+namespace Pyrite.Services;
+
+public enum SoundEffect
+{
+    PendingFocus,
+    Accepted,
+    Rejected,
+    RankJump,
+    Award
+}
+
+/// <summary>
+///     Fire-and-forget playback of presentation sound effects. Uses winmm on Windows, <c>afplay</c> on macOS and
+///     <c>paplay</c>/<c>aplay</c> elsewhere, so no audio library has to be bundled with the AOT build.
+/// </summary>
+public sealed class SoundEffectPlayer
+{
+    private const uint SndAsync = 0x0001;
+    private const uint SndNoDefault = 0x0002;
+    private const uint SndFileName = 0x00020000;
+
+    private static readonly string[] LinuxPlayers = ["paplay", "aplay"];
+
+    private readonly Dictionary<SoundEffect, string> _paths = [];
+    private string? _linuxPlayer;
+
+    public SoundEffectPlayer(SoundConfig config, string? dataPath)
+    {
+        IsMuted = config.Muted;
+        Register(SoundEffect.PendingFocus, config.PendingFocus, dataPath);
+        Register(SoundEffect.Accepted, config.Accepted, dataPath);
+        Register(SoundEffect.Rejected, config.Rejected, dataPath);
+        Register(SoundEffect.RankJump, config.RankJump, dataPath);
+        Register(SoundEffect.Award, config.Award, dataPath);
+    }
+
+    public bool IsMuted { get; set; }
+
+    public void Play(SoundEffect effect)
+    {
+        if (IsMuted || !_paths.TryGetValue(effect, out var path)) return;
+
+        try
+        {
+            if (OperatingSystem.IsWindows())
+            {
+                PlaySound(path, IntPtr.Zero, SndAsync | SndNoDefault | SndFileName);
+                return;
+            }
+
+            if (OperatingSystem.IsMacOS())
+            {
+                StartPlayer("afplay", path);
+                return;
+            }
+
+            PlayWithLinuxPlayer(path);
+        }
+        catch (Exception ex)
+        {
+            Trace.WriteLine($"[SoundEffectPlayer] Failed to play {effect} ({path}): {ex.Message}");
+        }
+    }
+
+    private void Register(SoundEffect effect, string? configuredPath, string? dataPath)
+    {
+        if (string.IsNullOrWhiteSpace(configuredPath)) return;
+
+        var resolved = ResolvePath(configuredPath, dataPath);
+        if (resolved is null)
+        {
+            Trace.WriteLine($"[SoundEffectPlayer] Sound file for {effect} not found: {configuredPath}");
+            return;
+        }
+
+        _paths[effect] = resolved;
+    }
+
+    private static string? ResolvePath(string path, string? dataPath)
+    {
+        if (Path.IsPathRooted(path)) return File.Exists(path) ? path : null;
+
+        if (!string.IsNullOrWhiteSpace(dataPath))
+        {
+            var combinedPath = Path.Combine(dataPath, path);
+            if (File.Exists(combinedPath)) return combinedPath;
+        }
+
+        return File.Exists(path) ? Path.GetFullPath(path) : null;
+    }
+
+    private void PlayWithLinuxPlayer(string path)
+    {
+        if (_linuxPlayer is not null)
+        {
+            StartPlayer(_linuxPlayer, path);
+            return;
+        }
+
+        foreach (var player in LinuxPlayers)
+        {
+            try
+            {
+                StartPlayer(player, path);
+                _linuxPlayer = player;
+                return;
+            }
+            catch (Win32Exception)
+            {
+                // Player binary not installed, try the next one.
+            }
+        }
+
+        throw new InvalidOperationException($"None of {string.Join(", ", LinuxPlayers)} is available.");
+    }
+
+    private static void StartPlayer(string fileName, string path)
+    {
+        var startInfo = new ProcessStartInfo(fileName)
+        {
+            UseShellExecute = false,
+            CreateNoWindow = true,
+            RedirectStandardOutput = false,
+            RedirectStandardError = false
+        };
+        startInfo.ArgumentList.Add(path);
+        Process.Start(startInfo)?.Dispose();
+    }
+
+    [DllImport("winmm.dll", EntryPoint = "PlaySoundW", CharSet = CharSet.Unicode)]
+    [return: MarshalAs(UnmanagedType.Bool)]
+    private static extern bool PlaySound(string? sound, IntPtr module, uint flags);
+}
@@ -0,0 +1,272 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+using System.Text;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Final standings flattened into rows for the file exports. Rows follow the finalized leaderboard restricted to
+///     the teams in the state; unofficial teams keep their place but get no rank, like on the board's medal zone.
+/// </summary>
+public static class StandingsExport
+{
+    public static IReadOnlyList<Problem> GetOrderedProblems(ContestState state)
+    {
+        return state.Problems.Values
+            .OrderBy(problem => problem.Ordinal)
+            .ThenBy(problem => problem.Label, StringComparer.Ordinal)
+            .ToList();
+    }
+
+    public static List<StandingsRow> BuildRows(ContestState state)
+    {
+        var problems = GetOrderedProblems(state);
+        var contestStart = state.Contest?.StartTime;
+        var firstSolvers = ContestProcessor.FindFirstSolvers(state.LeaderboardFinalized);
+        var rows = new List<StandingsRow>();
+        var rank = 0;
+        foreach (var team in state.LeaderboardFinalized.Where(team => state.Teams.ContainsKey(team.TeamId)))
+        {
+            var teamInfo = state.Teams[team.TeamId];
+            var isUnofficial = state.UnofficialTeamIds.Contains(team.TeamId);
+            if (!isUnofficial) rank += 1;
+
+            var cells = problems
+                .Select(problem => BuildCell(team, problem.Id, contestStart, firstSolvers))
+                .ToList();
+            var awards = state.Awards.Values
+                .Where(award => award.TeamIds.Contains(team.TeamId, StringComparer.Ordinal))
+                .OrderBy(award => award.Order)
+                .ThenBy(award => award.Id, StringComparer.Ordinal)
+                .ToList();
+
+            rows.Add(new StandingsRow(
+                isUnofficial ? null : rank,
+                team.TeamId,
+                team.TeamName,
+                GetOrganizationName(state, teamInfo),
+                string.Join("; ", teamInfo.GroupIds
+                    .Select(groupId => state.Groups.TryGetValue(groupId, out var group) ? group.Name : groupId)),
+                team.TotalPoints,
+                team.TotalPenalty,
+                isUnofficial,
+                cells,
+                awards));
+        }
+
+        return rows;
+    }
+
+    /// <summary>
+    ///     Writes one line per team with rank, team, organization, groups, solved, penalty, and a result column per
+    ///     problem (<c>attempts/minute</c> when solved, <c>-attempts</c> when not).
+    /// </summary>
+    public static void WriteDelimited(ContestState state, string path, char separator)
+    {
+        var problems = GetOrderedProblems(state);
+        var builder = new StringBuilder();
+        var header = new List<string> { "Rank", "Team ID", "Team", "Organization", "Groups", "Solved", "Penalty" };
+        header.AddRange(problems.Select(GetProblemLabel));
+        AppendDelimitedLine(builder, header, separator);
+
+        foreach (var row in BuildRows(state))
+        {
+            var fields = new List<string>
+            {
+                row.Rank?.ToString(CultureInfo.InvariantCulture) ?? string.Empty,
+                row.TeamId,
+                row.TeamName,
+                row.OrganizationName,
+                row.GroupNames,
+                row.Solved.ToString(CultureInfo.InvariantCulture),
+                row.Penalty.ToString(CultureInfo.InvariantCulture)
+            };
+            fields.AddRange(row.Cells.Select(cell => cell.ToResultText()));
+            AppendDelimitedLine(builder, fields, separator);
+        }
+
+        // The BOM lets spreadsheet applications detect UTF-8 team names.
+        File.WriteAllText(path, builder.ToString(), new UTF8Encoding(true));
+    }
+
+    /// <summary>
+    ///     Writes an Excel workbook with a standings sheet (medal rows tinted, problem results colored), a per-problem
+    ///     statistics sheet, and an awards sheet with one row per awarded team.
+    /// </summary>
+    public static void WriteXlsx(ContestState state, string path)
+    {
+        var problems = GetOrderedProblems(state);
+        var rows = BuildRows(state);
+        var workbook = new XlsxWorkbook();
+
+        var standings = workbook.AddSheet("Standings");
+        standings.ColumnWidths.AddRange([7, 14, 32, 32, 20, 8, 9]);
+        standings.ColumnWidths.AddRange(problems.Select(_ => 9d));
+        standings.AddRow(new[] { "Rank", "Team ID", "Team", "Organization", "Groups", "Solved", "Penalty" }
+            .Concat(problems.Select(GetProblemLabel))
+            .Select(text => XlsxCell.Of(text, XlsxStyle.Header)));
+        foreach (var row in rows)
+        {
+            var rowStyle = row.IsUnofficial ? XlsxStyle.Unofficial : GetMedalStyle(row);
+            var cells = new List<XlsxCell>
+            {
+                row.Rank is { } rank ? XlsxCell.Of(rank, rowStyle) : XlsxCell.Of(string.Empty, rowStyle),
+                XlsxCell.Of(row.TeamId, rowStyle),
+                XlsxCell.Of(row.TeamName, rowStyle),
+                XlsxCell.Of(row.OrganizationName, rowStyle),
+                XlsxCell.Of(row.GroupNames, rowStyle),
+                XlsxCell.Of(row.Solved, rowStyle),
+                XlsxCell.Of(row.Penalty, rowStyle)
+            };
+            cells.AddRange(row.Cells.Select(cell => XlsxCell.Of(cell.ToResultText(), cell switch
+            {
+                { IsFirstSolve: true } => XlsxStyle.FirstSolve,
+                { Solved: true } => XlsxStyle.Solved,
+                { Attempted: true } => XlsxStyle.Failed,
+                _ => XlsxStyle.Default
+            })));
+            standings.AddRow(cells);
+        }
+
+        var statistics = workbook.AddSheet("Problems");
+        statistics.ColumnWidths.AddRange([9, 32, 10, 12, 12, 16, 32]);
+        statistics.AddRow(new[] { "Problem", "Name", "Solved", "Attempted", "Submissions", "First Solve (min)", "First Solved By" }
+            .Select(text => XlsxCell.Of(text, XlsxStyle.Header)));
+        for (var i = 0; i < problems.Count; i++)
+        {
+            var problemCells = rows.Select(row => (Row: row, Cell: row.Cells[i])).ToList();
+            var firstSolves = problemCells.Where(pair => pair.Cell.IsFirstSolve).ToList();
+            var firstMinute = firstSolves.Select(pair => pair.Cell.Minute).FirstOrDefault(value => value is not null);
+            statistics.AddRow([
+                XlsxCell.Of(GetProblemLabel(problems[i])),
+                XlsxCell.Of(problems[i].Name),
+                XlsxCell.Of(problemCells.Count(pair => pair.Cell.Solved)),
+                XlsxCell.Of(problemCells.Count(pair => pair.Cell.Attempted)),
+                XlsxCell.Of(problemCells.Sum(pair => pair.Cell.Attempts)),
+                firstMinute is { } minute ? XlsxCell.Of(minute) : XlsxCell.Of(string.Empty),
+                XlsxCell.Of(string.Join("; ", firstSolves.Select(pair => pair.Row.TeamName)))
+            ]);
+        }
+
+        var awardsSheet = workbook.AddSheet("Awards");
+        awardsSheet.ColumnWidths.AddRange([24, 40, 14, 32, 7]);
+        awardsSheet.AddRow(new[] { "Award ID", "Citation", "Team ID", "Team", "Rank" }
+            .Select(text => XlsxCell.Of(text, XlsxStyle.Header)));
+        var rowsByTeamId = rows.ToDictionary(row => row.TeamId, StringComparer.Ordinal);
+        foreach (var award in state.Awards.Values
+                     .OrderBy(award => award.Order)
+                     .ThenBy(award => award.Id, StringComparer.Ordinal))
+        {
+            var style = AwardTemplates.GetMedalColor(award.Id) is { } color ? GetMedalStyle(color) : XlsxStyle.Default;
+            foreach (var teamId in award.TeamIds)
+            {
+                rowsByTeamId.TryGetValue(teamId, out var row);
+                awardsSheet.AddRow([
+                    XlsxCell.Of(award.Id, style),
+                    XlsxCell.Of(row is null
+                        ? award.Citation
+                        : AwardTemplates.ExpandCitation(award.Citation, row.TeamName, row.OrganizationName, row.Rank, row.Solved),
+                        style),
+                    XlsxCell.Of(teamId, style),
+                    XlsxCell.Of(row?.TeamName ?? string.Empty, style),
+                    row?.Rank is { } rank ? XlsxCell.Of(rank, style) : XlsxCell.Of(string.Empty, style)
+                ]);
+            }
+        }
+
+        workbook.Save(path);
+    }
+
+    public static string GetProblemLabel(Problem problem)
+    {
+        return string.IsNullOrWhiteSpace(problem.Label) ? problem.ShortName : problem.Label;
+    }
+
+    private static StandingsCell BuildCell(
+        TeamStatus team,
+        string problemId,
+        DateTimeOffset? contestStart,
+        IReadOnlyDictionary<string, HashSet<string>> firstSolvers)
+    {
+        if (!team.ProblemStats.TryGetValue(problemId, out var stat) || stat.SubmissionsBeforeSolved == 0)
+            return new StandingsCell(false, 0, null, false);
+
+        long? minute = stat.Solved && stat.FirstAcTime is { } acTime && contestStart is { } start
+            ? (long)(acTime - start).TotalMinutes
+            : null;
+        var isFirstSolve = stat.Solved && firstSolvers.TryGetValue(problemId, out var solvers) &&
+                           solvers.Contains(team.TeamId);
+        return new StandingsCell(stat.Solved, stat.SubmissionsBeforeSolved, minute, isFirstSolve);
+    }
+
+    private static XlsxStyle GetMedalStyle(StandingsRow row)
+    {
+        var color = row.Awards
+            .Select(award => AwardTemplates.GetMedalColor(award.Id))
+            .FirstOrDefault(medalColor => medalColor is not null);
+        return color is null ? XlsxStyle.Default : GetMedalStyle(color);
+    }
+
+    private static XlsxStyle GetMedalStyle(string color)
+    {
+        return color switch
+        {
+            "gold" => XlsxStyle.Gold,
+            "silver" => XlsxStyle.Silver,
+            "bronze" => XlsxStyle.Bronze,
+            _ => XlsxStyle.Default
+        };
+    }
+
+    private static string GetOrganizationName(ContestState state, Team team)
+    {
+        if (team.OrganizationId is null || !state.Organizations.TryGetValue(team.OrganizationId, out var organization))
+            return string.Empty;
+
+        return new[] { organization.FormalName, organization.Name, organization.Shortname }
+            .FirstOrDefault(name => !string.IsNullOrWhiteSpace(name)) ?? string.Empty;
+    }
+
+    private static void AppendDelimitedLine(StringBuilder builder, IEnumerable<string> fields, char separator)
+    {
+        builder.AppendJoin(separator, fields.Select(field => EscapeField(field, separator)));
+        builder.Append("\r\n");
+    }
+
+    private static string EscapeField(string field, char separator)
+    {
+        if (field.IndexOfAny([separator, '"', '\r', '\n']) < 0) return field;
+
+        return $"\"{field.Replace("\"", "\"\"", StringComparison.Ordinal)}\"";
+    }
+}
+
+public sealed record StandingsRow(
+    int? Rank,
+    string TeamId,
+    string TeamName,
+    string OrganizationName,
+    string GroupNames,
+    int Solved,
+    long Penalty,
+    bool IsUnofficial,
+    IReadOnlyList<StandingsCell> Cells,
+    IReadOnlyList<Award> Awards);
+
+public sealed record StandingsCell(bool Solved, int Attempts, long? Minute, bool IsFirstSolve)
+{
+    public bool Attempted => Attempts > 0;
+
+    public string ToResultText()
+    {
+        if (!Attempted) return string.Empty;
+
+        return Solved
+            ? $"{Attempts.ToString(CultureInfo.InvariantCulture)}/{Minute?.ToString(CultureInfo.InvariantCulture) ?? "?"}"
+            : $"-{Attempts.ToString(CultureInfo.InvariantCulture)}";
+    }
+}
@@ -0,0 +1,294 @@
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.Linq;
+using System.Net;
+using System.Text;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Contest statistics for a written report: per-problem solve rates, submissions per problem and time bucket,
+///     languages, and activity during the freeze. A submission counts as accepted when its latest judgement is of a
+///     solving judgement type; submissions without a time are left out of the heatmap and freeze figures.
+/// </summary>
+public static class StatisticsReport
+{
+    private static readonly int[] BucketSizes = [5, 10, 15, 20, 30, 60];
+    private const int MaxBuckets = 12;
+
+    public static ContestStatistics Build(ContestState state)
+    {
+        var contestStart = state.Contest?.StartTime;
+        var freezeTime = state.Contest?.ScoreboardFreezeTime;
+        var durationMinutes = Math.Max(1, (int)Math.Ceiling(state.Contest?.Duration.TotalMinutes ?? 0));
+        var bucketMinutes = ChooseBucketMinutes(durationMinutes);
+        var bucketCount = Math.Max(1, (durationMinutes + bucketMinutes - 1) / bucketMinutes);
+
+        var latestJudgements = state.Judgements.Values
+            .GroupBy(judgement => judgement.SubmissionId, StringComparer.Ordinal)
+            .ToDictionary(
+                group => group.Key,
+                group => group.OrderBy(judgement => judgement.StartTime).Last(),
+                StringComparer.Ordinal);
+        var submissions = state.Submissions.Values
+            .Where(submission => state.Teams.ContainsKey(submission.TeamId))
+            .Select(submission =>
+            {
+                var accepted = latestJudgements.TryGetValue(submission.Id, out var judgement) &&
+                               judgement.JudgementTypeId is { } typeId &&
+                               state.JudgementTypes.TryGetValue(typeId, out var type) && type.Solved;
+                int? minute = submission.Time is { } time && contestStart is { } start
+                    ? (int)Math.Floor((time - start).TotalMinutes)
+                    : null;
+                var duringFreeze = submission.Time is { } submitted && freezeTime is { } freeze && submitted >= freeze;
+                return new SubmissionInfo(submission, accepted, minute, duringFreeze);
+            })
+            .ToList();
+
+        var problems = StandingsExport.GetOrderedProblems(state)
+            .Select(problem =>
+            {
+                var problemSubmissions = submissions
+                    .Where(info => string.Equals(info.Submission.ProblemId, problem.Id, StringComparison.Ordinal))
+                    .ToList();
+                var heat = new int[bucketCount];
+                foreach (var info in problemSubmissions)
+                    if (info.Minute is { } minute && minute >= 0)
+                        heat[Math.Min(bucketCount - 1, minute / bucketMinutes)] += 1;
+
+                return new ProblemStatistics(
+                    StandingsExport.GetProblemLabel(problem),
+                    problem.Name,
+                    problemSubmissions.Count,
+                    problemSubmissions.Count(info => info.Accepted),
+                    problemSubmissions.Select(info => info.Submission.TeamId).Distinct(StringComparer.Ordinal).Count(),
+                    problemSubmissions.Where(info => info.Accepted)
+                        .Select(info => info.Submission.TeamId).Distinct(StringComparer.Ordinal).Count(),
+                    problemSubmissions.Where(info => info.Accepted).Select(info => info.Minute).Min(),
+                    problemSubmissions.Count(info => info.DuringFreeze),
+                    heat);
+            })
+            .ToList();
+
+        var languages = submissions
+            .GroupBy(info => string.IsNullOrWhiteSpace(info.Submission.LanguageId) ? "unknown" : info.Submission.LanguageId,
+                StringComparer.Ordinal)
+            .Select(group => new LanguageStatistics(
+                group.Key,
+                group.Count(),
+                group.Count(info => info.Accepted),
+                group.Select(info => info.Submission.TeamId).Distinct(StringComparer.Ordinal).Count()))
+            .OrderByDescending(language => language.Submissions)
+            .ThenBy(language => language.LanguageId, StringComparer.Ordinal)
+            .ToList();
+
+        var freezeSubmissions = submissions.Where(info => info.DuringFreeze).ToList();
+        return new ContestStatistics(
+            new[] { state.Contest?.FormalName, state.Contest?.Name }
+                .FirstOrDefault(name => !string.IsNullOrWhiteSpace(name)) ?? "Contest",
+            state.Teams.Count,
+            submissions.Count,
+            submissions.Count(info => info.Accepted),
+            bucketMinutes,
+            problems,
+            languages,
+            freezeTime is { } freezeAt && contestStart is { } startAt ? (int)(freezeAt - startAt).TotalMinutes : null,
+            freezeSubmissions.Count,
+            freezeSubmissions.Count(info => info.Accepted),
+            freezeSubmissions.Select(info => info.Submission.TeamId).Distinct(StringComparer.Ordinal).Count());
+    }
+
+    public static void WriteMarkdown(ContestStatistics statistics, string path)
+    {
+        var builder = new StringBuilder();
+        builder.AppendLine(CultureInfo.InvariantCulture, $"# {statistics.ContestName} Statistics");
+        builder.AppendLine();
+        builder.AppendLine(CultureInfo.InvariantCulture,
+            $"{statistics.TeamCount} teams, {statistics.SubmissionCount} submissions, {statistics.AcceptedCount} accepted.");
+        builder.AppendLine();
+
+        builder.AppendLine("## Problems");
+        builder.AppendLine();
+        builder.AppendLine("| Problem | Name | Submissions | Accepted | Teams Attempted | Teams Solved | Solve Rate | First Solve | During Freeze |");
+        builder.AppendLine("|---|---|---:|---:|---:|---:|---:|---:|---:|");
+        foreach (var problem in statistics.Problems)
+            builder.AppendLine(CultureInfo.InvariantCulture,
+                $"| {EscapeMarkdown(problem.Label)} | {EscapeMarkdown(problem.Name)} | {problem.Submissions} | {problem.Accepted} | {problem.TeamsAttempted} | {problem.TeamsSolved} | {FormatRate(problem.TeamsSolved, statistics.TeamCount)} | {FormatMinute(problem.FirstSolveMinute)} | {problem.FreezeSubmissions} |");
+        builder.AppendLine();
+
+        builder.AppendLine(CultureInfo.InvariantCulture,
+            $"## Submissions per {statistics.BucketMinutes} Minutes");
+        builder.AppendLine();
+        builder.Append("| Problem |");
+        for (var i = 0; i < statistics.BucketCount; i++)
+            builder.Append(CultureInfo.InvariantCulture, $" {FormatBucket(statistics, i)} |");
+        builder.AppendLine();
+        builder.Append("|---|");
+        for (var i = 0; i < statistics.BucketCount; i++) builder.Append("---:|");
+        builder.AppendLine();
+        foreach (var problem in statistics.Problems)
+            builder.AppendLine(CultureInfo.InvariantCulture,
+                $"| {EscapeMarkdown(problem.Label)} | {string.Join(" | ", problem.Heat)} |");
+        builder.AppendLine();
+
+        builder.AppendLine("## Languages");
+        builder.AppendLine();
+        builder.AppendLine("| Language | Submissions | Accepted | Teams |");
+        builder.AppendLine("|---|---:|---:|---:|");
+        foreach (var language in statistics.Languages)
+            builder.AppendLine(CultureInfo.InvariantCulture,
+                $"| {EscapeMarkdown(language.LanguageId)} | {language.Submissions} | {language.Accepted} | {language.Teams} |");
+        builder.AppendLine();
+
+        builder.AppendLine("## Freeze");
+        builder.AppendLine();
+        foreach (var (label, value) in GetFreezeLines(statistics))
+            builder.AppendLine(CultureInfo.InvariantCulture, $"- {label}: {value}");
+
+        File.WriteAllText(path, builder.ToString(), new UTF8Encoding(false));
+    }
+
+    public static void WriteHtml(ContestStatistics statistics, string path)
+    {
+        var builder = new StringBuilder();
+        var title = Encode($"{statistics.ContestName} Statistics");
+        builder.AppendLine("<!DOCTYPE html>");
+        builder.AppendLine("<html>");
+        builder.AppendLine("<head>");
+        builder.AppendLine("<meta charset=\"utf-8\">");
+        builder.AppendLine(CultureInfo.InvariantCulture, $"<title>{title}</title>");
+        builder.AppendLine("<style>");
+        builder.AppendLine("body { font-family: system-ui, sans-serif; margin: 2rem; color: #222; }");
+        builder.AppendLine("table { border-collapse: collapse; margin-bottom: 1.5rem; }");
+        builder.AppendLine("th, td { border: 1px solid #ddd; padding: 0.3rem 0.6rem; text-align: right; }");
+        builder.AppendLine("th { background: #eee; } td.text { text-align: left; }");
+        builder.AppendLine("</style>");
+        builder.AppendLine("</head>");
+        builder.AppendLine("<body>");
+        builder.AppendLine(CultureInfo.InvariantCulture, $"<h1>{title}</h1>");
+        builder.AppendLine(CultureInfo.InvariantCulture,
+            $"<p>{statistics.TeamCount} teams, {statistics.SubmissionCount} submissions, {statistics.AcceptedCount} accepted.</p>");
+
+        builder.AppendLine("<h2>Problems</h2>");
+        builder.AppendLine("<table><tr><th>Problem</th><th>Name</th><th>Submissions</th><th>Accepted</th><th>Teams Attempted</th><th>Teams Solved</th><th>Solve Rate</th><th>First Solve</th><th>During Freeze</th></tr>");
+        foreach (var problem in statistics.Problems)
+            builder.AppendLine(CultureInfo.InvariantCulture,
+                $"<tr><td class=\"text\">{Encode(problem.Label)}</td><td class=\"text\">{Encode(problem.Name)}</td><td>{problem.Submissions}</td><td>{problem.Accepted}</td><td>{problem.TeamsAttempted}</td><td>{problem.TeamsSolved}</td><td>{FormatRate(problem.TeamsSolved, statistics.TeamCount)}</td><td>{FormatMinute(problem.FirstSolveMinute)}</td><td>{problem.FreezeSubmissions}</td></tr>");
+        builder.AppendLine("</table>");
+
+        builder.AppendLine(CultureInfo.InvariantCulture,
+            $"<h2>Submissions per {statistics.BucketMinutes} Minutes</h2>");
+        builder.Append("<table><tr><th>Problem</th>");
+        for (var i = 0; i < statistics.BucketCount; i++)
+            builder.Append(CultureInfo.InvariantCulture, $"<th>{FormatBucket(statistics, i)}</th>");
+        builder.AppendLine("</tr>");
+        var maxHeat = Math.Max(1, statistics.Problems.SelectMany(problem => problem.Heat).DefaultIfEmpty(0).Max());
+        foreach (var problem in statistics.Problems)
+        {
+            builder.Append(CultureInfo.InvariantCulture, $"<tr><td class=\"text\">{Encode(problem.Label)}</td>");
+            foreach (var count in problem.Heat)
+                builder.Append(CultureInfo.InvariantCulture,
+                    $"<td style=\"background: rgba(43, 127, 255, {(double)count / maxHeat:0.##})\">{count}</td>");
+            builder.AppendLine("</tr>");
+        }
+
+        builder.AppendLine("</table>");
+
+        builder.AppendLine("<h2>Languages</h2>");
+        builder.AppendLine("<table><tr><th>Language</th><th>Submissions</th><th>Accepted</th><th>Teams</th></tr>");
+        foreach (var language in statistics.Languages)
+            builder.AppendLine(CultureInfo.InvariantCulture,
+                $"<tr><td class=\"text\">{Encode(language.LanguageId)}</td><td>{language.Submissions}</td><td>{language.Accepted}</td><td>{language.Teams}</td></tr>");
+        builder.AppendLine("</table>");
+
+        builder.AppendLine("<h2>Freeze</h2>");
+        builder.AppendLine("<ul>");
+        foreach (var (label, value) in GetFreezeLines(statistics))
+            builder.AppendLine(CultureInfo.InvariantCulture, $"<li>{Encode(label)}: {Encode(value)}</li>");
+        builder.AppendLine("</ul>");
+        builder.AppendLine("</body>");
+        builder.AppendLine("</html>");
+
+        File.WriteAllText(path, builder.ToString(), new UTF8Encoding(false));
+    }
+
+    /// <summary>
+    ///     The smallest round bucket size that splits the contest into at most <see cref="MaxBuckets" /> columns.
+    /// </summary>
+    private static int ChooseBucketMinutes(int durationMinutes)
+    {
+        foreach (var size in BucketSizes)
+            if (durationMinutes <= size * MaxBuckets)
+                return size;
+
+        return 60 * (int)Math.Ceiling(durationMinutes / (60.0 * MaxBuckets));
+    }
+
+    private static IEnumerable<(string Label, string Value)> GetFreezeLines(ContestStatistics statistics)
+    {
+        yield return ("Freeze starts at", FormatMinute(statistics.FreezeMinute));
+        yield return ("Submissions during the freeze", statistics.FreezeSubmissions.ToString(CultureInfo.InvariantCulture));
+        yield return ("Accepted during the freeze", statistics.FreezeAccepted.ToString(CultureInfo.InvariantCulture));
+        yield return ("Teams submitting during the freeze", statistics.FreezeTeams.ToString(CultureInfo.InvariantCulture));
+    }
+
+    private static string FormatBucket(ContestStatistics statistics, int index)
+    {
+        return string.Create(CultureInfo.InvariantCulture,
+            $"{index * statistics.BucketMinutes}-{(index + 1) * statistics.BucketMinutes}");
+    }
+
+    private static string FormatRate(int count, int total)
+    {
+        return total == 0 ? "-" : ((double)count / total).ToString("P0", CultureInfo.InvariantCulture);
+    }
+
+    private static string FormatMinute(int? minute)
+    {
+        return minute is { } value ? value.ToString(CultureInfo.InvariantCulture) + " min" : "-";
+    }
+
+    private static string EscapeMarkdown(string text)
+    {
+        return text.Replace("|", "\\|", StringComparison.Ordinal).Replace('\n', ' ');
+    }
+
+    private static string Encode(string text)
+    {
+        return WebUtility.HtmlEncode(text);
+    }
+
+    private sealed record SubmissionInfo(Submission Submission, bool Accepted, int? Minute, bool DuringFreeze);
+}
+
+public sealed record ContestStatistics(
+    string ContestName,
+    int TeamCount,
+    int SubmissionCount,
+    int AcceptedCount,
+    int BucketMinutes,
+    IReadOnlyList<ProblemStatistics> Problems,
+    IReadOnlyList<LanguageStatistics> Languages,
+    int? FreezeMinute,
+    int FreezeSubmissions,
+    int FreezeAccepted,
+    int FreezeTeams)
+{
+    public int BucketCount => Problems.Count == 0 ? 0 : Problems[0].Heat.Count;
+}
+
+public sealed record ProblemStatistics(
+    string Label,
+    string Name,
+    int Submissions,
+    int Accepted,
+    int TeamsAttempted,
+    int TeamsSolved,
+    int? FirstSolveMinute,
+    int FreezeSubmissions,
+    IReadOnlyList<int> Heat);
+
+public sealed record LanguageStatistics(string LanguageId, int Submissions, int Accepted, int Teams);
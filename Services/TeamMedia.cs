@@ -0,0 +1,37 @@
+using System.Collections.Generic;
+using System.IO;
+using System.Linq;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Finds a team's photos in a CDP. Two layouts are detected automatically. A <c>teams/&lt;id&gt;/</c> folder holding
+///     a <c>photo.&lt;ext&gt;</c> or <c>video.&lt;ext&gt;</c> is the ICPC CDS media layout: the photo is used, or the
+///     video's first frame when there is no photo. Any other <c>teams/&lt;id&gt;/</c> folder is a slideshow of all its
+///     images. Without a folder the flat <c>teams/&lt;id&gt;.&lt;ext&gt;</c> photo is used. Photos are looked up with
+///     <c>team_photo_extension</c> first and then any other <see cref="ImageFiles.Extensions" />.
+/// </summary>
+public static class TeamMedia
+{
+    public static List<string> ResolvePhotos(string? dataPath, string? teamId, string photoExtension)
+    {
+        if (string.IsNullOrWhiteSpace(dataPath) || string.IsNullOrWhiteSpace(teamId)) return [];
+
+        var teamsPath = Path.Combine(dataPath, "teams");
+        var teamPath = Path.Combine(teamsPath, teamId);
+        if (ImageFiles.Find(teamPath, "photo", photoExtension) is { } photo) return [photo];
+        if (FindVideo(teamPath) is { } video) return [video];
+
+        var photos = ImageFiles.ListDirectory(teamPath);
+        if (photos.Count > 0) return photos;
+
+        return ImageFiles.Find(teamsPath, teamId, photoExtension) is { } flatPhoto ? [flatPhoto] : [];
+    }
+
+    private static string? FindVideo(string teamPath)
+    {
+        return ImageFiles.VideoExtensions
+            .Select(extension => Path.Combine(teamPath, "video" + extension))
+            .FirstOrDefault(File.Exists);
+    }
+}
@@ -0,0 +1,188 @@
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.IO.Compression;
+using System.Linq;
+using System.Security;
+using System.Text;
+
+namespace Pyrite.Services;
+
+/// <summary>
+///     Just enough of the Office Open XML spreadsheet format for the standings export: inline strings, numbers, a
+///     handful of fixed cell styles, column widths, and a frozen header row. Kept dependency-free for AOT publishing.
+/// </summary>
+public sealed class XlsxWorkbook
+{
+    private readonly List<XlsxSheet> _sheets = [];
+
+    public XlsxSheet AddSheet(string name)
+    {
+        var sheet = new XlsxSheet(name.Length > 31 ? name[..31] : name);
+        _sheets.Add(sheet);
+        return sheet;
+    }
+
+    public void Save(string path)
+    {
+        using var stream = File.Create(path);
+        using var archive = new ZipArchive(stream, ZipArchiveMode.Create);
+        WriteEntry(archive, "[Content_Types].xml", BuildContentTypes());
+        WriteEntry(archive, "_rels/.rels",
+            """<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>""");
+        WriteEntry(archive, "xl/workbook.xml", BuildWorkbook());
+        WriteEntry(archive, "xl/_rels/workbook.xml.rels", BuildWorkbookRelationships());
+        WriteEntry(archive, "xl/styles.xml", Styles);
+        for (var i = 0; i < _sheets.Count; i++)
+            WriteEntry(archive, $"xl/worksheets/sheet{i + 1}.xml", _sheets[i].BuildXml());
+    }
+
+    private string BuildContentTypes()
+    {
+        var builder = new StringBuilder(
+            """<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>""");
+        for (var i = 1; i <= _sheets.Count; i++)
+            builder.Append(CultureInfo.InvariantCulture,
+                $"""<Override PartName="/xl/worksheets/sheet{i}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>""");
+        builder.Append("</Types>");
+        return builder.ToString();
+    }
+
+    private string BuildWorkbook()
+    {
+        var builder = new StringBuilder(
+            """<?xml version="1.0" encoding="UTF-8" standalone="yes"?><workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets>""");
+        for (var i = 0; i < _sheets.Count; i++)
+            builder.Append(CultureInfo.InvariantCulture,
+                $"""<sheet name="{SecurityElement.Escape(_sheets[i].Name)}" sheetId="{i + 1}" r:id="rId{i + 1}"/>""");
+        builder.Append("</sheets></workbook>");
+        return builder.ToString();
+    }
+
+    private string BuildWorkbookRelationships()
+    {
+        var builder = new StringBuilder(
+            """<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">""");
+        for (var i = 1; i <= _sheets.Count; i++)
+            builder.Append(CultureInfo.InvariantCulture,
+                $"""<Relationship Id="rId{i}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet{i}.xml"/>""");
+        builder.Append(CultureInfo.InvariantCulture,
+            $"""<Relationship Id="rId{_sheets.Count + 1}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/>""");
+        builder.Append("</Relationships>");
+        return builder.ToString();
+    }
+
+    private static void WriteEntry(ZipArchive archive, string name, string content)
+    {
+        using var writer = new StreamWriter(archive.CreateEntry(name).Open(), new UTF8Encoding(false));
+        writer.Write(content);
+    }
+
+    // cellXfs are in XlsxStyle order.
+    private const string Styles =
+        """<?xml version="1.0" encoding="UTF-8" standalone="yes"?><styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><fonts count="2"><font><sz val="11"/><name val="Calibri"/></font><font><b/><sz val="11"/><name val="Calibri"/></font></fonts><fills count="10"><fill><patternFill patternType="none"/></fill><fill><patternFill patternType="gray125"/></fill><fill><patternFill patternType="solid"><fgColor rgb="FFD9D9D9"/></patternFill></fill><fill><patternFill patternType="solid"><fgColor rgb="FFF3D27A"/></patternFill></fill><fill><patternFill patternType="solid"><fgColor rgb="FFD0D5DA"/></patternFill></fill><fill><patternFill patternType="solid"><fgColor rgb="FFE3B58F"/></patternFill></fill><fill><patternFill patternType="solid"><fgColor rgb="FFC6EFCE"/></patternFill></fill><fill><patternFill patternType="solid"><fgColor rgb="FFFFC7CE"/></patternFill></fill><fill><patternFill patternType="solid"><fgColor rgb="FF63BE7B"/></patternFill></fill><fill><patternFill patternType="solid"><fgColor rgb="FFF2F2F2"/></patternFill></fill></fills><borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders><cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs><cellXfs count="9"><xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/><xf numFmtId="0" fontId="1" fillId="2" borderId="0" xfId="0" applyFont="1" applyFill="1"/><xf numFmtId="0" fontId="0" fillId="3" borderId="0" xfId="0" applyFill="1"/><xf numFmtId="0" fontId="0" fillId="4" borderId="0" xfId="0" applyFill="1"/><xf numFmtId="0" fontId="0" fillId="5" borderId="0" xfId="0" applyFill="1"/><xf numFmtId="0" fontId="0" fillId="6" borderId="0" xfId="0" applyFill="1"/><xf numFmtId="0" fontId="0" fillId="7" borderId="0" xfId="0" applyFill="1"/><xf numFmtId="0" fontId="1" fillId="8" borderId="0" xfId="0" applyFont="1" applyFill="1"/><xf numFmtId="0" fontId="0" fillId="9" borderId="0" xfId="0" applyFill="1"/></cellXfs></styleSheet>""";
+}
+
+public sealed class XlsxSheet
+{
+    private readonly List<IReadOnlyList<XlsxCell>> _rows = [];
+
+    internal XlsxSheet(string name)
+    {
+        Name = name;
+    }
+
+    public string Name { get; }
+    public List<double> ColumnWidths { get; } = [];
+    public bool FreezeHeaderRow { get; set; } = true;
+
+    public void AddRow(IEnumerable<XlsxCell> cells)
+    {
+        _rows.Add(cells.ToList());
+    }
+
+    internal string BuildXml()
+    {
+        var builder = new StringBuilder(
+            """<?xml version="1.0" encoding="UTF-8" standalone="yes"?><worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">""");
+        if (FreezeHeaderRow && _rows.Count > 1)
+            builder.Append(
+                """<sheetViews><sheetView workbookViewId="0"><pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/></sheetView></sheetViews>""");
+
+        if (ColumnWidths.Count > 0)
+        {
+            builder.Append("<cols>");
+            for (var i = 0; i < ColumnWidths.Count; i++)
+                builder.Append(CultureInfo.InvariantCulture,
+                    $"""<col min="{i + 1}" max="{i + 1}" width="{ColumnWidths[i]}" customWidth="1"/>""");
+            builder.Append("</cols>");
+        }
+
+        builder.Append("<sheetData>");
+        for (var rowIndex = 0; rowIndex < _rows.Count; rowIndex++)
+        {
+            builder.Append(CultureInfo.InvariantCulture, $"""<row r="{rowIndex + 1}">""");
+            var row = _rows[rowIndex];
+            for (var columnIndex = 0; columnIndex < row.Count; columnIndex++)
+            {
+                var cell = row[columnIndex];
+                var reference = $"{GetColumnName(columnIndex)}{rowIndex + 1}";
+                var style = (int)cell.Style;
+                if (cell.Number is { } number)
+                    builder.Append(CultureInfo.InvariantCulture,
+                        $"""<c r="{reference}" s="{style}"><v>{number}</v></c>""");
+                else if (!string.IsNullOrEmpty(cell.Text))
+                    builder.Append(CultureInfo.InvariantCulture,
+                        $"""<c r="{reference}" s="{style}" t="inlineStr"><is><t xml:space="preserve">{EscapeText(cell.Text)}</t></is></c>""");
+                else if (cell.Style != XlsxStyle.Default)
+                    builder.Append(CultureInfo.InvariantCulture, $"""<c r="{reference}" s="{style}"/>""");
+            }
+
+            builder.Append("</row>");
+        }
+
+        builder.Append("</sheetData></worksheet>");
+        return builder.ToString();
+    }
+
+    private static string GetColumnName(int index)
+    {
+        var name = string.Empty;
+        for (var i = index + 1; i > 0; i = (i - 1) / 26) name = (char)('A' + (i - 1) % 26) + name;
+
+        return name;
+    }
+
+    private static string EscapeText(string text)
+    {
+        // Control characters other than tab and newlines are not allowed in XML.
+        var cleaned = new string(text.Where(c => c >= ' ' || c is '\t' or '\n' or '\r').ToArray());
+        return SecurityElement.Escape(cleaned);
+    }
+}
+
+public enum XlsxStyle
+{
+    Default = 0,
+    Header = 1,
+    Gold = 2,
+    Silver = 3,
+    Bronze = 4,
+    Solved = 5,
+    Failed = 6,
+    FirstSolve = 7,
+    Unofficial = 8
+}
+
+public readonly record struct XlsxCell(string? Text, double? Number, XlsxStyle Style = XlsxStyle.Default)
+{
+    public static XlsxCell Of(string text, XlsxStyle style = XlsxStyle.Default)
+    {
+        return new XlsxCell(text, null, style);
+    }
+
+    public static XlsxCell Of(double number, XlsxStyle style = XlsxStyle.Default)
+    {
+        return new XlsxCell(null, number, style);
+    }
+}
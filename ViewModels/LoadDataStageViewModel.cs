@@ -4,6 +4,7 @@ using System;
 using System.Collections.Generic;
 using System.Collections.ObjectModel;
 using System.IO;
+using System.IO.Compression;
 using System.Threading;
 using System.Threading.Tasks;
 
@@ -12,6 +13,7 @@ namespace Pyrite.ViewModels;
 public sealed class LoadDataStageViewModel : ViewModelBase
 {
     private string? _cdpPath;
+    private string? _zipPath;
     private bool _isParseSuccessful;
     private bool _isParsing;
     private PyriteConfig _loadedConfig = PyriteConfig.Default();
@@ -19,21 +21,34 @@ public sealed class LoadDataStageViewModel : ViewModelBase
     private CancellationTokenSource? _parseCts;
     private double _parseProgress;
     private string _parseStatus = "Select a CDP folder to begin.";
+    private string _scoreboardHash = string.Empty;
     private string _validationStatus = string.Empty;
+    private string _imageCacheStatus = string.Empty;
 
     public LoadDataStageViewModel()
     {
         ParseErrors = [];
         ParseWarnings = [];
+        RecentFolders = new ObservableCollection<string>(Services.RecentFolders.Load());
     }
 
     public ObservableCollection<string> ParseErrors { get; }
     public ObservableCollection<string> ParseWarnings { get; }
 
+    /// <summary>
+    ///     CDP folders and zips that were parsed successfully before, newest first.
+    /// </summary>
+    public ObservableCollection<string> RecentFolders { get; }
+
+    public bool HasRecentFolders => RecentFolders.Count > 0;
+
     public string? CdpPath
     {
         get => _cdpPath;
-        private set => SetProperty(ref _cdpPath, value);
+        private set
+        {
+            if (SetProperty(ref _cdpPath, value)) OnPropertyChanged(nameof(CanEditSettings));
+        }
     }
 
     public bool IsParsing
@@ -44,12 +59,28 @@ public sealed class LoadDataStageViewModel : ViewModelBase
             if (SetProperty(ref _isParsing, value))
             {
                 OnPropertyChanged(nameof(IsNotParsing));
+                OnPropertyChanged(nameof(CanEditSettings));
             }
         }
     }
 
     public bool IsNotParsing => !IsParsing;
 
+    /// <summary>
+    ///     The zip <see cref="CdpPath" /> was extracted from, or <c>null</c> for a folder. The extracted copy is
+    ///     temporary and replaced when the zip is loaded again, so nothing is saved into it.
+    /// </summary>
+    public string? ZipPath
+    {
+        get => _zipPath;
+        private set
+        {
+            if (SetProperty(ref _zipPath, value)) OnPropertyChanged(nameof(CanEditSettings));
+        }
+    }
+
+    public bool CanEditSettings => !IsParsing && !string.IsNullOrWhiteSpace(CdpPath) && ZipPath is null;
+
     public bool IsParseSuccessful
     {
         get => _isParseSuccessful;
@@ -74,6 +105,30 @@ public sealed class LoadDataStageViewModel : ViewModelBase
         private set => SetProperty(ref _validationStatus, value);
     }
 
+    public string ScoreboardHash
+    {
+        get => _scoreboardHash;
+        private set
+        {
+            if (SetProperty(ref _scoreboardHash, value)) OnPropertyChanged(nameof(HasScoreboardHash));
+        }
+    }
+
+    /// <summary>
+    ///     Size of the decoded image cache kept from the last presentation, set by the main window.
+    /// </summary>
+    public string ImageCacheStatus
+    {
+        get => _imageCacheStatus;
+        private set => SetProperty(ref _imageCacheStatus, value);
+    }
+
+    /// <summary>
+    ///     Raised by <see cref="ClearImageCache" />; the main window owns the cache and clears it.
+    /// </summary>
+    public event Action? ClearImageCacheRequested;
+
+    public bool HasScoreboardHash => !string.IsNullOrWhiteSpace(ScoreboardHash);
     public bool HasValidationStatus => !string.IsNullOrWhiteSpace(ValidationStatus);
     public bool HasParseErrors => ParseErrors.Count > 0;
     public bool HasParseWarnings => ParseWarnings.Count > 0;
@@ -90,9 +145,25 @@ public sealed class LoadDataStageViewModel : ViewModelBase
         private set => SetProperty(ref _loadedConfig, value);
     }
 
-    public async Task SelectCdpFolderAsync(string folderPath)
+    public void SetImageCacheSize(int count, long approxBytes)
+    {
+        ImageCacheStatus = Localizer.Instance.Format("load_data.image_cache", count, approxBytes / (1024.0 * 1024));
+    }
+
+    public void ClearImageCache()
+    {
+        ClearImageCacheRequested?.Invoke();
+    }
+
+    public Task SelectCdpFolderAsync(string folderPath)
+    {
+        return LoadCdpFolderAsync(folderPath, null);
+    }
+
+    private async Task LoadCdpFolderAsync(string folderPath, string? zipPath)
     {
         CdpPath = folderPath;
+        ZipPath = zipPath;
         ResetLoadDataState();
 
         var validationErrors = ValidateCdpFolder(folderPath);
@@ -102,12 +173,15 @@ public sealed class LoadDataStageViewModel : ViewModelBase
             return;
         }
 
-        ValidationStatus = "CDP folder validated.";
+        ValidationStatus = zipPath is null
+            ? "CDP folder validated."
+            : $"CDP extracted from {Path.GetFileName(zipPath)} to a temporary folder; extract it yourself to edit its settings.";
         OnPropertyChanged(nameof(HasValidationStatus));
 
         try
         {
             LoadedConfig = ConfigLoader.LoadIfExists(folderPath);
+            Localizer.Instance.SetLanguage(LoadedConfig.Language);
         }
         catch (Exception ex)
         {
@@ -120,6 +194,54 @@ public sealed class LoadDataStageViewModel : ViewModelBase
         await ParseEventFeedAsync(Path.Combine(folderPath, "event-feed.ndjson"));
     }
 
+    /// <summary>
+    ///     Loads a CDP folder dropped onto the window or picked from the recent list, or a <c>.zip</c> of one, which is
+    ///     extracted to a temporary folder first. A zip whose files sit in a single top-level folder is loaded from that
+    ///     folder, and the zip itself is remembered in the recent list.
+    /// </summary>
+    public async Task SelectDroppedPathAsync(string path)
+    {
+        if (Directory.Exists(path))
+        {
+            await SelectCdpFolderAsync(path);
+            return;
+        }
+
+        if (!File.Exists(path) || !string.Equals(Path.GetExtension(path), ".zip", StringComparison.OrdinalIgnoreCase))
+        {
+            ResetLoadDataState();
+            SetValidationFailure([$"Not a folder or .zip file: {path}"], "Drop a CDP folder or a .zip of one.");
+            return;
+        }
+
+        ResetLoadDataState();
+        ParseStatus = $"Extracting {Path.GetFileName(path)}...";
+        string folderPath;
+        try
+        {
+            folderPath = await Task.Run(() => ExtractCdpZip(path));
+        }
+        catch (Exception ex) when (ex is IOException or InvalidDataException or UnauthorizedAccessException)
+        {
+            SetValidationFailure([ex.Message], $"Failed to extract {Path.GetFileName(path)}.");
+            return;
+        }
+
+        await LoadCdpFolderAsync(folderPath, path);
+    }
+
+    private static string ExtractCdpZip(string zipPath)
+    {
+        var target = Path.Combine(Path.GetTempPath(), "Pyrite", Path.GetFileNameWithoutExtension(zipPath));
+        if (Directory.Exists(target)) Directory.Delete(target, true);
+
+        ZipFile.ExtractToDirectory(zipPath, target);
+        if (File.Exists(Path.Combine(target, "event-feed.ndjson"))) return target;
+
+        var directories = Directory.GetDirectories(target);
+        return directories.Length == 1 && Directory.GetFiles(target).Length == 0 ? directories[0] : target;
+    }
+
     private async Task ParseEventFeedAsync(string eventFeedPath)
     {
         _parseCts?.Cancel();
@@ -153,11 +275,13 @@ public sealed class LoadDataStageViewModel : ViewModelBase
             }
 
             LoadedContestState = result.ContestState;
+            ScoreboardHash = result.ContestState.ScoreboardHash;
             ParseProgress = 1;
             ParseStatus = result.Warnings.Count > 0
                 ? $"Parsed successfully with {result.Warnings.Count} warning(s)."
                 : "Parsed successfully with no warnings.";
             IsParseSuccessful = true;
+            if ((ZipPath ?? CdpPath) is { } loadedPath) RememberFolder(loadedPath);
         }
         catch (OperationCanceledException)
         {
@@ -173,6 +297,13 @@ public sealed class LoadDataStageViewModel : ViewModelBase
         }
     }
 
+    private void RememberFolder(string folderPath)
+    {
+        RecentFolders.Clear();
+        foreach (var path in Services.RecentFolders.Add(folderPath)) RecentFolders.Add(path);
+        OnPropertyChanged(nameof(HasRecentFolders));
+    }
+
     private static Collection<string> ValidateCdpFolder(string folderPath)
     {
         var errors = new Collection<string>();
@@ -189,8 +320,10 @@ public sealed class LoadDataStageViewModel : ViewModelBase
         var teamsPath = Path.Combine(folderPath, "teams");
         if (!Directory.Exists(teamsPath)) errors.Add("Missing required folder: teams");
 
-        var affiliationsPath = Path.Combine(folderPath, "affiliations");
-        if (!Directory.Exists(affiliationsPath)) errors.Add("Missing required folder: affiliations");
+        // Logos live either in affiliations/<id>.<ext> or in the CDP layout organizations/<id>/logo.<ext>.
+        if (!Directory.Exists(Path.Combine(folderPath, "affiliations")) &&
+            !Directory.Exists(Path.Combine(folderPath, "organizations")))
+            errors.Add("Missing required folder: affiliations (or organizations)");
 
         return errors;
     }
@@ -204,6 +337,7 @@ public sealed class LoadDataStageViewModel : ViewModelBase
         ParseProgress = 0;
         IsParseSuccessful = false;
         LoadedContestState = null;
+        ScoreboardHash = string.Empty;
 
         NotifyStatusCollectionsChanged();
     }
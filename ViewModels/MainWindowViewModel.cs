@@ -1,5 +1,7 @@
 using CommunityToolkit.Mvvm.Input;
+using Pyrite.Services;
 using System;
+using System.Collections.Generic;
 using System.ComponentModel;
 using System.Diagnostics;
 
@@ -13,15 +15,15 @@ public enum AppStage
 
 public class MainWindowViewModel : ViewModelBase
 {
-    private readonly record struct StageInfo(string Key, string Title, string Description);
+    private readonly record struct StageInfo(string Key, string TitleKey, string DescriptionKey);
     private static readonly StageInfo LoadDataInfo = new(
         "load_data",
-        "Load Data",
-        "Validate CDP input, parse event-feed.ndjson, and build standings.");
+        "main.stage.load_data.title",
+        "main.stage.load_data.description");
     private static readonly StageInfo SetMedalInfo = new(
         "set_medal",
-        "Set Medal",
-        "Review ranking and assign medal citations, then launch presentation.");
+        "main.stage.set_medal.title",
+        "main.stage.set_medal.description");
 
     private AppStage _currentStage = AppStage.LoadData;
     private bool _isPresentationActive;
@@ -36,7 +38,10 @@ public class MainWindowViewModel : ViewModelBase
         LaunchPresentationCommand = new RelayCommand(LaunchPresentation, () => CanLaunchPresentation);
         PrimaryActionCommand = new RelayCommand(ExecutePrimaryAction, () => CanExecutePrimaryAction);
         PresentationStage.ExitRequested += ExitPresentation;
+        LoadDataStage.ClearImageCacheRequested += ClearImageCache;
+        RefreshImageCacheStatus();
         LoadDataStage.PropertyChanged += OnLoadDataStagePropertyChanged;
+        Localizer.Instance.LanguageChanged += OnLanguageChanged;
     }
 
     public RelayCommand PreviousStageCommand { get; }
@@ -59,7 +64,9 @@ public class MainWindowViewModel : ViewModelBase
                 OnPropertyChanged(nameof(StageDescription));
                 OnPropertyChanged(nameof(IsLoadDataStage));
                 OnPropertyChanged(nameof(IsSetMedalStage));
+                OnPropertyChanged(nameof(Breadcrumbs));
                 NotifyWorkflowStateChanged();
+                RefreshImageCacheStatus();
             }
         }
     }
@@ -85,9 +92,19 @@ public class MainWindowViewModel : ViewModelBase
 
     public string CurrentStageKey => GetStageInfo(CurrentStage).Key;
 
-    public string StageTitle => GetStageInfo(CurrentStage).Title;
+    public string StageTitle => Localizer.Instance[GetStageInfo(CurrentStage).TitleKey];
 
-    public string StageDescription => GetStageInfo(CurrentStage).Description;
+    public string StageDescription => Localizer.Instance[GetStageInfo(CurrentStage).DescriptionKey];
+
+    /// <summary>
+    ///     Load Data › Set Medal › Present, with the current stage highlighted.
+    /// </summary>
+    public IReadOnlyList<StageBreadcrumbItem> Breadcrumbs =>
+    [
+        new(Localizer.Instance[LoadDataInfo.TitleKey], CurrentStage == AppStage.LoadData, false),
+        new(Localizer.Instance[SetMedalInfo.TitleKey], CurrentStage == AppStage.SetMedal, false),
+        new(Localizer.Instance["main.stage.presentation.title"], false, true)
+    ];
 
     public bool IsLoadDataStage => CurrentStage == AppStage.LoadData;
     public bool IsSetMedalStage => CurrentStage == AppStage.SetMedal;
@@ -96,7 +113,7 @@ public class MainWindowViewModel : ViewModelBase
     public bool CanMoveNext => !IsPresentationActive && CurrentStage < AppStage.SetMedal && CanAdvanceCurrentStage;
     public bool CanLaunchPresentation => !IsPresentationActive && CurrentStage == AppStage.SetMedal;
     public bool CanExecutePrimaryAction => CanMoveNext || CanLaunchPresentation;
-    public string PrimaryActionText => CurrentStage == AppStage.SetMedal ? "Launch" : "Next";
+    public string PrimaryActionText => Localizer.Instance[CurrentStage == AppStage.SetMedal ? "main.launch" : "main.next"];
 
     private bool CanAdvanceCurrentStage => CurrentStage switch
     {
@@ -132,6 +149,8 @@ public class MainWindowViewModel : ViewModelBase
         if (e.PropertyName == nameof(LoadDataStageViewModel.LoadedContestState))
         {
             SetMedalStage.SetContestState(LoadDataStage.LoadedContestState);
+            SetMedalStage.SetGroupPresetSource(LoadDataStage.CdpPath, LoadDataStage.LoadedConfig.GroupPresets);
+            SetMedalStage.SetConfig(LoadDataStage.LoadedConfig);
         }
 
         if (e.PropertyName == nameof(LoadDataStageViewModel.IsParsing) ||
@@ -158,9 +177,7 @@ public class MainWindowViewModel : ViewModelBase
     private void LaunchPresentation()
     {
         if (!CanLaunchPresentation) return;
-        if (!SetMedalStage.TryPreparePresentation(out _)) return;
-        var contestState = LoadDataStage.LoadedContestState;
-        if (contestState is null) return;
+        if (!SetMedalStage.TryPreparePresentation(out var contestState, out _)) return;
 
         Trace.WriteLine(
             $"[MainWindowVM] LaunchPresentation: ts={DateTime.Now:HH:mm:ss.fff}, " +
@@ -198,7 +215,33 @@ public class MainWindowViewModel : ViewModelBase
         {
             AppStage.LoadData => LoadDataInfo,
             AppStage.SetMedal => SetMedalInfo,
-            _ => new StageInfo("unknown", "Unknown Stage", string.Empty)
+            _ => new StageInfo("unknown", "main.stage.unknown.title", string.Empty)
         };
     }
+
+    private void ClearImageCache()
+    {
+        PresentationStage.ClearImageCache();
+        RefreshImageCacheStatus();
+    }
+
+    private void RefreshImageCacheStatus()
+    {
+        LoadDataStage.SetImageCacheSize(PresentationStage.ImageCacheCount, PresentationStage.ImageCacheBytes);
+    }
+
+    private void OnLanguageChanged()
+    {
+        RefreshImageCacheStatus();
+        OnPropertyChanged(nameof(StageTitle));
+        OnPropertyChanged(nameof(StageDescription));
+        OnPropertyChanged(nameof(PrimaryActionText));
+        OnPropertyChanged(nameof(Breadcrumbs));
+    }
+}
+
+public sealed record StageBreadcrumbItem(string Title, bool IsCurrent, bool IsLast)
+{
+    public string Label => IsLast ? Title : $"{Title}  ›";
+    public double Opacity => IsCurrent ? 1 : 0.55;
 }
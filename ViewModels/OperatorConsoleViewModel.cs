@@ -0,0 +1,218 @@
+using CommunityToolkit.Mvvm.Input;
+using Pyrite.Models;
+using Pyrite.Services;
+using System;
+using System.Collections.ObjectModel;
+using System.ComponentModel;
+using System.Linq;
+
+namespace Pyrite.ViewModels;
+
+/// <summary>
+///     Presenter-facing mirror of the resolver state. Shows what the next key press will do, including the hidden
+///     results of the focused team's pending problems, so the operator is never surprised on stage.
+/// </summary>
+public sealed class OperatorConsoleViewModel : ViewModelBase, IDisposable
+{
+    private const int MaxUpcomingAwards = 12;
+
+    private readonly PresentationStageViewModel _presentation;
+    private string _focusedTeamText = string.Empty;
+    private string _nextActionText = string.Empty;
+
+    public OperatorConsoleViewModel(PresentationStageViewModel presentation)
+    {
+        _presentation = presentation;
+        AdvanceCommand = new RelayCommand(_presentation.HandleSpacePressed);
+        UndoCommand = new RelayCommand(_presentation.HandleUndoPressed);
+        ToggleAutopilotCommand = new RelayCommand(_presentation.HandleAutopilotToggled);
+        SkipTeamCommand = new RelayCommand(_presentation.HandleSkipTeamPressed);
+        BackToSetupCommand = new RelayCommand(() => _presentation.ExitCommand.Execute(null));
+        ReloadImagesCommand = new RelayCommand(() => _presentation.ReloadImages());
+        ReloadTeamImagesCommand = new RelayCommand(ReloadFocusedTeamImages);
+        _presentation.PropertyChanged += OnPresentationPropertyChanged;
+        Localizer.Instance.LanguageChanged += RefreshButtonLabels;
+        Refresh();
+    }
+
+    public RelayCommand AdvanceCommand { get; }
+    public RelayCommand UndoCommand { get; }
+    public RelayCommand ToggleAutopilotCommand { get; }
+    public RelayCommand SkipTeamCommand { get; }
+    public RelayCommand BackToSetupCommand { get; }
+    public RelayCommand ReloadImagesCommand { get; }
+    public RelayCommand ReloadTeamImagesCommand { get; }
+    public ObservableCollection<OperatorPendingProblemItem> PendingProblems { get; } = [];
+    public ObservableCollection<OperatorUpcomingAwardItem> UpcomingAwards { get; } = [];
+
+    public PresentationStageViewModel Presentation => _presentation;
+
+    // Button labels name the keys from [keys], like the F1 overlay.
+    public string AdvanceLabel => FormatButtonLabel("operator.advance", KeyAction.Advance);
+    public string UndoLabel => FormatButtonLabel("operator.undo", KeyAction.Undo);
+    public string AutopilotLabel => FormatButtonLabel("operator.autopilot", KeyAction.Autopilot);
+    public string SkipTeamLabel => FormatButtonLabel("operator.skip_team", KeyAction.SkipTeam);
+    public string BackToSetupLabel => FormatButtonLabel("operator.back_to_setup", KeyAction.BackToSetup);
+
+    public string FocusedTeamText
+    {
+        get => _focusedTeamText;
+        private set => SetProperty(ref _focusedTeamText, value);
+    }
+
+    public string NextActionText
+    {
+        get => _nextActionText;
+        private set => SetProperty(ref _nextActionText, value);
+    }
+
+    public string StatusText => _presentation.SessionStatus;
+
+    public void Dispose()
+    {
+        _presentation.PropertyChanged -= OnPresentationPropertyChanged;
+        Localizer.Instance.LanguageChanged -= RefreshButtonLabels;
+    }
+
+    private void OnPresentationPropertyChanged(object? sender, PropertyChangedEventArgs e)
+    {
+        if (e.PropertyName == nameof(PresentationStageViewModel.SessionStatus))
+        {
+            Refresh();
+        }
+        else if (e.PropertyName == nameof(PresentationStageViewModel.HotkeyHelpItems))
+        {
+            // Raised when config.toml is reloaded, which may remap the keys.
+            RefreshButtonLabels();
+        }
+    }
+
+    private string FormatButtonLabel(string key, KeyAction action)
+    {
+        return Localizer.Instance.Format(key, _presentation.FormatActionKeys(action));
+    }
+
+    private void RefreshButtonLabels()
+    {
+        OnPropertyChanged(nameof(AdvanceLabel));
+        OnPropertyChanged(nameof(UndoLabel));
+        OnPropertyChanged(nameof(AutopilotLabel));
+        OnPropertyChanged(nameof(SkipTeamLabel));
+        OnPropertyChanged(nameof(BackToSetupLabel));
+    }
+
+    private void Refresh()
+    {
+        OnPropertyChanged(nameof(StatusText));
+
+        var rows = _presentation.PreFreezeRows;
+        var focusIndex = _presentation.FocusedRowIndex;
+        var focusedRow = focusIndex >= 0 && focusIndex < rows.Count ? rows[focusIndex] : null;
+
+        FocusedTeamText = focusedRow is null
+            ? "No focused team"
+            : $"#{focusedRow.Rank} {focusedRow.TeamName} ({focusedRow.TeamId}) - {focusedRow.TotalPoints} solved, {focusedRow.TotalPenalty} min";
+
+        PendingProblems.Clear();
+        if (focusedRow is not null)
+        {
+            foreach (var problemId in _presentation.GetPendingProblemIds(focusedRow.TeamId))
+            {
+                focusedRow.TeamStatus.ProblemStats.TryGetValue(problemId, out var stat);
+                PendingProblems.Add(new OperatorPendingProblemItem(GetProblemLabel(problemId), stat));
+            }
+        }
+
+        UpcomingAwards.Clear();
+        for (var i = Math.Min(focusIndex, rows.Count - 1); i >= 0 && UpcomingAwards.Count < MaxUpcomingAwards; i--)
+        {
+            var row = rows[i];
+            var citations = _presentation.GetAwardsForTeam(row.TeamId)
+                .Select(award => _presentation.FormatAwardCitation(award, row.TeamId))
+                .ToList();
+            if (citations.Count == 0)
+            {
+                continue;
+            }
+
+            UpcomingAwards.Add(new OperatorUpcomingAwardItem(row.Rank, row.TeamName, string.Join(", ", citations)));
+        }
+
+        NextActionText = BuildNextActionText(focusedRow);
+    }
+
+    private void ReloadFocusedTeamImages()
+    {
+        var rows = _presentation.PreFreezeRows;
+        var focusIndex = _presentation.FocusedRowIndex;
+        if (focusIndex >= 0 && focusIndex < rows.Count)
+        {
+            _presentation.ReloadImages(rows[focusIndex].TeamId);
+        }
+    }
+
+    private string BuildNextActionText(PreFreezeScoreboardRowViewModel? focusedRow)
+    {
+        if (focusedRow is null)
+        {
+            return "Nothing to do.";
+        }
+
+        return _presentation.State switch
+        {
+            PresentationRowState.RowInProgressAwaitResort => "Resort the scoreboard.",
+            PresentationRowState.RowCompleteAwardShowing => "Hide the award overlay.",
+            PresentationRowState.RowCompleteReadyToAdvance when _presentation.FocusedRowIndex == 0 => "Finish the presentation.",
+            PresentationRowState.RowCompleteReadyToAdvance => "Move focus up one row.",
+            PresentationRowState.ChampionCelebration => "Dismiss the champion celebration.",
+            PresentationRowState.Summary => "Dismiss the summary screen.",
+            PresentationRowState.Finished => "Presentation finished.",
+            _ when PendingProblems.Count > 0 =>
+                $"Reveal problem {PendingProblems[0].Label} for {focusedRow.TeamName} ({PendingProblems[0].Result}).",
+            _ when _presentation.GetAwardsForTeam(focusedRow.TeamId).Any() => $"Show awards for {focusedRow.TeamName}.",
+            _ when _presentation.FocusedRowIndex == 0 => "Finish the presentation.",
+            _ => "Move focus up one row."
+        };
+    }
+
+    private string GetProblemLabel(string problemId)
+    {
+        return _presentation.OrderedProblems
+            .FirstOrDefault(problem => string.Equals(problem.Id, problemId, StringComparison.Ordinal))?.Label ?? problemId;
+    }
+}
+
+public sealed class OperatorPendingProblemItem
+{
+    public OperatorPendingProblemItem(string label, ProblemStat? stat)
+    {
+        Label = label;
+        IsSolved = stat?.Solved ?? false;
+        Result = stat switch
+        {
+            null => "No data",
+            { Solved: true } => $"Accepted at {stat.LastSubmissionTime} min, {stat.SubmissionsBeforeSolved} tries",
+            _ => $"Rejected, {stat.SubmissionsBeforeSolved} tries"
+        };
+    }
+
+    public string Label { get; }
+    public string Result { get; }
+    public bool IsSolved { get; }
+    public string Foreground => IsSolved ? "#31C950" : "#FB2C36";
+}
+
+public sealed class OperatorUpcomingAwardItem
+{
+    public OperatorUpcomingAwardItem(int rank, string teamName, string citations)
+    {
+        Rank = rank;
+        TeamName = teamName;
+        Citations = citations;
+    }
+
+    public int Rank { get; }
+    public string TeamName { get; }
+    public string Citations { get; }
+    public string DisplayLabel => $"#{Rank} {TeamName}: {Citations}";
+}
@@ -1,13 +1,17 @@
 using Avalonia.Media.Imaging;
+using Avalonia.Threading;
 using CommunityToolkit.Mvvm.Input;
 using Pyrite.Models;
+using Pyrite.Services;
 using System;
 using System.Collections.Generic;
 using System.Collections.ObjectModel;
 using System.Diagnostics;
+using System.Globalization;
 using System.IO;
 using System.Linq;
 using System.Text;
+using System.Threading.Tasks;
 
 namespace Pyrite.ViewModels;
 
@@ -17,37 +21,151 @@ public sealed class PresentationStageViewModel : ViewModelBase
     private const int AwardBackgroundDecodeMinWidth = 1280;
     private const int AwardBackgroundDecodeMaxWidth = 2560;
     private const double AwardBackgroundDecodeViewportScale = 1.2;
-    private const int ScoreboardLogoDecodeWidth = 96;
-    private const int AwardAffiliationLogoDecodeWidth = 256;
+    // Logo display sizes in stage units, as laid out in PresentationStageView.axaml.
+    private const double ScoreboardLogoSize = 52;
+    private const double AwardAffiliationLogoSize = 180;
+    private const double ChampionLogoSize = 320;
+    private const double InfoBarLogoHeight = 32;
+    private const int LogoDecodeStep = 16;
     private const int MaxLogoCacheItems = 512;
-    private const long MaxLogoCacheApproxBytes = 64L * 1024 * 1024;
+    private const int LogoKeepViewports = 2;
+    private const int MaxUndoHistory = 4096;
+    private const int SummaryClimberCount = 3;
+    private const string NoMedalTint = "#00000000";
+    private const string DefaultAwardBannerColor = "#80000000";
+
+    private static readonly double[] AnimationSpeedSteps = [0.25, 0.5, 0.75, 1, 1.5, 2, 3, 4];
+
+    /// <summary>
+    ///     The default key text of each help line; lines whose actions are remapped in <c>[keys]</c> show the
+    ///     configured keys instead.
+    /// </summary>
+    private static readonly (string Keys, string DescriptionKey, KeyAction[] Actions)[] HotkeyHelp =
+    [
+        ("Space", "hotkey.advance", [KeyAction.Advance]),
+        ("Backspace", "hotkey.undo", [KeyAction.Undo]),
+        ("Click", "hotkey.click_cell", []),
+        ("Wheel", "hotkey.manual_scroll", []),
+        ("S", "hotkey.skip_team", [KeyAction.SkipTeam]),
+        ("A", "hotkey.autopilot", [KeyAction.Autopilot]),
+        ("B", "hotkey.blank", [KeyAction.Blank]),
+        ("C", "hotkey.rank_comparison", [KeyAction.RankComparison]),
+        ("V", "hotkey.mute", [KeyAction.Mute]),
+        ("K", "hotkey.palette", [KeyAction.Palette]),
+        ("[  ]  \\", "hotkey.animation_speed",
+            [KeyAction.SlowerAnimations, KeyAction.FasterAnimations, KeyAction.NormalAnimations]),
+        ("Ctrl + -  Ctrl + +  Ctrl + 0", "hotkey.stage_scale", [KeyAction.ZoomOut, KeyAction.ZoomIn, KeyAction.ZoomReset]),
+        ("O", "hotkey.operator_console", [KeyAction.OperatorConsole]),
+        ("P", "hotkey.mirror", [KeyAction.Mirror]),
+        ("F8", "hotkey.recording", [KeyAction.Recording]),
+        ("F9", "hotkey.screenshot", [KeyAction.Screenshot]),
+        ("F11 / F12", "hotkey.fullscreen", [KeyAction.Fullscreen]),
+        ("F10", "hotkey.borderless", [KeyAction.Borderless]),
+        ("M", "hotkey.next_monitor", [KeyAction.NextMonitor]),
+        ("Ctrl + Shift + F", "hotkey.reveal_all", [KeyAction.RevealAll]),
+        ("Ctrl + Shift + Backspace", "hotkey.back_to_setup", [KeyAction.BackToSetup]),
+        ("F1", "hotkey.help", [KeyAction.Help])
+    ];
+
+    private static readonly double[] StageScaleSteps = [0.5, 0.6, 0.7, 0.8, 0.9, 1, 1.1, 1.25, 1.5, 1.75, 2, 2.5, 3];
+
 
     private ContestState? _contestState;
-    private readonly BoundedBitmapCache _logoCache = new(MaxLogoCacheItems, MaxLogoCacheApproxBytes);
+    private readonly DispatcherTimer _autopilotTimer;
+    private readonly DispatcherTimer _pendingPulseTimer;
+    private readonly DispatcherTimer _awardSlideshowTimer;
+    private readonly DispatcherTimer _countdownTimer;
+    private readonly List<string> _awardPhotoPaths = [];
+    private readonly Dictionary<string, Task<Bitmap?>> _awardPhotoPrefetches = new(StringComparer.Ordinal);
+    private Task<Bitmap?>? _pendingAwardPhoto;
+    private int _awardPhotoIndex;
+    private readonly BoundedBitmapCache _logoCache = new(MaxLogoCacheItems, 64L * 1024 * 1024);
+    private readonly Dictionary<string, Task<Bitmap?>> _scoreboardLogoLoads = new(StringComparer.Ordinal);
+    private readonly Dictionary<string, string?> _scoreboardLogoPaths = new(StringComparer.Ordinal);
+    private readonly HashSet<PreFreezeScoreboardRowViewModel> _logoRows = [];
+    private int _visibleRowFirst;
+    private int _visibleRowLast = int.MaxValue;
+    private int _logoCacheGeneration;
     private Bitmap? _awardAffiliationLogoImage;
+    private Bitmap? _awardCountryFlagImage;
     private Bitmap? _awardBackgroundImage;
+    private Bitmap? _championLogoImage;
+    private Bitmap? _infoBarLogoImage;
+    private Bitmap? _holdingSlideImage;
+    private Bitmap? _stageBackgroundImage;
+    private bool _isBlanked;
+    private bool _isRankComparisonVisible;
+    private ColorPalette _colorPalette;
+    private IReadOnlyList<IntroSlideViewModel> _introSlides = [];
+    private int _introSlideIndex = -1;
+    private ResultSummaryViewModel? _summary;
+    private bool _isCountdownVisible;
+    private string _countdownText = string.Empty;
+    private DateTime _countdownEndUtc;
+    private DateTime _phaseHoldStartUtc;
+    private float _phaseHoldSeconds;
+    private PresentationColorsConfig _activeColors = new();
+    private bool _isHotkeyHelpVisible;
+    private string _progressText = string.Empty;
+    private CellFlipAnimationRequest? _cellFlipAnimationRequest;
+    private long _cellFlipAnimationRequestCounter;
+    private RankDeltaAnimationRequest? _rankDeltaAnimationRequest;
+    private long _rankDeltaAnimationRequestCounter;
+    private string _championSummaryText = string.Empty;
+    private string _championTeamName = string.Empty;
     private string _awardTeamName = string.Empty;
+    private IReadOnlyList<ProblemDisplayInfo> _awardProblems = [];
+    private string _awardTitle = string.Empty;
+    private string _awardBannerColor = DefaultAwardBannerColor;
+    private string? _awardOverlayTeamId;
     private string _awardText = string.Empty;
     private string? _dataPath;
     private int _focusedRowIndex = -1;
+    private bool _isAutopilotEnabled;
+    private bool _isAutopilotPaused;
+    private bool _isSoundMuted;
+    private double _animationSpeed = 1;
+    private double _stageScale = 1;
+    private double _baseStageScale = 1;
+    private double _renderScaling = 1;
+    private int _scoreboardLogoDecodeSize = 64;
+    private SoundEffectPlayer _soundPlayer = new(new SoundConfig(), null);
+    private readonly LinkedList<PresentationSnapshot> _history = new();
     private bool _isAwardOverlayVisible;
     private bool _isInitialized;
     private bool _isStarted;
+    private bool _isResultsWebhookSent;
+    private bool _isRevealingAll;
     private PyriteConfig _loadedConfig = PyriteConfig.Default();
     private readonly Dictionary<string, Queue<string>> _pendingRevealsByTeamId = new(StringComparer.Ordinal);
+    private readonly Dictionary<string, StandingsRow> _finalStandingsByTeamId = new(StringComparer.Ordinal);
     private readonly List<ProblemDisplayInfo> _orderedProblems = [];
     private string? _pendingResortSolvedTeamId;
     private MoveUpAnimationRequest? _moveUpAnimationRequest;
     private long _moveUpAnimationRequestCounter;
     private PresentationRowState _state = PresentationRowState.RowInProgress;
+    private PreFreezeScoreboardRowViewModel? _revealTargetRow;
     private double _viewportHeight;
     private double _viewportWidth;
+    private FileSystemWatcher? _configWatcher;
+    private readonly DispatcherTimer _configReloadTimer;
 
     public PresentationStageViewModel()
     {
         ExitCommand = new RelayCommand(RequestExit);
         RevealCommand = new RelayCommand(() => RunReveal(), CanReveal);
-        MoveUpCommand = new RelayCommand(RunMoveUp, CanMoveUp);
+        MoveUpCommand = new RelayCommand(() => RunMoveUp(), CanMoveUp);
+        _autopilotTimer = new DispatcherTimer(DispatcherPriority.Normal);
+        _autopilotTimer.Tick += OnAutopilotTick;
+        _pendingPulseTimer = new DispatcherTimer(DispatcherPriority.Normal);
+        _pendingPulseTimer.Tick += (_, _) => ClearRevealTarget();
+        _awardSlideshowTimer = new DispatcherTimer(DispatcherPriority.Normal);
+        _awardSlideshowTimer.Tick += OnAwardSlideshowTick;
+        _countdownTimer = new DispatcherTimer(DispatcherPriority.Normal) { Interval = TimeSpan.FromMilliseconds(250) };
+        _countdownTimer.Tick += (_, _) => RefreshCountdownText();
+        // Editors often write a file in several steps; wait for the writes to settle before parsing.
+        _configReloadTimer = new DispatcherTimer(DispatcherPriority.Normal) { Interval = TimeSpan.FromMilliseconds(300) };
+        _configReloadTimer.Tick += (_, _) => ReloadConfig();
         RefreshSessionStatus();
     }
 
@@ -57,13 +175,160 @@ public sealed class PresentationStageViewModel : ViewModelBase
     public RelayCommand RevealCommand { get; }
     public RelayCommand MoveUpCommand { get; }
     public ObservableCollection<PreFreezeScoreboardRowViewModel> PreFreezeRows { get; } = [];
+    public ObservableCollection<ProblemHeaderViewModel> ProblemHeaders { get; } = [];
+    public int ProblemHeaderCount => ProblemHeaders.Count;
+    public bool ShowProblemSolveCounts => _loadedConfig.Presentation.ShowProblemSolveCounts;
     public MoveUpAnimationRequest? MoveUpAnimationRequest
     {
         get => _moveUpAnimationRequest;
         private set => SetProperty(ref _moveUpAnimationRequest, value);
     }
-    public double RowFlyAnimationSeconds => Math.Max(0.01, _loadedConfig.Presentation.RowFlyAnimationSeconds);
-    public double ScrollAnimationSeconds => Math.Max(0.01, _loadedConfig.Presentation.ScrollAnimationSeconds);
+    public CellFlipAnimationRequest? CellFlipAnimationRequest
+    {
+        get => _cellFlipAnimationRequest;
+        private set => SetProperty(ref _cellFlipAnimationRequest, value);
+    }
+    public RankDeltaAnimationRequest? RankDeltaAnimationRequest
+    {
+        get => _rankDeltaAnimationRequest;
+        private set => SetProperty(ref _rankDeltaAnimationRequest, value);
+    }
+    public PresentationConfig PresentationConfig => _loadedConfig.Presentation;
+    public FontsConfig Fonts => _loadedConfig.Fonts;
+
+    /// <summary>
+    ///     The <c>[fonts]</c> files that exist, resolved against the CDP folder.
+    /// </summary>
+    public IReadOnlyList<string> FontFilePaths { get; private set; } = [];
+
+    /// <summary>
+    ///     The configured colors with the palette currently selected by the <c>K</c> hotkey.
+    /// </summary>
+    public PresentationColorsConfig ActiveColors
+    {
+        get => _activeColors;
+        private set => SetProperty(ref _activeColors, value);
+    }
+    public bool ShowTeamLabels => _loadedConfig.Presentation.ShowTeamLabels;
+    public bool ShowInfoBarTop => _loadedConfig.Presentation.InfoBar == InfoBarPosition.Top;
+    public bool ShowInfoBarBottom => _loadedConfig.Presentation.InfoBar == InfoBarPosition.Bottom;
+    public string ContestName => _contestState?.Contest?.FormalName ?? string.Empty;
+    public Bitmap? InfoBarLogoImage
+    {
+        get => _infoBarLogoImage;
+        private set
+        {
+            if (SetProperty(ref _infoBarLogoImage, value))
+            {
+                OnPropertyChanged(nameof(HasInfoBarLogo));
+            }
+        }
+    }
+    public bool HasInfoBarLogo => InfoBarLogoImage is not null;
+    public bool IsBlanked
+    {
+        get => _isBlanked;
+        private set => SetProperty(ref _isBlanked, value);
+    }
+    public IReadOnlyList<HotkeyHelpItem> HotkeyHelpItems => HotkeyHelp
+        .Select(item => new HotkeyHelpItem(FormatHotkeys(item.Keys, item.Actions), Localizer.Instance[item.DescriptionKey]))
+        .ToList();
+
+    /// <summary>
+    ///     The remappable key bindings from <c>[keys]</c>.
+    /// </summary>
+    public KeysConfig Keys => _loadedConfig.Keys;
+    public bool IsHotkeyHelpVisible
+    {
+        get => _isHotkeyHelpVisible;
+        private set => SetProperty(ref _isHotkeyHelpVisible, value);
+    }
+    public bool IsRankComparisonVisible
+    {
+        get => _isRankComparisonVisible;
+        private set => SetProperty(ref _isRankComparisonVisible, value);
+    }
+    public Bitmap? HoldingSlideImage
+    {
+        get => _holdingSlideImage;
+        private set => SetProperty(ref _holdingSlideImage, value);
+    }
+    public IntroSlideViewModel? CurrentIntroSlide =>
+        _introSlideIndex >= 0 && _introSlideIndex < _introSlides.Count ? _introSlides[_introSlideIndex] : null;
+    public bool IsIntroVisible => CurrentIntroSlide is not null;
+    public bool IsCountdownVisible
+    {
+        get => _isCountdownVisible;
+        private set => SetProperty(ref _isCountdownVisible, value);
+    }
+    public string CountdownText
+    {
+        get => _countdownText;
+        private set => SetProperty(ref _countdownText, value);
+    }
+    public Bitmap? StageBackgroundImage
+    {
+        get => _stageBackgroundImage;
+        private set => SetProperty(ref _stageBackgroundImage, value);
+    }
+    public string ProgressText
+    {
+        get => _progressText;
+        private set => SetProperty(ref _progressText, value);
+    }
+    public double RowFlyAnimationSeconds =>
+        Math.Max(0.01, _loadedConfig.Presentation.RowFlyAnimationSeconds / AnimationSpeed);
+    public double ScrollAnimationSeconds =>
+        Math.Max(0.01, _loadedConfig.Presentation.ScrollAnimationSeconds / AnimationSpeed);
+
+    public double AnimationSpeed
+    {
+        get => _animationSpeed;
+        private set
+        {
+            if (SetProperty(ref _animationSpeed, value))
+            {
+                OnPropertyChanged(nameof(RowFlyAnimationSeconds));
+                OnPropertyChanged(nameof(ScrollAnimationSeconds));
+                OnPropertyChanged(nameof(AnimationSpeedText));
+                OnPropertyChanged(nameof(IsAnimationSpeedAdjusted));
+            }
+        }
+    }
+
+    public string AnimationSpeedText => $"{AnimationSpeed:0.##}\u00D7";
+    public bool IsAnimationSpeedAdjusted => Math.Abs(AnimationSpeed - 1) > 0.001;
+
+    /// <summary>
+    ///     Zoom factor for the whole stage (text, paddings, logos), adjustable live for projectors of different sizes.
+    /// </summary>
+    public double StageScale
+    {
+        get => _stageScale;
+        private set
+        {
+            if (SetProperty(ref _stageScale, value))
+            {
+                RefreshScoreboardLogoSize();
+            }
+        }
+    }
+
+    /// <summary>
+    ///     Physical pixels per stage unit at 100% zoom, reported by the view, so logos are decoded at the size they
+    ///     are drawn.
+    /// </summary>
+    public double RenderScaling
+    {
+        get => _renderScaling;
+        set
+        {
+            if (value > 0 && SetProperty(ref _renderScaling, value))
+            {
+                RefreshScoreboardLogoSize();
+            }
+        }
+    }
     public bool IsAwardOverlayVisible
     {
         get => _isAwardOverlayVisible;
@@ -75,6 +340,18 @@ public sealed class PresentationStageViewModel : ViewModelBase
         get => _awardAffiliationLogoImage;
         private set => SetProperty(ref _awardAffiliationLogoImage, value);
     }
+    public Bitmap? AwardCountryFlagImage
+    {
+        get => _awardCountryFlagImage;
+        private set
+        {
+            if (SetProperty(ref _awardCountryFlagImage, value))
+            {
+                OnPropertyChanged(nameof(HasAwardCountryFlag));
+            }
+        }
+    }
+    public bool HasAwardCountryFlag => AwardCountryFlagImage is not null;
     public string AwardTeamName
     {
         get => _awardTeamName;
@@ -85,6 +362,38 @@ public sealed class PresentationStageViewModel : ViewModelBase
         get => _awardText;
         private set => SetProperty(ref _awardText, value);
     }
+    /// <summary>
+    ///     Problems of the first-to-solve awards on the overlay, shown as labelled balloons.
+    /// </summary>
+    public IReadOnlyList<ProblemDisplayInfo> AwardProblems
+    {
+        get => _awardProblems;
+        private set
+        {
+            if (SetProperty(ref _awardProblems, value))
+            {
+                OnPropertyChanged(nameof(HasAwardProblems));
+            }
+        }
+    }
+    public bool HasAwardProblems => AwardProblems.Count > 0;
+    public string AwardTitle
+    {
+        get => _awardTitle;
+        private set
+        {
+            if (SetProperty(ref _awardTitle, value))
+            {
+                OnPropertyChanged(nameof(HasAwardTitle));
+            }
+        }
+    }
+    public bool HasAwardTitle => !string.IsNullOrWhiteSpace(AwardTitle);
+    public string AwardBannerColor
+    {
+        get => _awardBannerColor;
+        private set => SetProperty(ref _awardBannerColor, value);
+    }
 
     public PresentationRowState State
     {
@@ -93,11 +402,41 @@ public sealed class PresentationStageViewModel : ViewModelBase
         {
             if (SetProperty(ref _state, value))
             {
+                OnPropertyChanged(nameof(IsChampionCelebrationVisible));
+                OnPropertyChanged(nameof(IsSummaryVisible));
                 RefreshSessionStatus();
+                if (value == PresentationRowState.Finished && !_isRevealingAll)
+                {
+                    // The rehearsal fast-forward must not publish; the real ceremony posts when it finishes.
+                    PostResultsWebhook();
+                }
             }
         }
     }
 
+    public bool IsChampionCelebrationVisible => State == PresentationRowState.ChampionCelebration;
+    public ResultSummaryViewModel? Summary
+    {
+        get => _summary;
+        private set => SetProperty(ref _summary, value);
+    }
+    public bool IsSummaryVisible => State == PresentationRowState.Summary;
+    public Bitmap? ChampionLogoImage
+    {
+        get => _championLogoImage;
+        private set => SetProperty(ref _championLogoImage, value);
+    }
+    public string ChampionTeamName
+    {
+        get => _championTeamName;
+        private set => SetProperty(ref _championTeamName, value);
+    }
+    public string ChampionSummaryText
+    {
+        get => _championSummaryText;
+        private set => SetProperty(ref _championSummaryText, value);
+    }
+
     public int FocusedRowIndex
     {
         get => _focusedRowIndex;
@@ -106,6 +445,7 @@ public sealed class PresentationStageViewModel : ViewModelBase
             if (SetProperty(ref _focusedRowIndex, value))
             {
                 RefreshSessionStatus();
+                PrefetchFocusedTeamAwardPhoto();
             }
         }
     }
@@ -134,9 +474,46 @@ public sealed class PresentationStageViewModel : ViewModelBase
         }
     }
 
+    public bool CanUndo => _history.Count > 0;
+
+    internal IReadOnlyList<ProblemDisplayInfo> OrderedProblems => _orderedProblems;
+
+    public bool IsAutopilotEnabled
+    {
+        get => _isAutopilotEnabled;
+        private set
+        {
+            if (SetProperty(ref _isAutopilotEnabled, value))
+            {
+                OnPropertyChanged(nameof(AutopilotStatusText));
+            }
+        }
+    }
+
+    public bool IsAutopilotPaused
+    {
+        get => _isAutopilotPaused;
+        private set
+        {
+            if (SetProperty(ref _isAutopilotPaused, value))
+            {
+                OnPropertyChanged(nameof(AutopilotStatusText));
+            }
+        }
+    }
+
+    public string AutopilotStatusText => Localizer.Instance[IsAutopilotPaused ? "present.auto_paused" : "present.auto"];
+
+    public bool IsSoundMuted
+    {
+        get => _isSoundMuted;
+        private set => SetProperty(ref _isSoundMuted, value);
+    }
+
     public string SessionStatus =>
         $"Initialized={IsInitialized}, Started={IsStarted}, State={State}, FocusIndex={FocusedRowIndex}, " +
-        $"Viewport={_viewportWidth:F0}x{_viewportHeight:F0}";
+        $"Undo={_history.Count}, Viewport={_viewportWidth:F0}x{_viewportHeight:F0}, " +
+        $"LogoCache={_logoCache.Count} ({_logoCache.ApproxBytes / (1024.0 * 1024):F1} MB)";
 
     public void Initialize(ContestState contestState, PyriteConfig config, string? dataPath)
     {
@@ -145,13 +522,66 @@ public sealed class PresentationStageViewModel : ViewModelBase
 
         _contestState = contestState;
         _loadedConfig = config;
+        _isResultsWebhookSent = false;
+        _colorPalette = config.Presentation.Palette;
+        ActiveColors = config.Presentation.Colors;
+        OnPropertyChanged(nameof(PresentationConfig));
+        OnPropertyChanged(nameof(ShowProblemSolveCounts));
+        OnPropertyChanged(nameof(ShowInfoBarTop));
+        OnPropertyChanged(nameof(ShowInfoBarBottom));
+        OnPropertyChanged(nameof(ShowTeamLabels));
+        OnPropertyChanged(nameof(HotkeyHelpItems));
+        OnPropertyChanged(nameof(ContestName));
         OnPropertyChanged(nameof(RowFlyAnimationSeconds));
         OnPropertyChanged(nameof(ScrollAnimationSeconds));
         HideAwardOverlay();
         _logoCache.Clear();
+        ReleaseCountryFlags();
+        _logoCache.MaxApproxBytes = config.Presentation.ImageCacheMegabytes * 1024L * 1024;
+        _scoreboardLogoLoads.Clear();
+        _scoreboardLogoPaths.Clear();
+        _logoRows.Clear();
+        _logoCacheGeneration++;
+        _scoreboardLogoDecodeSize = GetLogoDecodeSize(ScoreboardLogoSize);
         _dataPath = dataPath;
+        RefreshFontFilePaths();
+        // The info bar logo is decoded once for the largest zoom, so it stays sharp without reloading.
+        var previousInfoBarLogo = InfoBarLogoImage;
+        var infoBarLogoPath = ResolveDataRelativePath(config.Presentation.InfoBarLogoPath);
+        InfoBarLogoImage = infoBarLogoPath is null
+            ? null
+            : DecodeBitmap(
+                infoBarLogoPath,
+                (int)Math.Ceiling(InfoBarLogoHeight * StageScaleSteps[^1] * _renderScaling),
+                config.Presentation.RecordingFfmpegPath,
+                DecodeFit.Height);
+        previousInfoBarLogo?.Dispose();
+        IsBlanked = false;
+        IsRankComparisonVisible = false;
+        var previousHoldingSlide = HoldingSlideImage;
+        HoldingSlideImage = LoadAwardBackgroundImage(ResolveDataRelativePath(config.Presentation.HoldingSlidePath));
+        previousHoldingSlide?.Dispose();
+        foreach (var slide in _introSlides)
+        {
+            slide.Image?.Dispose();
+        }
+
+        _introSlideIndex = -1;
+        _introSlides = [];
+        var previousStageBackground = StageBackgroundImage;
+        StageBackgroundImage = LoadAwardBackgroundImage(ResolveDataRelativePath(config.Presentation.BackgroundImagePath));
+        previousStageBackground?.Dispose();
+        _soundPlayer = new SoundEffectPlayer(config.Presentation.Sounds, dataPath);
+        IsSoundMuted = _soundPlayer.IsMuted;
+        SetAutopilotEnabled(false);
+        ClearHistory();
         InitializePresentationRows(contestState);
-        FocusedRowIndex = FindInitialFocusedRowIndex();
+        _introSlides = BuildIntroSlides(contestState);
+        Summary = config.Presentation.SummaryScreen ? BuildResultSummary(contestState) : null;
+        RefreshProblemHeaders();
+        RefreshMedalTints();
+        RefreshDerivedState();
+        FocusedRowIndex = FastForwardToStartRank(FindInitialFocusedRowIndex());
         State = PresentationRowState.RowInProgress;
         IsInitialized = true;
         RevealCommand.NotifyCanExecuteChanged();
@@ -167,6 +597,10 @@ public sealed class PresentationStageViewModel : ViewModelBase
         }
 
         IsStarted = true;
+        SetIntroSlideIndex(_introSlides.Count > 0 ? 0 : -1);
+        StartCountdown();
+        StartConfigWatcher();
+        UpdateRevealTarget();
         RevealCommand.NotifyCanExecuteChanged();
         MoveUpCommand.NotifyCanExecuteChanged();
         RefreshSessionStatus();
@@ -174,6 +608,12 @@ public sealed class PresentationStageViewModel : ViewModelBase
 
     public void Stop()
     {
+        StopConfigWatcher();
+        SetAutopilotEnabled(false);
+        IsBlanked = false;
+        StopCountdown();
+        SetIntroSlideIndex(-1);
+        ClearRevealTarget();
         IsStarted = false;
         RevealCommand.NotifyCanExecuteChanged();
         MoveUpCommand.NotifyCanExecuteChanged();
@@ -198,180 +638,1464 @@ public sealed class PresentationStageViewModel : ViewModelBase
         UpdateViewport(width, height);
     }
 
+    /// <summary>
+    ///     Board rows currently on screen, reported by the view as it scrolls. Until the first report every row keeps
+    ///     its logo.
+    /// </summary>
+    public void UpdateVisibleRows(int first, int last)
+    {
+        if (first == _visibleRowFirst && last == _visibleRowLast)
+        {
+            return;
+        }
+
+        _visibleRowFirst = first;
+        _visibleRowLast = last;
+        ApplyLogoRange();
+    }
+
     public void HandleSpacePressed()
     {
-        if (!IsInitialized || !IsStarted)
+        if (!IsInitialized || !IsStarted || IsBlanked)
         {
             return;
         }
 
-        // Template for your transition logic:
-        // - decide next state
-        // - decide whether to call Reveal or MoveUp
-        Trace.WriteLine($"[PresentationStageVM] StateBefore: state={State}, focusIndex={FocusedRowIndex}");
-        switch (State)
+        if (IsCountdownVisible)
         {
-            case PresentationRowState.RowInProgress:
-                if (FocusedRowIndex < 0 || FocusedRowIndex >= PreFreezeRows.Count)
-                {
-                    Trace.WriteLine($"[PresentationStageVM] InvalidFocusIndex: focusIndex={FocusedRowIndex}, rowCount={PreFreezeRows.Count}");
-                    State = PresentationRowState.RowInProgress;
-                    break;
-                }
+            // The countdown is only a holding screen; the first Space ends it whether or not it reached zero.
+            StopCountdown();
+            RefreshDerivedState();
+            return;
+        }
 
-                var teamId = PreFreezeRows[FocusedRowIndex].TeamId;
-                if (HasPendingReveal(teamId))
-                {
-                    Trace.WriteLine($"[PresentationStageVM] Action: reveal, focusIndex={FocusedRowIndex}");
-                    var revealOutcome = RunReveal();
-                    if (revealOutcome.NeedResort)
-                    {
-                        _pendingResortSolvedTeamId = revealOutcome.SolvedTeamId;
-                        State = PresentationRowState.RowInProgressAwaitResort;
-                    }
-                    else
-                    {
-                        _pendingResortSolvedTeamId = null;
-                        State = PresentationRowState.RowInProgress;
-                    }
-                }
-                else
-                {
-                    Trace.WriteLine($"[PresentationStageVM] TeamNoPendingReveal: focusIndex={FocusedRowIndex}");
-                    if (HasAwards(teamId))
-                    {
-                        ShowAwardOverlay(teamId);
-                        State = PresentationRowState.RowCompleteAwardShowing;
-                    }
-                    else
-                    {
-                        Trace.WriteLine("[PresentationStageVM] Action: move_up");
-                        RunMoveUp();
-                        State = PresentationRowState.RowInProgress;
-                    }
-                }
+        if (IsIntroVisible)
+        {
+            // Past the last slide the board appears and the next Space starts revealing.
+            SetIntroSlideIndex(_introSlideIndex + 1 < _introSlides.Count ? _introSlideIndex + 1 : -1);
+            return;
+        }
 
-                break;
-            case PresentationRowState.RowInProgressAwaitResort:
-                ResortScoreboard(_pendingResortSolvedTeamId);
-                _pendingResortSolvedTeamId = null;
-                State = PresentationRowState.RowInProgress;
-                break;
-            case PresentationRowState.RowCompleteAwardShowing:
-                HideAwardOverlay();
-                State = PresentationRowState.RowCompleteReadyToAdvance;
-                break;
-            case PresentationRowState.RowCompleteReadyToAdvance:
-                RunMoveUp();
-                State = PresentationRowState.RowInProgress;
-                break;
-            default:
-                throw new ArgumentOutOfRangeException();
+        if (IsAutopilotEnabled)
+        {
+            IsAutopilotPaused = !IsAutopilotPaused;
+            ScheduleAutopilotStep();
+            return;
         }
-        Trace.WriteLine($"[PresentationStageVM] StateAfter: state={State}");
-    }
 
-    private void RequestExit()
-    {
-        ExitRequested?.Invoke();
-    }
+        var holdSeconds = GetPhaseHoldRemainingSeconds();
+        if (holdSeconds > 0)
+        {
+            Trace.WriteLine($"[PresentationStageVM] SpaceIgnored: phase pause, {holdSeconds:0.00}s left");
+            return;
+        }
 
-    private void RefreshSessionStatus()
-    {
-        OnPropertyChanged(nameof(SessionStatus));
+        AdvanceStep();
     }
 
-    private void InitializePresentationRows(ContestState contestState)
+    public void HandleAutopilotToggled()
     {
-        _orderedProblems.Clear();
-        _orderedProblems.AddRange(contestState.Problems.Values
-            .OrderBy(problem => problem.Ordinal)
-            .ThenBy(problem => problem.Label, StringComparer.Ordinal)
-            .Select(problem => new ProblemDisplayInfo(
-                problem.Id,
-                string.IsNullOrWhiteSpace(problem.Label) ? problem.ShortName : problem.Label))
-            .ToList());
-
-        PreFreezeRows.Clear();
-
-        _pendingRevealsByTeamId.Clear();
-        for (var i = 0; i < contestState.LeaderboardPreFreeze.Count; i++)
+        if (!IsInitialized || !IsStarted || IsPreShowVisible)
         {
-            var team = CloneTeamStatus(contestState.LeaderboardPreFreeze[i]);
-            var pendingProblemIds = team.ProblemStats
-                .Where(kv => kv.Value.AttemptedDuringFreeze)
-                .OrderBy(kv => kv.Key)
-                .Select(kv => kv.Key);
-
-            _pendingRevealsByTeamId[team.TeamId] = new Queue<string>(pendingProblemIds);
-
-            var teamLogo = LoadPinnedLogo(BuildAffiliationLogoPath(team.TeamAffiliation), ScoreboardLogoDecodeWidth);
-            var rowVm = new PreFreezeScoreboardRowViewModel(
-                team,
-                i + 1,
-                _orderedProblems,
-                teamLogo);
-            PreFreezeRows.Add(rowVm);
+            return;
         }
+
+        SetAutopilotEnabled(!IsAutopilotEnabled);
     }
 
-    private int FindInitialFocusedRowIndex()
+    /// <summary>
+    ///     Reveals every pending problem of the focused team in one step, leaving a single resort to follow.
+    /// </summary>
+    public void HandleSkipTeamPressed()
     {
-        for (var row = PreFreezeRows.Count - 1; row >= 0; row--)
+        if (!IsInitialized || !IsStarted || IsBlanked || IsPreShowVisible ||
+            State != PresentationRowState.RowInProgress || !CanReveal())
         {
-            var teamId = PreFreezeRows[row].TeamId;
-            if (HasPendingReveal(teamId))
+            return;
+        }
+
+        var snapshot = CaptureSnapshot();
+        var anySolved = false;
+        string? solvedTeamId = null;
+        while (CanReveal())
+        {
+            var outcome = RunReveal();
+            if (!outcome.Applied)
             {
-                return row;
+                break;
+            }
+
+            if (outcome.NeedResort)
+            {
+                anySolved = true;
+                solvedTeamId = outcome.SolvedTeamId;
             }
         }
 
-        return -1;
+        _soundPlayer.Play(anySolved ? SoundEffect.Accepted : SoundEffect.Rejected);
+        _pendingResortSolvedTeamId = anySolved ? solvedTeamId : null;
+        State = anySolved ? PresentationRowState.RowInProgressAwaitResort : PresentationRowState.RowInProgress;
+        StartPhaseHold(_loadedConfig.Presentation.Timing.RevealPauseSeconds);
+        PushHistory(snapshot);
+        RefreshDerivedState();
+        ScheduleAutopilotStep();
+        Trace.WriteLine($"[PresentationStageVM] SkipTeam: solved={anySolved}, state={State}");
     }
 
-    private RevealOutcome Reveal()
+    /// <summary>
+    ///     Reveals a specific frozen cell of the focused team out of the usual left-to-right order, e.g. when the operator
+    ///     clicks the problem the audience is waiting for.
+    /// </summary>
+    public void HandleProblemCellClicked(ProblemStatusCellViewModel cell)
     {
-        if (FocusedRowIndex < 0 || FocusedRowIndex >= PreFreezeRows.Count)
+        if (!IsInitialized || !IsStarted || IsBlanked || IsPreShowVisible ||
+            State != PresentationRowState.RowInProgress || !CanReveal())
         {
-            return RevealOutcome.None;
+            return;
         }
 
-        var teamRow = PreFreezeRows[FocusedRowIndex];
-        var team = teamRow.TeamStatus;
-        if (!_pendingRevealsByTeamId.TryGetValue(team.TeamId, out var pending) || pending.Count == 0)
+        var row = PreFreezeRows[FocusedRowIndex];
+        var problemId = row.FindProblemId(cell);
+        if (problemId is null || !GetPendingProblemIds(row.TeamId).Contains(problemId, StringComparer.Ordinal))
         {
-            return RevealOutcome.None;
+            Trace.WriteLine($"[PresentationStageVM] CellClickIgnored: team={row.TeamId}, problem={problemId ?? "<none>"}");
+            return;
         }
 
-        var problemId = pending.Dequeue();
-        if (!team.ProblemStats.TryGetValue(problemId, out var stat))
+        if (_loadedConfig.Presentation.RevealOrder == RevealOrder.Operator)
         {
-            return RevealOutcome.None;
+            // Queue the cell for the next Space so the presenter can announce it first; Backspace undoes a mis-click.
+            var reorderSnapshot = CaptureSnapshot();
+            _pendingRevealsByTeamId[row.TeamId] = new Queue<string>(
+                GetPendingProblemIds(row.TeamId)
+                    .OrderBy(id => string.Equals(id, problemId, StringComparison.Ordinal) ? 0 : 1)
+                    .ToList());
+            PushHistory(reorderSnapshot);
+            RefreshDerivedState();
+            Trace.WriteLine($"[PresentationStageVM] CellClickQueued: team={row.TeamId}, problem={problemId}");
+            return;
         }
 
-        stat.AttemptedDuringFreeze = false;
-        var solved = false;
-        if (stat.Solved)
+        var snapshot = CaptureSnapshot();
+        var outcome = RunReveal(problemId);
+        if (!outcome.Applied)
         {
-            solved = true;
-            team.TotalPoints += 1;
-            team.TotalPenalty += stat.Penalty;
-
-            if (stat.FirstAcTime.HasValue && (!team.LastAcTime.HasValue || stat.FirstAcTime > team.LastAcTime))
-            {
-                team.LastAcTime = stat.FirstAcTime;
-            }
+            return;
         }
 
-        teamRow.RefreshFromSource();
-        return new RevealOutcome(true, solved, solved, solved ? team.TeamId : null);
+        _soundPlayer.Play(outcome.Solved ? SoundEffect.Accepted : SoundEffect.Rejected);
+        _pendingResortSolvedTeamId = outcome.NeedResort ? outcome.SolvedTeamId : null;
+        State = outcome.NeedResort ? PresentationRowState.RowInProgressAwaitResort : PresentationRowState.RowInProgress;
+        StartPhaseHold(_loadedConfig.Presentation.Timing.RevealPauseSeconds);
+        PushHistory(snapshot);
+        RefreshDerivedState();
+        ScheduleAutopilotStep();
+        Trace.WriteLine($"[PresentationStageVM] CellClickReveal: team={row.TeamId}, problem={problemId}, state={State}");
     }
 
-    private bool MoveUp()
+    /// <summary>
+    ///     Rehearsal shortcut: applies every remaining frozen result, sorts the board into its final order and ends
+    ///     the presentation without animations, awards, or the champion celebration. Undo restores the prior state.
+    /// </summary>
+    public void HandleRevealAllPressed()
     {
-        if (FocusedRowIndex <= 0 || FocusedRowIndex >= PreFreezeRows.Count)
+        if (!IsInitialized || !IsStarted || IsBlanked || IsPreShowVisible || State == PresentationRowState.Finished)
         {
-            return false;
+            return;
+        }
+
+        SetAutopilotEnabled(false);
+        var snapshot = CaptureSnapshot(allTeams: true);
+        foreach (var row in PreFreezeRows)
+        {
+            if (!_pendingRevealsByTeamId.TryGetValue(row.TeamId, out var pending))
+            {
+                continue;
+            }
+
+            while (pending.Count > 0)
+            {
+                if (row.TeamStatus.ProblemStats.TryGetValue(pending.Dequeue(), out var stat))
+                {
+                    ApplyFrozenResult(row.TeamStatus, stat);
+                }
+            }
+
+            row.RefreshFromSource();
+        }
+
+        ApplyRowOrder(PreFreezeRows.OrderBy(row => row.TeamStatus).Select(row => row.TeamId).ToList());
+        RefreshRanks();
+        RefreshProblemHeaders();
+        HideAwardOverlay();
+        _pendingResortSolvedTeamId = null;
+        SetFocusedRowIndexWithRefresh(PreFreezeRows.Count > 0 ? 0 : -1);
+        _isRevealingAll = true;
+        State = PresentationRowState.Finished;
+        _isRevealingAll = false;
+        PushHistory(snapshot);
+        RefreshDerivedState();
+        RevealCommand.NotifyCanExecuteChanged();
+        MoveUpCommand.NotifyCanExecuteChanged();
+        Trace.WriteLine("[PresentationStageVM] RevealAll: fast-forwarded to the final standings.");
+    }
+
+    /// <summary>
+    ///     Steps the global animation speed through <see cref="AnimationSpeedSteps" />; <paramref name="direction" />
+    ///     of zero resets it to 1x.
+    /// </summary>
+    public void HandleAnimationSpeedChanged(int direction)
+    {
+        if (direction == 0)
+        {
+            AnimationSpeed = 1;
+        }
+        else
+        {
+            var index = Array.FindIndex(AnimationSpeedSteps, step => step >= AnimationSpeed - 0.001);
+            index = Math.Clamp((index < 0 ? AnimationSpeedSteps.Length - 1 : index) + Math.Sign(direction), 0,
+                AnimationSpeedSteps.Length - 1);
+            AnimationSpeed = AnimationSpeedSteps[index];
+        }
+
+        Trace.WriteLine($"[PresentationStageVM] AnimationSpeed: {AnimationSpeed}");
+    }
+
+    /// <summary>
+    ///     Sets the zoom the stage starts at and resets to, from <c>ui_scale</c> or the size the view detected for
+    ///     <c>"auto"</c>. A manual zoom is kept until the base scale actually changes.
+    /// </summary>
+    public void SetBaseStageScale(double scale)
+    {
+        scale = Math.Round(Math.Clamp(scale, StageScaleSteps[0], StageScaleSteps[^1]), 2);
+        if (Math.Abs(scale - _baseStageScale) < 0.001)
+        {
+            return;
+        }
+
+        _baseStageScale = scale;
+        StageScale = scale;
+        Trace.WriteLine($"[PresentationStageVM] BaseStageScale: {scale}");
+    }
+
+    /// <summary>
+    ///     Steps the stage zoom through <see cref="StageScaleSteps" />; <paramref name="direction" /> 0 resets it to the
+    ///     base scale from <see cref="SetBaseStageScale" />.
+    /// </summary>
+    public void HandleStageScaleChanged(int direction)
+    {
+        if (direction == 0)
+        {
+            StageScale = _baseStageScale;
+        }
+        else
+        {
+            var index = Array.FindIndex(StageScaleSteps, step => step >= StageScale - 0.001);
+            index = Math.Clamp((index < 0 ? StageScaleSteps.Length - 1 : index) + Math.Sign(direction), 0,
+                StageScaleSteps.Length - 1);
+            StageScale = StageScaleSteps[index];
+        }
+
+        Trace.WriteLine($"[PresentationStageVM] StageScale: {StageScale}");
+    }
+
+    public void HandleHotkeyHelpToggled()
+    {
+        IsHotkeyHelpVisible = !IsHotkeyHelpVisible;
+    }
+
+    public void HandleHotkeyHelpDismissed()
+    {
+        IsHotkeyHelpVisible = false;
+    }
+
+    /// <summary>
+    ///     Shows each team's pre-freeze rank under its current rank, colored by whether the reveal moved it up or down.
+    /// </summary>
+    public void HandleRankComparisonToggled()
+    {
+        if (!IsInitialized)
+        {
+            return;
+        }
+
+        IsRankComparisonVisible = !IsRankComparisonVisible;
+        foreach (var row in PreFreezeRows)
+        {
+            row.ShowPreFreezeRank = IsRankComparisonVisible;
+        }
+
+        Trace.WriteLine($"[PresentationStageVM] RankComparison: {IsRankComparisonVisible}");
+    }
+
+    /// <summary>
+    ///     Covers the scoreboard with black or the configured holding slide. Resolver state is untouched; advancing is
+    ///     ignored and autopilot is paused while blanked so nothing happens off-screen.
+    /// </summary>
+    public void HandleBlankToggled()
+    {
+        if (!IsInitialized || !IsStarted)
+        {
+            return;
+        }
+
+        IsBlanked = !IsBlanked;
+        if (IsBlanked && IsAutopilotEnabled)
+        {
+            IsAutopilotPaused = true;
+            ScheduleAutopilotStep();
+        }
+
+        Trace.WriteLine($"[PresentationStageVM] Blank: {IsBlanked}");
+    }
+
+    /// <summary>
+    ///     Applies an edited <c>config.toml</c> to the running presentation: timings, colors, fonts, key bindings, and
+    ///     display options take effect at once. Settings that decide what the board contains (filters, category
+    ///     mapping, name overrides, <c>reveal_order</c>, <c>present_top_teams</c>, <c>start_rank</c>) and the images
+    ///     and sounds loaded at launch keep their launch values until the presentation is started again.
+    /// </summary>
+    public void ApplyReloadedConfig(PyriteConfig config)
+    {
+        ArgumentNullException.ThrowIfNull(config);
+
+        var previous = _loadedConfig;
+        config.Language = previous.Language;
+        config.FilterTeamSubmissions = previous.FilterTeamSubmissions;
+        config.HiddenProblems = previous.HiddenProblems;
+        config.TeamGroupMap = previous.TeamGroupMap;
+        config.TeamNames = previous.TeamNames;
+        config.GroupNames = previous.GroupNames;
+        config.GroupPresets = previous.GroupPresets;
+        config.Presentation.RevealOrder = previous.Presentation.RevealOrder;
+        config.Presentation.PresentTopTeams = previous.Presentation.PresentTopTeams;
+        config.Presentation.StartRank = previous.Presentation.StartRank;
+        config.Presentation.SummaryScreen = previous.Presentation.SummaryScreen;
+        config.Presentation.Sounds = previous.Presentation.Sounds;
+        _loadedConfig = config;
+        _logoCache.MaxApproxBytes = config.Presentation.ImageCacheMegabytes * 1024L * 1024;
+
+        // A palette picked with the hotkey stays; otherwise follow the edited palette.
+        if (_colorPalette == previous.Presentation.Palette)
+        {
+            _colorPalette = config.Presentation.Palette;
+        }
+
+        ActiveColors = _colorPalette == config.Presentation.Palette
+            ? config.Presentation.Colors
+            : config.Presentation.Colors.WithPalette(_colorPalette);
+        foreach (var row in PreFreezeRows)
+        {
+            row.SetColors(ActiveColors);
+        }
+
+        OnPropertyChanged(nameof(PresentationConfig));
+        OnPropertyChanged(nameof(ShowProblemSolveCounts));
+        OnPropertyChanged(nameof(ShowInfoBarTop));
+        OnPropertyChanged(nameof(ShowInfoBarBottom));
+        OnPropertyChanged(nameof(ShowTeamLabels));
+        OnPropertyChanged(nameof(RowFlyAnimationSeconds));
+        OnPropertyChanged(nameof(ScrollAnimationSeconds));
+        OnPropertyChanged(nameof(Keys));
+        OnPropertyChanged(nameof(HotkeyHelpItems));
+        RefreshFontFilePaths();
+        RefreshProblemHeaders();
+        RefreshMedalTints();
+        ScheduleAutopilotStep();
+        Trace.WriteLine("[PresentationStageVM] ConfigReloaded");
+    }
+
+    /// <summary>
+    ///     The keys of <paramref name="action" /> as the help overlay shows them, following <c>[keys]</c>.
+    /// </summary>
+    public string FormatActionKeys(KeyAction action)
+    {
+        var (defaultKeys, _, actions) = HotkeyHelp.First(item => item.Actions.SequenceEqual([action]));
+        return FormatHotkeys(defaultKeys, actions);
+    }
+
+    private string FormatHotkeys(string defaultKeys, KeyAction[] actions)
+    {
+        var keys = _loadedConfig.Keys;
+        if (!actions.Any(action => keys.IsRemapped(action) ||
+                                   keys.GetGestures(action).Count != KeysConfig.Defaults[action].Length))
+        {
+            return defaultKeys;
+        }
+
+        var gestures = actions.SelectMany(keys.GetGestures).ToList();
+        return gestures.Count == 0 ? "-" : string.Join(" / ", gestures);
+    }
+
+    public void HandlePaletteCycled()
+    {
+        var palettes = Enum.GetValues<ColorPalette>();
+        _colorPalette = palettes[(Array.IndexOf(palettes, _colorPalette) + 1) % palettes.Length];
+        // Returning to the configured palette restores any [presentation.colors] overrides of the preset colors.
+        ActiveColors = _colorPalette == _loadedConfig.Presentation.Palette
+            ? _loadedConfig.Presentation.Colors
+            : _loadedConfig.Presentation.Colors.WithPalette(_colorPalette);
+        foreach (var row in PreFreezeRows)
+        {
+            row.SetColors(ActiveColors);
+        }
+
+        RefreshMedalTints();
+        Trace.WriteLine($"[PresentationStageVM] Palette: {_colorPalette}");
+    }
+
+    public void HandleMuteToggled()
+    {
+        _soundPlayer.IsMuted = !_soundPlayer.IsMuted;
+        IsSoundMuted = _soundPlayer.IsMuted;
+        Trace.WriteLine($"[PresentationStageVM] Sound: muted={IsSoundMuted}");
+    }
+
+    private bool AdvanceStep()
+    {
+        // Template for your transition logic:
+        // - decide next state
+        // - decide whether to call Reveal or MoveUp
+        Trace.WriteLine($"[PresentationStageVM] StateBefore: state={State}, focusIndex={FocusedRowIndex}");
+        var snapshot = CaptureSnapshot();
+        var changed = true;
+        var timing = _loadedConfig.Presentation.Timing;
+        var holdSeconds = 0f;
+        switch (State)
+        {
+            case PresentationRowState.RowInProgress:
+                if (FocusedRowIndex < 0 || FocusedRowIndex >= PreFreezeRows.Count)
+                {
+                    Trace.WriteLine($"[PresentationStageVM] InvalidFocusIndex: focusIndex={FocusedRowIndex}, rowCount={PreFreezeRows.Count}");
+                    State = PresentationRowState.RowInProgress;
+                    changed = false;
+                    break;
+                }
+
+                var teamId = PreFreezeRows[FocusedRowIndex].TeamId;
+                if (HasPendingReveal(teamId))
+                {
+                    Trace.WriteLine($"[PresentationStageVM] Action: reveal, focusIndex={FocusedRowIndex}");
+                    var revealOutcome = RunReveal();
+                    if (revealOutcome.Applied)
+                    {
+                        _soundPlayer.Play(revealOutcome.Solved ? SoundEffect.Accepted : SoundEffect.Rejected);
+                        holdSeconds = timing.RevealPauseSeconds;
+                    }
+
+                    if (revealOutcome.NeedResort)
+                    {
+                        _pendingResortSolvedTeamId = revealOutcome.SolvedTeamId;
+                        State = PresentationRowState.RowInProgressAwaitResort;
+                    }
+                    else
+                    {
+                        _pendingResortSolvedTeamId = null;
+                        State = PresentationRowState.RowInProgress;
+                    }
+                }
+                else
+                {
+                    Trace.WriteLine($"[PresentationStageVM] TeamNoPendingReveal: focusIndex={FocusedRowIndex}");
+                    if (HasAwards(teamId))
+                    {
+                        ShowAwardOverlay(teamId);
+                        _soundPlayer.Play(SoundEffect.Award);
+                        holdSeconds = timing.OverlayMinSeconds;
+                        State = PresentationRowState.RowCompleteAwardShowing;
+                    }
+                    else if (FocusedRowIndex == 0)
+                    {
+                        FinishPresentation();
+                    }
+                    else
+                    {
+                        Trace.WriteLine("[PresentationStageVM] Action: move_up");
+                        changed = RunMoveUp();
+                        PlayPendingFocusSound();
+                        holdSeconds = timing.FocusPauseSeconds;
+                        State = PresentationRowState.RowInProgress;
+                    }
+                }
+
+                break;
+            case PresentationRowState.RowInProgressAwaitResort:
+                ResortScoreboard(_pendingResortSolvedTeamId);
+                _pendingResortSolvedTeamId = null;
+                State = PresentationRowState.RowInProgress;
+                break;
+            case PresentationRowState.RowCompleteAwardShowing:
+                HideAwardOverlay();
+                State = PresentationRowState.RowCompleteReadyToAdvance;
+                break;
+            case PresentationRowState.RowCompleteReadyToAdvance:
+                if (FocusedRowIndex == 0)
+                {
+                    FinishPresentation();
+                    break;
+                }
+
+                RunMoveUp();
+                PlayPendingFocusSound();
+                holdSeconds = timing.FocusPauseSeconds;
+                State = PresentationRowState.RowInProgress;
+                break;
+            case PresentationRowState.ChampionCelebration:
+                State = Summary is not null ? PresentationRowState.Summary : PresentationRowState.Finished;
+                break;
+            case PresentationRowState.Summary:
+                State = PresentationRowState.Finished;
+                break;
+            case PresentationRowState.Finished:
+                changed = false;
+                break;
+            default:
+                throw new ArgumentOutOfRangeException();
+        }
+
+        if (changed)
+        {
+            PushHistory(snapshot);
+            StartPhaseHold(holdSeconds);
+        }
+
+        RefreshDerivedState();
+
+        Trace.WriteLine($"[PresentationStageVM] StateAfter: state={State}");
+        return changed;
+    }
+
+    public void HandleUndoPressed()
+    {
+        if (IsIntroVisible)
+        {
+            SetIntroSlideIndex(Math.Max(0, _introSlideIndex - 1));
+            return;
+        }
+
+        if (!IsInitialized || !IsStarted || _history.Count == 0)
+        {
+            return;
+        }
+
+        var snapshot = _history.Last!.Value;
+        _history.RemoveLast();
+        RestoreSnapshot(snapshot);
+        StartPhaseHold(0);
+        OnPropertyChanged(nameof(CanUndo));
+        ScheduleAutopilotStep();
+        Trace.WriteLine($"[PresentationStageVM] Undo: state={State}, focusIndex={FocusedRowIndex}, remaining={_history.Count}");
+    }
+
+    private void RequestExit()
+    {
+        ExitRequested?.Invoke();
+    }
+
+    private void RefreshSessionStatus()
+    {
+        OnPropertyChanged(nameof(SessionStatus));
+    }
+
+    private void RefreshFontFilePaths()
+    {
+        FontFilePaths = _loadedConfig.Fonts.Files
+            .Select(ResolveDataRelativePath)
+            .OfType<string>()
+            .Distinct(StringComparer.Ordinal)
+            .ToList();
+        OnPropertyChanged(nameof(FontFilePaths));
+    }
+
+    private void StartConfigWatcher()
+    {
+        StopConfigWatcher();
+        if (string.IsNullOrWhiteSpace(_dataPath) || !Directory.Exists(_dataPath))
+        {
+            return;
+        }
+
+        try
+        {
+            _configWatcher = new FileSystemWatcher(_dataPath, "config.toml")
+            {
+                NotifyFilter = NotifyFilters.LastWrite | NotifyFilters.FileName | NotifyFilters.Size
+            };
+            _configWatcher.Changed += OnConfigFileChanged;
+            _configWatcher.Created += OnConfigFileChanged;
+            _configWatcher.Renamed += OnConfigFileChanged;
+            _configWatcher.EnableRaisingEvents = true;
+        }
+        catch (Exception ex) when (ex is IOException or ArgumentException or PlatformNotSupportedException)
+        {
+            Trace.WriteLine($"[PresentationStageVM] ConfigWatcherFailed: {ex.Message}");
+            StopConfigWatcher();
+        }
+    }
+
+    private void StopConfigWatcher()
+    {
+        _configReloadTimer.Stop();
+        if (_configWatcher is null)
+        {
+            return;
+        }
+
+        _configWatcher.EnableRaisingEvents = false;
+        _configWatcher.Dispose();
+        _configWatcher = null;
+    }
+
+    private void OnConfigFileChanged(object sender, FileSystemEventArgs e)
+    {
+        Dispatcher.UIThread.Post(() =>
+        {
+            if (_configWatcher is null)
+            {
+                return;
+            }
+
+            _configReloadTimer.Stop();
+            _configReloadTimer.Start();
+        });
+    }
+
+    private void ReloadConfig()
+    {
+        _configReloadTimer.Stop();
+        if (string.IsNullOrWhiteSpace(_dataPath))
+        {
+            return;
+        }
+
+        try
+        {
+            ApplyReloadedConfig(ConfigLoader.LoadIfExists(_dataPath));
+        }
+        catch (Exception ex)
+        {
+            // A half-typed edit must not stop the ceremony; the last good config stays in effect.
+            Trace.WriteLine($"[PresentationStageVM] ConfigReloadFailed: {ex.Message}");
+        }
+    }
+
+    private void PostResultsWebhook()
+    {
+        // Undo can step back from Finished and reach it again; the standings only go out once per presentation.
+        var webhook = _loadedConfig.Webhook;
+        if (_isResultsWebhookSent || _contestState is null || string.IsNullOrWhiteSpace(webhook.Url))
+        {
+            return;
+        }
+
+        _isResultsWebhookSent = true;
+        _ = PostResultsWebhookAsync(webhook, _contestState);
+    }
+
+    private static async Task PostResultsWebhookAsync(WebhookConfig webhook, ContestState contestState)
+    {
+        try
+        {
+            await ResultsWebhook.PostAsync(webhook, contestState);
+            Trace.WriteLine($"[PresentationStageVM] ResultsWebhook: posted to {webhook.Url}");
+        }
+        catch (Exception ex)
+        {
+            Trace.WriteLine($"[PresentationStageVM] ResultsWebhookFailed: {ex.Message}");
+        }
+    }
+
+    private void SetAutopilotEnabled(bool enabled)
+    {
+        IsAutopilotEnabled = enabled;
+        IsAutopilotPaused = false;
+        ScheduleAutopilotStep();
+        Trace.WriteLine($"[PresentationStageVM] Autopilot: enabled={enabled}");
+    }
+
+    private void ScheduleAutopilotStep()
+    {
+        _autopilotTimer.Stop();
+        if (!IsAutopilotEnabled || IsAutopilotPaused || !IsStarted)
+        {
+            return;
+        }
+
+        _autopilotTimer.Interval =
+            TimeSpan.FromSeconds(Math.Max(0.05, Math.Max(GetAutopilotDelaySeconds(), GetPhaseHoldRemainingSeconds())));
+        _autopilotTimer.Start();
+    }
+
+    private float GetAutopilotDelaySeconds()
+    {
+        var autopilot = _loadedConfig.Presentation.Autopilot;
+        return State switch
+        {
+            PresentationRowState.RowInProgressAwaitResort => autopilot.ResortDelaySeconds,
+            PresentationRowState.RowCompleteAwardShowing => autopilot.AwardDelaySeconds,
+            PresentationRowState.RowInProgress when CanReveal() => autopilot.RevealDelaySeconds,
+            _ => autopilot.AdvanceDelaySeconds
+        };
+    }
+
+    private void StartPhaseHold(float seconds)
+    {
+        _phaseHoldStartUtc = DateTime.UtcNow;
+        _phaseHoldSeconds = seconds;
+    }
+
+    /// <summary>
+    ///     Seconds left before the next step may run: the <c>[presentation.timing]</c> pause after the last step and,
+    ///     when the next step opens an award overlay, the pause before it.
+    /// </summary>
+    private double GetPhaseHoldRemainingSeconds()
+    {
+        var holdSeconds = (double)_phaseHoldSeconds;
+        if (State == PresentationRowState.RowInProgress && FocusedRowIndex >= 0 &&
+            FocusedRowIndex < PreFreezeRows.Count)
+        {
+            var teamId = PreFreezeRows[FocusedRowIndex].TeamId;
+            if (!HasPendingReveal(teamId) && HasAwards(teamId))
+            {
+                holdSeconds = Math.Max(holdSeconds, _loadedConfig.Presentation.Timing.OverlayDelaySeconds);
+            }
+        }
+
+        return holdSeconds - (DateTime.UtcNow - _phaseHoldStartUtc).TotalSeconds;
+    }
+
+    private void OnAutopilotTick(object? sender, EventArgs e)
+    {
+        _autopilotTimer.Stop();
+        if (!IsAutopilotEnabled || IsAutopilotPaused || !IsStarted)
+        {
+            return;
+        }
+
+        // The champion celebration and summary hold until the operator dismisses them, so autopilot stops there.
+        if (!AdvanceStep() || State is PresentationRowState.ChampionCelebration or PresentationRowState.Summary
+                or PresentationRowState.Finished)
+        {
+            IsAutopilotPaused = true;
+            return;
+        }
+
+        ScheduleAutopilotStep();
+    }
+
+    /// <summary>
+    ///     Pulses the focused team's next pending cell for <see cref="PresentationConfig.PendingPulseSeconds" /> to build
+    ///     suspense before the reveal.
+    /// </summary>
+    private void UpdateRevealTarget()
+    {
+        ClearRevealTarget();
+        var pulseSeconds = _loadedConfig.Presentation.PendingPulseSeconds;
+        if (pulseSeconds <= 0 || IsPreShowVisible || State != PresentationRowState.RowInProgress || !CanReveal())
+        {
+            return;
+        }
+
+        var row = PreFreezeRows[FocusedRowIndex];
+        row.SetRevealTarget(GetPendingProblemIds(row.TeamId).First());
+        _revealTargetRow = row;
+        _pendingPulseTimer.Interval = TimeSpan.FromSeconds(pulseSeconds);
+        _pendingPulseTimer.Start();
+    }
+
+    /// <summary>
+    ///     Rows below the focus are complete, and so is the focused row once its award has been dismissed, so badges
+    ///     can be derived from the position instead of being tracked through undo.
+    /// </summary>
+    private void RefreshAwardBadges()
+    {
+        var focusedRowComplete = State is PresentationRowState.RowCompleteReadyToAdvance
+            or PresentationRowState.ChampionCelebration
+            or PresentationRowState.Summary
+            or PresentationRowState.Finished;
+        for (var i = 0; i < PreFreezeRows.Count; i++)
+        {
+            var complete = i > FocusedRowIndex || (i == FocusedRowIndex && focusedRowComplete);
+            PreFreezeRows[i].ShowAwardBadges = FocusedRowIndex >= 0 && complete;
+        }
+    }
+
+    private void RefreshDerivedState()
+    {
+        UpdateRevealTarget();
+        RefreshAwardBadges();
+        RefreshProgressText();
+    }
+
+    private void RefreshProgressText()
+    {
+        var frozenCells = _pendingRevealsByTeamId.Values.Sum(queue => queue.Count);
+        var teamsRemaining = State is PresentationRowState.ChampionCelebration or PresentationRowState.Summary
+            or PresentationRowState.Finished
+            ? 0
+            : Math.Max(0, FocusedRowIndex + (State == PresentationRowState.RowCompleteReadyToAdvance ? 0 : 1));
+        ProgressText = Localizer.Instance.Format("present.progress", teamsRemaining, frozenCells);
+    }
+
+    private void ClearRevealTarget()
+    {
+        _pendingPulseTimer.Stop();
+        _revealTargetRow?.SetRevealTarget(null);
+        _revealTargetRow = null;
+    }
+
+    private void PlayPendingFocusSound()
+    {
+        if (CanReveal())
+        {
+            _soundPlayer.Play(SoundEffect.PendingFocus);
+        }
+    }
+
+    private PresentationSnapshot CaptureSnapshot(bool allTeams = false)
+    {
+        // Each step only mutates the focused team, so the board snapshot is the row order plus that team.
+        var teams = new Dictionary<string, TeamStatus>(StringComparer.Ordinal);
+        var pendingReveals = new Dictionary<string, string[]>(StringComparer.Ordinal);
+        for (var i = 0; i < PreFreezeRows.Count; i++)
+        {
+            if (!allTeams && i != FocusedRowIndex)
+            {
+                continue;
+            }
+
+            var team = PreFreezeRows[i].TeamStatus;
+            teams[team.TeamId] = CloneTeamStatus(team);
+            pendingReveals[team.TeamId] = _pendingRevealsByTeamId.TryGetValue(team.TeamId, out var queue)
+                ? queue.ToArray()
+                : [];
+        }
+
+        return new PresentationSnapshot(
+            State,
+            FocusedRowIndex,
+            _pendingResortSolvedTeamId,
+            IsAwardOverlayVisible ? _awardOverlayTeamId : null,
+            PreFreezeRows.Select(row => row.TeamId).ToList(),
+            teams,
+            pendingReveals);
+    }
+
+    private void PushHistory(PresentationSnapshot snapshot)
+    {
+        _history.AddLast(snapshot);
+        while (_history.Count > MaxUndoHistory)
+        {
+            _history.RemoveFirst();
+        }
+
+        OnPropertyChanged(nameof(CanUndo));
+        RefreshSessionStatus();
+    }
+
+    private void ClearHistory()
+    {
+        _history.Clear();
+        OnPropertyChanged(nameof(CanUndo));
+    }
+
+    private void RestoreSnapshot(PresentationSnapshot snapshot)
+    {
+        foreach (var row in PreFreezeRows)
+        {
+            if (!snapshot.Teams.TryGetValue(row.TeamId, out var savedTeam))
+            {
+                continue;
+            }
+
+            CopyTeamStatus(savedTeam, row.TeamStatus);
+            _pendingRevealsByTeamId[row.TeamId] = new Queue<string>(snapshot.PendingReveals[row.TeamId]);
+            row.RefreshFromSource();
+            row.ClearRankDelta();
+        }
+
+        ApplyRowOrder(snapshot.RowOrder);
+        RefreshRanks();
+        RefreshProblemHeaders();
+        _pendingResortSolvedTeamId = snapshot.PendingResortSolvedTeamId;
+        SetFocusedRowIndexWithRefresh(snapshot.FocusedRowIndex);
+
+        if (snapshot.AwardOverlayTeamId is not null)
+        {
+            ShowAwardOverlay(snapshot.AwardOverlayTeamId);
+        }
+        else
+        {
+            HideAwardOverlay();
+        }
+
+        if (snapshot.State == PresentationRowState.ChampionCelebration)
+        {
+            LoadChampionDetails();
+        }
+
+        State = snapshot.State;
+        RefreshDerivedState();
+        RevealCommand.NotifyCanExecuteChanged();
+        MoveUpCommand.NotifyCanExecuteChanged();
+        RefreshSessionStatus();
+    }
+
+    private void ApplyRowOrder(IReadOnlyList<string> teamIds)
+    {
+        for (var targetIndex = 0; targetIndex < teamIds.Count && targetIndex < PreFreezeRows.Count; targetIndex++)
+        {
+            var currentIndex = -1;
+            for (var i = targetIndex; i < PreFreezeRows.Count; i++)
+            {
+                if (string.Equals(PreFreezeRows[i].TeamId, teamIds[targetIndex], StringComparison.Ordinal))
+                {
+                    currentIndex = i;
+                    break;
+                }
+            }
+
+            if (currentIndex > targetIndex)
+            {
+                PreFreezeRows.Move(currentIndex, targetIndex);
+            }
+        }
+
+        ApplyLogoRange();
+    }
+
+    private void SetFocusedRowIndexWithRefresh(int index)
+    {
+        if (index != FocusedRowIndex)
+        {
+            FocusedRowIndex = index;
+            return;
+        }
+
+        // After collection moves, ListBox can keep selected *item* while index value is unchanged.
+        // Pulse SelectedIndex through -1 to force container :selected state refresh.
+        _focusedRowIndex = -1;
+        OnPropertyChanged(nameof(FocusedRowIndex));
+        _focusedRowIndex = index;
+        OnPropertyChanged(nameof(FocusedRowIndex));
+        RefreshSessionStatus();
+    }
+
+    /// <summary>
+    ///     The countdown or an intro slide is covering the board, so nothing may be revealed yet.
+    /// </summary>
+    private bool IsPreShowVisible => IsCountdownVisible || IsIntroVisible;
+
+    private void StartCountdown()
+    {
+        var seconds = _loadedConfig.Presentation.CountdownSeconds;
+        if (seconds <= 0)
+        {
+            StopCountdown();
+            return;
+        }
+
+        _countdownEndUtc = DateTime.UtcNow.AddSeconds(seconds);
+        RefreshCountdownText();
+        IsCountdownVisible = true;
+        _countdownTimer.Start();
+    }
+
+    private void StopCountdown()
+    {
+        _countdownTimer.Stop();
+        IsCountdownVisible = false;
+    }
+
+    private void RefreshCountdownText()
+    {
+        var remaining = _countdownEndUtc - DateTime.UtcNow;
+        var totalSeconds = Math.Max(0, (int)Math.Ceiling(remaining.TotalSeconds));
+        var clock = totalSeconds >= 3600
+            ? $"{totalSeconds / 3600}:{totalSeconds / 60 % 60:D2}:{totalSeconds % 60:D2}"
+            : $"{totalSeconds / 60}:{totalSeconds % 60:D2}";
+        var format = _loadedConfig.Presentation.CountdownText ?? Localizer.Instance["present.countdown"];
+        CountdownText = string.Format(CultureInfo.InvariantCulture, format, clock);
+        if (totalSeconds == 0)
+        {
+            // Hold "0:00" on screen until the presenter presses Space.
+            _countdownTimer.Stop();
+        }
+    }
+
+    private void SetIntroSlideIndex(int index)
+    {
+        _introSlideIndex = index;
+        OnPropertyChanged(nameof(CurrentIntroSlide));
+        OnPropertyChanged(nameof(IsIntroVisible));
+        RefreshDerivedState();
+    }
+
+    /// <summary>
+    ///     Each <c>intro_slides</c> entry is <c>statistics</c>, <c>problems</c>, or an image path. Statistics only use
+    ///     pre-freeze data so nothing about the frozen results leaks before the reveal.
+    /// </summary>
+    private IReadOnlyList<IntroSlideViewModel> BuildIntroSlides(ContestState contestState)
+    {
+        var slides = new List<IntroSlideViewModel>();
+        foreach (var entry in _loadedConfig.Presentation.IntroSlides)
+        {
+            switch (entry)
+            {
+                case "statistics":
+                    var leaderboard = GetPresentedLeaderboard(contestState);
+                    slides.Add(IntroSlideViewModel.ForStatistics(
+                        string.IsNullOrWhiteSpace(ContestName) ? Localizer.Instance["present.intro.statistics"] : ContestName,
+                        [
+                            new IntroStatisticItem(Localizer.Instance["present.intro.teams"], leaderboard.Count),
+                            new IntroStatisticItem(Localizer.Instance["present.intro.problems"], _orderedProblems.Count),
+                            new IntroStatisticItem(
+                                Localizer.Instance["present.intro.submissions"],
+                                contestState.Submissions.Count),
+                            new IntroStatisticItem(
+                                Localizer.Instance["present.intro.solved_before_freeze"],
+                                leaderboard.Sum(team => team.TotalPoints)),
+                            new IntroStatisticItem(
+                                Localizer.Instance["present.intro.frozen_cells"],
+                                _pendingRevealsByTeamId.Values.Sum(queue => queue.Count))
+                        ]));
+                    break;
+                case "problems":
+                    slides.Add(IntroSlideViewModel.ForProblems(
+                        Localizer.Instance["present.intro.problems"],
+                        _orderedProblems
+                            .Select(problem => new IntroProblemItem(
+                                problem,
+                                contestState.Problems.TryGetValue(problem.Id, out var source) ? source.Name : string.Empty))
+                            .ToList()));
+                    break;
+                default:
+                    var image = LoadAwardBackgroundImage(ResolveDataRelativePath(entry));
+                    if (image is null)
+                    {
+                        Trace.WriteLine($"[PresentationStageVM] IntroSlideSkipped: '{entry}' not found.");
+                        break;
+                    }
+
+                    slides.Add(IntroSlideViewModel.ForImage(image));
+                    break;
+            }
+        }
+
+        return slides;
+    }
+
+    /// <summary>
+    ///     Compares the presented teams on the pre-freeze and finalized leaderboards for the <c>summary_screen</c> shown
+    ///     after the reveal. Ranks are positions among the presented teams, the same numbers the board showed.
+    /// </summary>
+    private ResultSummaryViewModel BuildResultSummary(ContestState contestState)
+    {
+        var preFreeze = GetPresentedLeaderboard(contestState);
+        var presentedTeamIds = preFreeze.Select(team => team.TeamId).ToHashSet(StringComparer.Ordinal);
+        var finalized = contestState.LeaderboardFinalized
+            .Where(team => presentedTeamIds.Contains(team.TeamId))
+            .ToList();
+        var finalRankByTeamId = new Dictionary<string, int>(finalized.Count, StringComparer.Ordinal);
+        for (var i = 0; i < finalized.Count; i++)
+        {
+            finalRankByTeamId[finalized[i].TeamId] = i + 1;
+        }
+
+        var climbers = preFreeze
+            .Select((team, index) => (
+                Team: team,
+                FromRank: index + 1,
+                ToRank: finalRankByTeamId.GetValueOrDefault(team.TeamId, index + 1)))
+            .Where(entry => entry.ToRank < entry.FromRank)
+            .OrderByDescending(entry => entry.FromRank - entry.ToRank)
+            .ThenBy(entry => entry.ToRank)
+            .Take(SummaryClimberCount)
+            .Select(entry => new ResultSummaryClimberItem(
+                entry.Team.TeamName,
+                entry.FromRank,
+                entry.ToRank))
+            .ToList();
+
+        var highlights = new List<ResultSummaryHighlightItem>();
+        var labelByProblemId = _orderedProblems.ToDictionary(problem => problem.Id, problem => problem.Label, StringComparer.Ordinal);
+        if (contestState.Contest?.StartTime is { } startTime)
+        {
+            var fastest = finalized
+                .SelectMany(team => team.ProblemStats
+                    .Where(stat => stat.Value.Solved && stat.Value.FirstAcTime is not null)
+                    .Select(stat => (Team: team, ProblemId: stat.Key, AcTime: stat.Value.FirstAcTime!.Value)))
+                .OrderBy(entry => entry.AcTime)
+                .FirstOrDefault();
+            if (fastest.Team is not null)
+            {
+                highlights.Add(new ResultSummaryHighlightItem(
+                    Localizer.Instance["present.summary.fastest_solve"],
+                    Localizer.Instance.Format(
+                        "present.summary.fastest_solve_value",
+                        labelByProblemId.GetValueOrDefault(fastest.ProblemId, fastest.ProblemId),
+                        fastest.Team.TeamName,
+                        (long)(fastest.AcTime - startTime).TotalMinutes)));
+            }
+        }
+
+        var mostAttempted = finalized
+            .SelectMany(team => team.ProblemStats)
+            .GroupBy(stat => stat.Key, stat => stat.Value.SubmissionsBeforeSolved, StringComparer.Ordinal)
+            .Select(group => (ProblemId: group.Key, Attempts: group.Sum()))
+            .Where(entry => entry.Attempts > 0)
+            .OrderByDescending(entry => entry.Attempts)
+            .ThenBy(entry => _orderedProblems.FindIndex(problem => problem.Id == entry.ProblemId))
+            .FirstOrDefault();
+        if (mostAttempted.ProblemId is not null)
+        {
+            highlights.Add(new ResultSummaryHighlightItem(
+                Localizer.Instance["present.summary.most_attempted"],
+                Localizer.Instance.Format(
+                    "present.summary.most_attempted_value",
+                    labelByProblemId.GetValueOrDefault(mostAttempted.ProblemId, mostAttempted.ProblemId),
+                    mostAttempted.Attempts)));
+        }
+
+        return new ResultSummaryViewModel(climbers, highlights);
+    }
+
+    private void InitializePresentationRows(ContestState contestState)
+    {
+        var firstSolvers = ContestProcessor.FindFirstSolvers(contestState.LeaderboardPreFreeze);
+        _orderedProblems.Clear();
+        _orderedProblems.AddRange(contestState.Problems.Values
+            .OrderBy(problem => problem.Ordinal)
+            .ThenBy(problem => problem.Label, StringComparer.Ordinal)
+            .Select(problem => new ProblemDisplayInfo(
+                problem.Id,
+                string.IsNullOrWhiteSpace(problem.Label) ? problem.ShortName : problem.Label,
+                problem.Rgb,
+                firstSolvers.GetValueOrDefault(problem.Id)))
+            .ToList());
+
+        ProblemHeaders.Clear();
+        foreach (var problem in _orderedProblems)
+        {
+            ProblemHeaders.Add(new ProblemHeaderViewModel(problem));
+        }
+
+        OnPropertyChanged(nameof(ProblemHeaderCount));
+
+        PreFreezeRows.Clear();
+
+        _pendingRevealsByTeamId.Clear();
+        var leaderboard = GetPresentedLeaderboard(contestState);
+        BuildFinalStandings(contestState, leaderboard);
+        for (var i = 0; i < leaderboard.Count; i++)
+        {
+            var team = CloneTeamStatus(leaderboard[i]);
+            _pendingRevealsByTeamId[team.TeamId] = new Queue<string>(OrderPendingProblems(team));
+
+            contestState.Teams.TryGetValue(team.TeamId, out var teamInfo);
+            contestState.Organizations.TryGetValue(team.TeamAffiliation, out var organization);
+            var countryCode = _loadedConfig.Presentation.ShowCountryFlags
+                ? Media.ResolveCountryCode(teamInfo, organization)
+                : null;
+            var rowVm = new PreFreezeScoreboardRowViewModel(
+                team,
+                i + 1,
+                _orderedProblems,
+                ActiveColors,
+                _loadedConfig.Presentation.CellNotation)
+            {
+                CountryCode = countryCode,
+                CountryFlagImage = LoadCountryFlag(teamInfo, team.TeamAffiliation),
+                OrganizationName = GetOrganizationSubline(organization),
+                TeamLabel = teamInfo?.Label,
+                ShowTeamLabel = _loadedConfig.Presentation.ShowTeamLabels,
+                MarqueeTeamName = _loadedConfig.Presentation.MarqueeTeamNames,
+                IsUnofficial = contestState.UnofficialTeamIds.Contains(team.TeamId),
+                AwardBadges = GetAwardsForTeam(team.TeamId)
+                    .Select(award => new AwardBadgeViewModel(award, FormatAwardCitation(award, team.TeamId)))
+                    .ToList()
+            };
+            PreFreezeRows.Add(rowVm);
+        }
+
+        ApplyLogoRange();
+    }
+
+    /// <summary>
+    ///     Trims the pre-freeze board to the teams finishing in the top <see cref="PresentationConfig.PresentTopTeams" />,
+    ///     so a televised ceremony can skip the tail. Awards and first solves are still taken from the full contest.
+    /// </summary>
+    private List<TeamStatus> GetPresentedLeaderboard(ContestState contestState)
+    {
+        if (_loadedConfig.Presentation.PresentTopTeams is not { } topTeams ||
+            topTeams >= contestState.LeaderboardPreFreeze.Count)
+        {
+            return contestState.LeaderboardPreFreeze;
+        }
+
+        var presentableTeamIds = contestState.LeaderboardPreFreeze
+            .Select(team => team.TeamId)
+            .ToHashSet(StringComparer.Ordinal);
+        var presentedTeamIds = contestState.LeaderboardFinalized
+            .Where(team => presentableTeamIds.Contains(team.TeamId))
+            .Take(topTeams)
+            .Select(team => team.TeamId)
+            .ToHashSet(StringComparer.Ordinal);
+        Trace.WriteLine(
+            $"[PresentationStageVM] PresentTopTeams: presenting {presentedTeamIds.Count} of {contestState.LeaderboardPreFreeze.Count} teams");
+        return contestState.LeaderboardPreFreeze
+            .Where(team => presentedTeamIds.Contains(team.TeamId))
+            .ToList();
+    }
+
+    /// <summary>
+    ///     The citation of <paramref name="award" /> with its placeholders filled in for <paramref name="teamId" />, using
+    ///     the team's final rank on the presented board.
+    /// </summary>
+    public string FormatAwardCitation(Award award, string teamId)
+    {
+        var citation = string.IsNullOrWhiteSpace(award.Citation) ? award.Id : award.Citation;
+        if (_contestState is null)
+        {
+            return citation;
+        }
+
+        _contestState.Teams.TryGetValue(teamId, out var team);
+        Organization? organization = null;
+        if (team?.OrganizationId is { } organizationId)
+        {
+            _contestState.Organizations.TryGetValue(organizationId, out organization);
+        }
+
+        var organizationName = new[] { organization?.FormalName, organization?.Name, organization?.Shortname }
+            .FirstOrDefault(name => !string.IsNullOrWhiteSpace(name));
+        if (_finalStandingsByTeamId.TryGetValue(teamId, out var standing))
+        {
+            return AwardTemplates.ExpandCitation(
+                citation,
+                standing.TeamName,
+                organizationName,
+                standing.Rank,
+                standing.Solved);
+        }
+
+        return AwardTemplates.ExpandCitation(citation, team?.Name ?? teamId, organizationName, null, null);
+    }
+
+    /// <summary>
+    ///     Final rank and result of every presented team, taken from <see cref="StandingsExport.BuildRows" /> so a
+    ///     citation's <c>{rank}</c> matches the certificates and exports: unofficial teams get no rank and are not counted.
+    /// </summary>
+    private void BuildFinalStandings(ContestState contestState, IReadOnlyList<TeamStatus> presented)
+    {
+        _finalStandingsByTeamId.Clear();
+        var presentedIds = presented.Select(team => team.TeamId).ToHashSet(StringComparer.Ordinal);
+        foreach (var row in StandingsExport.BuildRows(contestState).Where(row => presentedIds.Contains(row.TeamId)))
+        {
+            _finalStandingsByTeamId[row.TeamId] = row;
+        }
+    }
+
+    private string? GetOrganizationSubline(Organization? organization)
+    {
+        if (organization is null)
+        {
+            return null;
+        }
+
+        // Fall back through the other name fields so an org with only one populated still shows something.
+        string[] candidates = _loadedConfig.Presentation.OrganizationSubline switch
+        {
+            OrganizationSubline.FormalName => [organization.FormalName, organization.Name, organization.Shortname],
+            OrganizationSubline.Shortname => [organization.Shortname, organization.Name, organization.FormalName],
+            OrganizationSubline.Name => [organization.Name, organization.FormalName, organization.Shortname],
+            _ => []
+        };
+
+        return candidates.FirstOrDefault(name => !string.IsNullOrWhiteSpace(name));
+    }
+
+    /// <summary>
+    ///     Silently runs the resolver over the rows below <see cref="PresentationConfig.StartRank" />, one frozen cell at a
+    ///     time with a resort after every solve, so the ceremony opens at that rank in the state it would have reached.
+    /// </summary>
+    private int FastForwardToStartRank(int focusIndex)
+    {
+        if (_loadedConfig.Presentation.StartRank is not { } startRank || focusIndex < startRank)
+        {
+            return focusIndex;
+        }
+
+        var startIndex = startRank - 1;
+        var rows = PreFreezeRows.ToList();
+        var revealed = 0;
+        while (focusIndex > startIndex)
+        {
+            var row = rows[focusIndex];
+            if (!_pendingRevealsByTeamId.TryGetValue(row.TeamId, out var pending) || pending.Count == 0)
+            {
+                focusIndex -= 1;
+                continue;
+            }
+
+            revealed += 1;
+            if (row.TeamStatus.ProblemStats.TryGetValue(pending.Dequeue(), out var stat) &&
+                ApplyFrozenResult(row.TeamStatus, stat))
+            {
+                rows = rows.OrderBy(item => item.TeamStatus).ToList();
+            }
+        }
+
+        foreach (var row in rows)
+        {
+            row.RefreshFromSource();
+        }
+
+        ApplyRowOrder(rows.Select(row => row.TeamId).ToList());
+        RefreshRanks();
+        RefreshProblemHeaders();
+        Trace.WriteLine($"[PresentationStageVM] StartRank: {startRank}, revealed {revealed} cells below it");
+        return focusIndex;
+    }
+
+    private int FindInitialFocusedRowIndex()
+    {
+        for (var row = PreFreezeRows.Count - 1; row >= 0; row--)
+        {
+            var teamId = PreFreezeRows[row].TeamId;
+            if (HasPendingReveal(teamId))
+            {
+                return row;
+            }
+        }
+
+        return -1;
+    }
+
+    /// <summary>
+    ///     The team's frozen problems in the order <see cref="PresentationConfig.RevealOrder" /> reveals them, with
+    ///     scoreboard column order breaking ties.
+    /// </summary>
+    private IEnumerable<string> OrderPendingProblems(TeamStatus team)
+    {
+        var pending = team.ProblemStats
+            .Where(kv => kv.Value.AttemptedDuringFreeze)
+            .OrderBy(kv => GetProblemColumn(kv.Key))
+            .ThenBy(kv => kv.Key, StringComparer.Ordinal);
+
+        return _loadedConfig.Presentation.RevealOrder switch
+        {
+            RevealOrder.LeastImpact => pending
+                .OrderBy(kv => kv.Value.Solved)
+                .ThenByDescending(kv => kv.Value.Solved ? kv.Value.Penalty : 0)
+                .Select(kv => kv.Key),
+            RevealOrder.SubmissionOrder => pending
+                .OrderBy(kv => kv.Value.LastSubmissionTime)
+                .Select(kv => kv.Key),
+            _ => pending.Select(kv => kv.Key)
+        };
+    }
+
+    private int GetProblemColumn(string problemId)
+    {
+        for (var i = 0; i < _orderedProblems.Count; i++)
+        {
+            if (string.Equals(_orderedProblems[i].Id, problemId, StringComparison.Ordinal))
+            {
+                return i;
+            }
+        }
+
+        return int.MaxValue;
+    }
+
+    private RevealOutcome Reveal(string? problemId = null)
+    {
+        if (FocusedRowIndex < 0 || FocusedRowIndex >= PreFreezeRows.Count)
+        {
+            return RevealOutcome.None;
+        }
+
+        var teamRow = PreFreezeRows[FocusedRowIndex];
+        var team = teamRow.TeamStatus;
+        if (!_pendingRevealsByTeamId.TryGetValue(team.TeamId, out var pending) || pending.Count == 0)
+        {
+            return RevealOutcome.None;
+        }
+
+        if (problemId is null)
+        {
+            problemId = pending.Dequeue();
+        }
+        else
+        {
+            if (!pending.Contains(problemId, StringComparer.Ordinal))
+            {
+                return RevealOutcome.None;
+            }
+
+            _pendingRevealsByTeamId[team.TeamId] = new Queue<string>(
+                pending.Where(id => !string.Equals(id, problemId, StringComparison.Ordinal)));
+        }
+
+        if (!team.ProblemStats.TryGetValue(problemId, out var stat))
+        {
+            return RevealOutcome.None;
+        }
+
+        var cell = teamRow.GetProblemCell(problemId);
+        var previousBackground = cell?.Background;
+        var solved = ApplyFrozenResult(team, stat);
+
+        teamRow.RefreshFromSource();
+        RefreshProblemHeaders();
+        RequestCellFlipAnimation(cell, previousBackground);
+        return new RevealOutcome(true, solved, solved, solved ? team.TeamId : null);
+    }
+
+    /// <summary>
+    ///     Unfreezes one problem and adds it to the team's score if it was solved. Returns whether it was solved.
+    /// </summary>
+    private static bool ApplyFrozenResult(TeamStatus team, ProblemStat stat)
+    {
+        stat.AttemptedDuringFreeze = false;
+        if (!stat.Solved)
+        {
+            return false;
+        }
+
+        team.TotalPoints += 1;
+        team.TotalPenalty += stat.Penalty;
+
+        if (stat.FirstAcTime.HasValue && (!team.LastAcTime.HasValue || stat.FirstAcTime > team.LastAcTime))
+        {
+            team.LastAcTime = stat.FirstAcTime;
+        }
+
+        return true;
+    }
+
+    private void RequestCellFlipAnimation(ProblemStatusCellViewModel? cell, string? previousBackground)
+    {
+        var durationSeconds = _loadedConfig.Presentation.CellFlipSeconds / AnimationSpeed;
+        if (cell is null || previousBackground is null || durationSeconds <= 0)
+        {
+            return;
+        }
+
+        _cellFlipAnimationRequestCounter += 1;
+        CellFlipAnimationRequest = new CellFlipAnimationRequest(
+            cell,
+            previousBackground,
+            durationSeconds,
+            _cellFlipAnimationRequestCounter);
+    }
+
+    private bool MoveUp()
+    {
+        if (FocusedRowIndex <= 0 || FocusedRowIndex >= PreFreezeRows.Count)
+        {
+            return false;
         }
 
         FocusedRowIndex -= 1;
@@ -379,113 +2103,392 @@ public sealed class PresentationStageViewModel : ViewModelBase
         return true;
     }
 
-    private RevealOutcome RunReveal()
+    private RevealOutcome RunReveal(string? problemId = null)
+    {
+        if (!CanReveal())
+        {
+            return RevealOutcome.None;
+        }
+
+        var outcome = Reveal(problemId);
+        if (!outcome.Applied)
+        {
+            return RevealOutcome.None;
+        }
+
+        RevealCommand.NotifyCanExecuteChanged();
+        MoveUpCommand.NotifyCanExecuteChanged();
+        RefreshSessionStatus();
+        return outcome;
+    }
+
+    private bool RunMoveUp()
+    {
+        if (!CanMoveUp())
+        {
+            return false;
+        }
+
+        if (!MoveUp())
+        {
+            return false;
+        }
+
+        RevealCommand.NotifyCanExecuteChanged();
+        MoveUpCommand.NotifyCanExecuteChanged();
+        RefreshSessionStatus();
+        return true;
+    }
+
+    private bool CanReveal()
+    {
+        if (FocusedRowIndex < 0 || FocusedRowIndex >= PreFreezeRows.Count)
+        {
+            return false;
+        }
+
+        return HasPendingReveal(PreFreezeRows[FocusedRowIndex].TeamId);
+    }
+
+    private bool CanMoveUp()
+    {
+        return FocusedRowIndex > 0 && FocusedRowIndex < PreFreezeRows.Count && IsInitialized && IsStarted;
+    }
+
+    private bool HasPendingReveal(string teamId)
+    {
+        return _pendingRevealsByTeamId.TryGetValue(teamId, out var queue) && queue.Count > 0;
+    }
+
+    internal IReadOnlyCollection<string> GetPendingProblemIds(string teamId)
+    {
+        return _pendingRevealsByTeamId.TryGetValue(teamId, out var queue) ? queue : [];
+    }
+
+    internal IEnumerable<Award> GetAwardsForTeam(string teamId)
+    {
+        if (string.IsNullOrWhiteSpace(teamId) || _contestState is null)
+        {
+            return [];
+        }
+
+        return _contestState.Awards.Values
+            .Where(award => award.TeamIds.Contains(teamId, StringComparer.Ordinal))
+            .OrderBy(award => award.Order)
+            .ThenBy(award => award.Id, StringComparer.Ordinal);
+    }
+
+    private bool HasAwards(string teamId)
+    {
+        if (string.IsNullOrWhiteSpace(teamId) || _contestState is null)
+        {
+            return false;
+        }
+
+        foreach (var award in _contestState.Awards.Values)
+        {
+            if (award.TeamIds.Contains(teamId, StringComparer.Ordinal))
+            {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    private void ShowAwardOverlay(string teamId)
+    {
+        if (string.IsNullOrWhiteSpace(teamId) || _contestState is null)
+        {
+            HideAwardOverlay();
+            return;
+        }
+
+        var row = PreFreezeRows.FirstOrDefault(r => string.Equals(r.TeamId, teamId, StringComparison.Ordinal));
+        var teamName = row?.TeamName;
+        var teamAffiliation = row?.TeamStatus.TeamAffiliation;
+        _contestState.Teams.TryGetValue(teamId, out var team);
+        if (string.IsNullOrWhiteSpace(teamName) && team is not null)
+        {
+            teamName = _loadedConfig.TeamNames.GetValueOrDefault(teamId) ??
+                       (string.IsNullOrWhiteSpace(team.DisplayName) ? team.Name : team.DisplayName);
+        }
+
+        _awardOverlayTeamId = teamId;
+        AwardTeamName = teamName ?? teamId;
+        AwardText = BuildAwardText(teamId);
+        // Awards are in presentation order, so the first one that sets a style decides it for the merged overlay.
+        var awards = GetAwardsForTeam(teamId).ToList();
+        AwardTitle = awards.Select(award => award.Title).FirstOrDefault(title => !string.IsNullOrWhiteSpace(title)) ??
+                     string.Empty;
+        AwardBannerColor = awards
+            .Select(award => PresentationColorsConfig.TryNormalizeHexColor(award.BannerColor ?? string.Empty, out var color)
+                ? color
+                : null)
+            .FirstOrDefault(color => color is not null) ?? DefaultAwardBannerColor;
+        AwardProblems = GetAwardsForTeam(teamId)
+            .Select(AwardTemplates.GetFirstToSolveProblemId)
+            .Select(problemId => _orderedProblems.FirstOrDefault(problem =>
+                string.Equals(problem.Id, problemId, StringComparison.Ordinal)))
+            .OfType<ProblemDisplayInfo>()
+            .Distinct()
+            .ToList();
+        StartAwardSlideshow(teamId, awards.Select(award => award.MediaPath).FirstOrDefault(path => !string.IsNullOrWhiteSpace(path)));
+        AwardAffiliationLogoImage = LoadLogoImage(BuildAffiliationLogoPath(teamAffiliation), AwardAffiliationLogoSize);
+        AwardCountryFlagImage = row is not null
+            ? row.CountryFlagImage
+            : LoadCountryFlag(team, team?.OrganizationId ?? team?.Affiliation);
+        IsAwardOverlayVisible = true;
+        Trace.WriteLine(
+            $"[PresentationStageVM] AwardOverlayShow: teamId={teamId}, teamName={AwardTeamName}, hasPhoto={AwardBackgroundImage is not null}, hasAffiliationLogo={AwardAffiliationLogoImage is not null}");
+    }
+
+    private void FinishPresentation()
+    {
+        if (!_loadedConfig.Presentation.ChampionCelebration)
+        {
+            Trace.WriteLine("[PresentationStageVM] Finished: celebration disabled.");
+            State = Summary is not null ? PresentationRowState.Summary : PresentationRowState.Finished;
+            return;
+        }
+
+        LoadChampionDetails();
+        State = PresentationRowState.ChampionCelebration;
+        Trace.WriteLine($"[PresentationStageVM] ChampionCelebration: team={ChampionTeamName}");
+    }
+
+    private void LoadChampionDetails()
+    {
+        if (PreFreezeRows.Count == 0)
+        {
+            ChampionTeamName = string.Empty;
+            ChampionSummaryText = string.Empty;
+            ChampionLogoImage = null;
+            return;
+        }
+
+        var champion = PreFreezeRows[0];
+        ChampionTeamName = champion.TeamName;
+        ChampionSummaryText = Localizer.Instance.Format("present.champion_summary", champion.TotalPoints, champion.TotalPenalty);
+        ChampionLogoImage = LoadLogoImage(
+            BuildAffiliationLogoPath(champion.TeamStatus.TeamAffiliation),
+            ChampionLogoSize);
+    }
+
+    private void StartAwardSlideshow(string teamId, string? awardMediaPath)
     {
-        if (!CanReveal())
+        _awardSlideshowTimer.Stop();
+        _awardPhotoPaths.Clear();
+        _awardPhotoPaths.AddRange(BuildAwardPhotoPaths(teamId, awardMediaPath));
+        _awardPhotoIndex = 0;
+        SetAwardBackgroundImage(null);
+        if (_awardPhotoPaths.Count > 0)
         {
-            return RevealOutcome.None;
+            ShowAwardPhoto(_awardPhotoPaths[0]);
+        }
+        if (_awardPhotoPaths.Count > 1)
+        {
+            _awardSlideshowTimer.Interval = TimeSpan.FromSeconds(_loadedConfig.Presentation.AwardPhotoSeconds);
+            _awardSlideshowTimer.Start();
+            PrefetchAwardPhotos([_awardPhotoPaths[1]]);
         }
+    }
 
-        var outcome = Reveal();
-        if (!outcome.Applied)
+    /// <summary>
+    ///     The photos an award overlay cycles through: the award's media, else the team's photos (see
+    ///     <see cref="TeamMedia" />), else the configured fallback.
+    /// </summary>
+    private List<string> BuildAwardPhotoPaths(string teamId, string? awardMediaPath)
+    {
+        var paths = BuildAwardMediaPaths(awardMediaPath).ToList();
+        if (paths.Count == 0)
         {
-            return RevealOutcome.None;
+            paths.AddRange(TeamMedia.ResolvePhotos(_dataPath, teamId, _loadedConfig.Presentation.TeamPhotoExtension));
         }
 
-        RevealCommand.NotifyCanExecuteChanged();
-        MoveUpCommand.NotifyCanExecuteChanged();
-        RefreshSessionStatus();
-        return outcome;
+        if (paths.Count == 0 && ResolveDataRelativePath(_loadedConfig.Presentation.TeamPhotoFallbackPath) is { } fallbackPath)
+        {
+            paths.Add(fallbackPath);
+        }
+
+        return paths;
     }
 
-    private void RunMoveUp()
+    /// <summary>
+    ///     Starts decoding the first award photo of the focused team while the audience is still watching its row, so
+    ///     the overlay opens without decoding a full-size photo on the UI thread.
+    /// </summary>
+    private void PrefetchFocusedTeamAwardPhoto()
     {
-        if (!CanMoveUp())
+        if (!IsStarted || FocusedRowIndex < 0 || FocusedRowIndex >= PreFreezeRows.Count)
         {
             return;
         }
 
-        if (!MoveUp())
+        var teamId = PreFreezeRows[FocusedRowIndex].TeamId;
+        var awards = GetAwardsForTeam(teamId).ToList();
+        if (awards.Count == 0)
         {
             return;
         }
 
-        RevealCommand.NotifyCanExecuteChanged();
-        MoveUpCommand.NotifyCanExecuteChanged();
-        RefreshSessionStatus();
+        var mediaPath = awards.Select(award => award.MediaPath).FirstOrDefault(path => !string.IsNullOrWhiteSpace(path));
+        PrefetchAwardPhotos(BuildAwardPhotoPaths(teamId, mediaPath).Take(1));
     }
 
-    private bool CanReveal()
+    /// <summary>
+    ///     Decodes <paramref name="paths" /> on the thread pool at the current award photo size. Prefetches of other
+    ///     photos are dropped, so at most the next overlay's photos are held.
+    /// </summary>
+    private void PrefetchAwardPhotos(IEnumerable<string> paths)
     {
-        if (FocusedRowIndex < 0 || FocusedRowIndex >= PreFreezeRows.Count)
+        var decodeWidth = CalculateAwardBackgroundDecodeWidth();
+        var ffmpegPath = _loadedConfig.Presentation.RecordingFfmpegPath;
+        var keys = new HashSet<string>(StringComparer.Ordinal);
+        foreach (var path in paths)
         {
-            return false;
+            var key = $"{decodeWidth}:{path}";
+            keys.Add(key);
+            if (!_awardPhotoPrefetches.ContainsKey(key))
+            {
+                _awardPhotoPrefetches[key] = DecodeAwardPhotoAsync(path, decodeWidth, ffmpegPath);
+            }
         }
 
-        return HasPendingReveal(PreFreezeRows[FocusedRowIndex].TeamId);
+        DiscardAwardPhotoPrefetches(key => !keys.Contains(key));
     }
 
-    private bool CanMoveUp()
+    private static Task<Bitmap?> DecodeAwardPhotoAsync(string path, int decodeWidth, string? ffmpegPath)
     {
-        return FocusedRowIndex > 0 && FocusedRowIndex < PreFreezeRows.Count && IsInitialized && IsStarted;
+        return Task.Run(() => DecodeBitmap(path, decodeWidth, ffmpegPath, DecodeFit.Width));
     }
 
-    private bool HasPendingReveal(string teamId)
+    /// <summary>
+    ///     Shows the prefetched photo when its decode has finished. Otherwise the current photo stays until the decode
+    ///     completes; plain images that were not prefetched are decoded on the spot, but files that go through ffmpeg
+    ///     (AVIF and video frames) are always decoded on the thread pool.
+    /// </summary>
+    private void ShowAwardPhoto(string path)
     {
-        return _pendingRevealsByTeamId.TryGetValue(teamId, out var queue) && queue.Count > 0;
+        _pendingAwardPhoto = null;
+        var decodeWidth = CalculateAwardBackgroundDecodeWidth();
+        if (_awardPhotoPrefetches.Remove($"{decodeWidth}:{path}", out var decode))
+        {
+            if (decode.IsCompletedSuccessfully)
+            {
+                SetAwardBackgroundImage(decode.Result);
+                return;
+            }
+        }
+        else if (!ImageFiles.NeedsFfmpeg(path))
+        {
+            SetAwardBackgroundImage(LoadAwardBackgroundImage(path));
+            return;
+        }
+        else
+        {
+            decode = DecodeAwardPhotoAsync(path, decodeWidth, _loadedConfig.Presentation.RecordingFfmpegPath);
+        }
+
+        _pendingAwardPhoto = decode;
+        _ = ShowAwardPhotoWhenDecodedAsync(decode);
     }
 
-    private bool HasAwards(string teamId)
+    private async Task ShowAwardPhotoWhenDecodedAsync(Task<Bitmap?> decode)
     {
-        if (string.IsNullOrWhiteSpace(teamId) || _contestState is null)
+        var bitmap = await decode;
+        // The overlay closed or moved on to another photo meanwhile.
+        if (_pendingAwardPhoto != decode)
         {
-            return false;
+            bitmap?.Dispose();
+            return;
         }
 
-        foreach (var award in _contestState.Awards.Values)
+        _pendingAwardPhoto = null;
+        SetAwardBackgroundImage(bitmap);
+    }
+
+    private void DiscardAwardPhotoPrefetches(Func<string, bool>? predicate = null)
+    {
+        foreach (var key in _awardPhotoPrefetches.Keys.Where(key => predicate?.Invoke(key) ?? true).ToList())
         {
-            if (award.TeamIds.Contains(teamId, StringComparer.Ordinal))
+            if (_awardPhotoPrefetches.Remove(key, out var prefetch))
             {
-                return true;
+                prefetch.ContinueWith(task => task.Result?.Dispose(), TaskContinuationOptions.OnlyOnRanToCompletion);
             }
         }
+    }
 
-        return false;
+    private void OnAwardSlideshowTick(object? sender, EventArgs e)
+    {
+        if (!IsAwardOverlayVisible || _awardPhotoPaths.Count <= 1)
+        {
+            _awardSlideshowTimer.Stop();
+            return;
+        }
+
+        _awardPhotoIndex = (_awardPhotoIndex + 1) % _awardPhotoPaths.Count;
+        ShowAwardPhoto(_awardPhotoPaths[_awardPhotoIndex]);
+        PrefetchAwardPhotos([_awardPhotoPaths[(_awardPhotoIndex + 1) % _awardPhotoPaths.Count]]);
     }
 
-    private void ShowAwardOverlay(string teamId)
+    private void SetAwardBackgroundImage(Bitmap? newImage)
     {
-        if (string.IsNullOrWhiteSpace(teamId) || _contestState is null)
+        if (ReferenceEquals(_awardBackgroundImage, newImage))
         {
-            HideAwardOverlay();
             return;
         }
 
-        var row = PreFreezeRows.FirstOrDefault(r => string.Equals(r.TeamId, teamId, StringComparison.Ordinal));
-        var teamName = row?.TeamName;
-        var teamAffiliation = row?.TeamStatus.TeamAffiliation;
-        if (string.IsNullOrWhiteSpace(teamName) &&
-            _contestState.Teams.TryGetValue(teamId, out var team))
+        var previous = _awardBackgroundImage;
+        _awardBackgroundImage = newImage;
+        OnPropertyChanged(nameof(AwardBackgroundImage));
+        previous?.Dispose();
+    }
+
+    /// <summary>
+    ///     Photos of an award's <see cref="Award.MediaPath" />: the images in a folder, or a single image file.
+    /// </summary>
+    private IEnumerable<string> BuildAwardMediaPaths(string? mediaPath)
+    {
+        if (string.IsNullOrWhiteSpace(mediaPath))
         {
-            teamName = string.IsNullOrWhiteSpace(team.DisplayName) ? team.Name : team.DisplayName;
+            return [];
         }
 
-        AwardTeamName = teamName ?? teamId;
-        AwardText = BuildAwardText(teamId);
-        SetAwardBackgroundImage(LoadAwardBackgroundImage(BuildTeamPhotoPath(teamId)));
-        AwardAffiliationLogoImage = LoadLogoImage(BuildAffiliationLogoPath(teamAffiliation), AwardAffiliationLogoDecodeWidth);
-        IsAwardOverlayVisible = true;
-        Trace.WriteLine(
-            $"[PresentationStageVM] AwardOverlayShow: teamId={teamId}, teamName={AwardTeamName}, hasPhoto={AwardBackgroundImage is not null}, hasAffiliationLogo={AwardAffiliationLogoImage is not null}");
+        var directory = Path.IsPathRooted(mediaPath) || string.IsNullOrWhiteSpace(_dataPath)
+            ? mediaPath
+            : Path.Combine(_dataPath, mediaPath);
+        if (Directory.Exists(directory))
+        {
+            return ImageFiles.ListDirectory(directory);
+        }
+
+        var file = ResolveDataRelativePath(mediaPath);
+        if (file is null || !ImageFiles.IsImage(file))
+        {
+            Trace.WriteLine($"[PresentationStageVM] AwardMediaSkipped: '{mediaPath}' is not an image or folder of images.");
+            return [];
+        }
+
+        return [file];
     }
 
     private void HideAwardOverlay()
     {
+        _awardSlideshowTimer.Stop();
+        _awardPhotoPaths.Clear();
+        DiscardAwardPhotoPrefetches();
+        _pendingAwardPhoto = null;
         IsAwardOverlayVisible = false;
+        _awardOverlayTeamId = null;
         SetAwardBackgroundImage(null);
         AwardAffiliationLogoImage = null;
+        AwardCountryFlagImage = null;
         AwardTeamName = string.Empty;
         AwardText = string.Empty;
+        AwardProblems = [];
+        AwardTitle = string.Empty;
+        AwardBannerColor = DefaultAwardBannerColor;
     }
 
     private string BuildAwardText(string teamId)
@@ -496,14 +2499,9 @@ public sealed class PresentationStageViewModel : ViewModelBase
         }
 
         var lines = new List<string>();
-        foreach (var award in _contestState.Awards.Values)
+        foreach (var award in GetAwardsForTeam(teamId))
         {
-            if (!award.TeamIds.Contains(teamId, StringComparer.Ordinal))
-            {
-                continue;
-            }
-
-            var label = string.IsNullOrWhiteSpace(award.Citation) ? award.Id : award.Citation;
+            var label = FormatAwardCitation(award, teamId);
             if (!string.IsNullOrWhiteSpace(label))
             {
                 lines.Add(label);
@@ -524,57 +2522,56 @@ public sealed class PresentationStageViewModel : ViewModelBase
         return builder.ToString().TrimEnd();
     }
 
-    private string? BuildTeamPhotoPath(string teamId)
+    /// <summary>
+    ///     Resolves a configured file path: absolute paths as-is, relative paths against the CDP folder first and the
+    ///     working directory second. Returns <c>null</c> when the file does not exist.
+    /// </summary>
+    private string? ResolveDataRelativePath(string? path)
     {
-        var teamPhotoExtension = _loadedConfig.Presentation.TeamPhotoExtension?.Trim().TrimStart('.');
-        if (!string.IsNullOrWhiteSpace(_dataPath) &&
-            !string.IsNullOrWhiteSpace(teamId) &&
-            !string.IsNullOrWhiteSpace(teamPhotoExtension))
-        {
-            var primaryPath = Path.Combine(_dataPath, "teams", $"{teamId}.{teamPhotoExtension}");
-            if (File.Exists(primaryPath))
-            {
-                return primaryPath;
-            }
-        }
-
-        var fallbackPath = _loadedConfig.Presentation.TeamPhotoFallbackPath;
-        if (string.IsNullOrWhiteSpace(fallbackPath))
+        if (string.IsNullOrWhiteSpace(path))
         {
             return null;
         }
 
-        if (Path.IsPathRooted(fallbackPath))
+        if (Path.IsPathRooted(path))
         {
-            return File.Exists(fallbackPath) ? fallbackPath : null;
+            return File.Exists(path) ? path : null;
         }
 
         if (string.IsNullOrWhiteSpace(_dataPath))
         {
-            return File.Exists(fallbackPath) ? fallbackPath : null;
+            return File.Exists(path) ? path : null;
         }
 
-        var combinedPath = Path.Combine(_dataPath, fallbackPath);
-        return File.Exists(combinedPath) ? combinedPath : (File.Exists(fallbackPath) ? fallbackPath : null);
+        var combinedPath = Path.Combine(_dataPath, path);
+        return File.Exists(combinedPath) ? combinedPath : (File.Exists(path) ? path : null);
     }
 
-    private string? BuildAffiliationLogoPath(string? teamAffiliation)
+    /// <summary>
+    ///     The team's flag (see <see cref="Media" />), or <c>null</c> when flags are off or none is found.
+    /// </summary>
+    private Bitmap? LoadCountryFlag(Team? team, string? organizationId)
     {
-        if (string.IsNullOrWhiteSpace(_dataPath) ||
-            string.IsNullOrWhiteSpace(teamAffiliation) ||
-            string.IsNullOrWhiteSpace(_loadedConfig.Presentation.LogoExtension))
+        if (!_loadedConfig.Presentation.ShowCountryFlags || _contestState is null)
         {
             return null;
         }
 
-        var extension = _loadedConfig.Presentation.LogoExtension.Trim().TrimStart('.');
-        if (string.IsNullOrWhiteSpace(extension))
-        {
-            return null;
-        }
+        var organization = string.IsNullOrWhiteSpace(organizationId)
+            ? null
+            : _contestState.Organizations.GetValueOrDefault(organizationId);
+        return Media.LoadCountryFlag(
+            Media.ResolveCountryCode(team, organization),
+            OrganizationLogos.ResolveCountryFlag(_dataPath, organizationId, organization),
+            _loadedConfig.Presentation.RecordingFfmpegPath);
+    }
 
-        var candidatePath = Path.Combine(_dataPath, "affiliations", $"{teamAffiliation}.{extension}");
-        return File.Exists(candidatePath) ? candidatePath : null;
+    private string? BuildAffiliationLogoPath(string? teamAffiliation)
+    {
+        var organization = string.IsNullOrWhiteSpace(teamAffiliation)
+            ? null
+            : _contestState?.Organizations.GetValueOrDefault(teamAffiliation);
+        return OrganizationLogos.Resolve(_dataPath, teamAffiliation, organization, _loadedConfig.Presentation);
     }
 
     private int CalculateAwardBackgroundDecodeWidth()
@@ -597,56 +2594,426 @@ public sealed class PresentationStageViewModel : ViewModelBase
             return null;
         }
 
-        return LoadBitmapDecodedToWidth(path, CalculateAwardBackgroundDecodeWidth());
+        return LoadBitmapDecodedToWidth(path, CalculateAwardBackgroundDecodeWidth());
+    }
+
+    private Bitmap? LoadLogoImage(string? path, double displaySize)
+    {
+        var ffmpegPath = _loadedConfig.Presentation.RecordingFfmpegPath;
+        return _logoCache.GetOrAdd(path, GetLogoDecodeSize(displaySize), pin: false,
+            (logoPath, size) => DecodeBitmap(logoPath, size, ffmpegPath, DecodeFit.Fill));
+    }
+
+    /// <summary>
+    ///     Pixel size for a logo drawn <paramref name="displaySize" /> stage units wide at the current zoom and display
+    ///     scaling, rounded up to <see cref="LogoDecodeStep" /> so small zoom steps reuse cached bitmaps.
+    /// </summary>
+    private int GetLogoDecodeSize(double displaySize)
+    {
+        return (int)Math.Ceiling(displaySize * StageScale * _renderScaling / LogoDecodeStep) * LogoDecodeStep;
+    }
+
+    /// <summary>
+    ///     Re-decodes the logos of the rows near the screen when zoom or display scaling changes their drawn size.
+    /// </summary>
+    private void RefreshScoreboardLogoSize()
+    {
+        var size = GetLogoDecodeSize(ScoreboardLogoSize);
+        if (size == _scoreboardLogoDecodeSize)
+        {
+            return;
+        }
+
+        var previousSize = _scoreboardLogoDecodeSize;
+        _scoreboardLogoDecodeSize = size;
+        _scoreboardLogoLoads.Clear();
+        foreach (var row in PreFreezeRows)
+        {
+            row.TeamLogoImage = null;
+        }
+
+        _logoCache.PinOnly(previousSize, new HashSet<string>());
+        ApplyLogoRange();
+    }
+
+    public int ImageCacheCount => _logoCache.Count;
+
+    public long ImageCacheBytes => _logoCache.ApproxBytes;
+
+    /// <summary>
+    ///     Frees every decoded logo, CDP country flag, prefetched award photo, and ffmpeg still. Scrolled-in rows decode
+    ///     their logos again on demand, and the flags are read again at once.
+    /// </summary>
+    public void ClearImageCache()
+    {
+        DiscardAwardPhotoPrefetches();
+        foreach (var row in PreFreezeRows)
+        {
+            row.TeamLogoImage = null;
+        }
+
+        _logoCache.Clear();
+        _scoreboardLogoLoads.Clear();
+        _scoreboardLogoPaths.Clear();
+        _logoCacheGeneration++;
+        ImageFiles.ForgetConverted();
+        ReloadCountryFlags();
+        RefreshSessionStatus();
+        Trace.WriteLine("[PresentationStageVM] ImageCacheCleared");
+    }
+
+    /// <summary>
+    ///     Drops decoded images and reads the files again, e.g. after a logo or photo was replaced in the CDP folder
+    ///     during the ceremony. With <paramref name="teamId" /> only that team's organization logo (for every team of
+    ///     the organization) and award photos are reloaded; otherwise the whole cache is cleared. Country flags from
+    ///     CDP files are read again either way. An award overlay or champion screen on display is shown again with the
+    ///     fresh files.
+    /// </summary>
+    public void ReloadImages(string? teamId = null)
+    {
+        AwardAffiliationLogoImage = null;
+        ChampionLogoImage = null;
+        ReloadCountryFlags();
+        if (teamId is null)
+        {
+            DiscardAwardPhotoPrefetches();
+            foreach (var row in PreFreezeRows)
+            {
+                row.TeamLogoImage = null;
+            }
+
+            _logoCache.Clear();
+            _scoreboardLogoLoads.Clear();
+            _scoreboardLogoPaths.Clear();
+            _logoCacheGeneration++;
+            ImageFiles.ForgetConverted();
+        }
+        else
+        {
+            var mediaPath = GetAwardsForTeam(teamId)
+                .Select(award => award.MediaPath)
+                .FirstOrDefault(path => !string.IsNullOrWhiteSpace(path));
+            var photoPaths = BuildAwardPhotoPaths(teamId, mediaPath);
+            DiscardAwardPhotoPrefetches(key => photoPaths.Any(path => key.EndsWith($":{path}", StringComparison.Ordinal)));
+            foreach (var path in photoPaths)
+            {
+                ImageFiles.ForgetConverted(path);
+            }
+
+            var organizationId = PreFreezeRows
+                .FirstOrDefault(row => string.Equals(row.TeamId, teamId, StringComparison.Ordinal))
+                ?.TeamStatus.TeamAffiliation;
+            if (organizationId is not null)
+            {
+                // A fallback logo is shared by several organizations, and all of their rows hold the bitmap evicted here.
+                var path = _scoreboardLogoPaths.GetValueOrDefault(organizationId);
+                HashSet<string> organizationIds = path is null
+                    ? [organizationId]
+                    : _scoreboardLogoPaths
+                        .Where(entry => string.Equals(entry.Value, path, StringComparison.Ordinal))
+                        .Select(entry => entry.Key)
+                        .Append(organizationId)
+                        .ToHashSet(StringComparer.Ordinal);
+                foreach (var row in PreFreezeRows.Where(row => organizationIds.Contains(row.TeamStatus.TeamAffiliation)))
+                {
+                    row.TeamLogoImage = null;
+                }
+
+                foreach (var id in organizationIds)
+                {
+                    _scoreboardLogoPaths.Remove(id);
+                    _scoreboardLogoLoads.Remove(id);
+                }
+
+                if (path is not null)
+                {
+                    _logoCache.Remove(path);
+                }
+            }
+        }
+
+        ApplyLogoRange();
+        if (IsAwardOverlayVisible && _awardOverlayTeamId is { } awardTeamId)
+        {
+            ShowAwardOverlay(awardTeamId);
+        }
+
+        if (State == PresentationRowState.ChampionCelebration)
+        {
+            LoadChampionDetails();
+        }
+
+        RefreshSessionStatus();
+        Trace.WriteLine($"[PresentationStageVM] ImagesReloaded: team={teamId ?? "(all)"}");
+    }
+
+    /// <summary>
+    ///     Takes the flags off the rows and the award overlay, then disposes the ones decoded from CDP files.
+    /// </summary>
+    private void ReleaseCountryFlags()
+    {
+        foreach (var row in PreFreezeRows)
+        {
+            row.CountryFlagImage = null;
+        }
+
+        AwardCountryFlagImage = null;
+        Media.ClearCountryFlagFiles();
+    }
+
+    /// <summary>
+    ///     Reads the flags of every row and of the award overlay on display again, e.g. after a <c>country_flag</c>
+    ///     file was replaced in the CDP folder.
+    /// </summary>
+    private void ReloadCountryFlags()
+    {
+        ReleaseCountryFlags();
+        foreach (var row in PreFreezeRows)
+        {
+            row.CountryFlagImage = LoadCountryFlag(
+                _contestState?.Teams.GetValueOrDefault(row.TeamId),
+                row.TeamStatus.TeamAffiliation);
+        }
+
+        if (IsAwardOverlayVisible && _awardOverlayTeamId is { } awardTeamId)
+        {
+            var team = _contestState?.Teams.GetValueOrDefault(awardTeamId);
+            AwardCountryFlagImage = PreFreezeRows
+                                        .FirstOrDefault(row => string.Equals(row.TeamId, awardTeamId, StringComparison.Ordinal))
+                                        ?.CountryFlagImage
+                                    ?? LoadCountryFlag(team, team?.OrganizationId ?? team?.Affiliation);
+        }
+    }
+
+    /// <summary>
+    ///     Rows within <see cref="LogoKeepViewports" /> screens of the visible rows keep their logos; the others drop
+    ///     them, so the cache may evict those bitmaps once it is over <c>image_cache_mb</c>.
+    /// </summary>
+    private void ApplyLogoRange()
+    {
+        var margin = ((long)_visibleRowLast - _visibleRowFirst + 1) * LogoKeepViewports;
+        var keepFirst = _visibleRowFirst - margin;
+        var keepLast = _visibleRowLast + margin;
+        var keptPaths = new HashSet<string>(StringComparer.OrdinalIgnoreCase);
+        _logoRows.Clear();
+        for (var i = 0; i < PreFreezeRows.Count; i++)
+        {
+            var row = PreFreezeRows[i];
+            if (i < keepFirst || i > keepLast)
+            {
+                row.TeamLogoImage = null;
+                continue;
+            }
+
+            _logoRows.Add(row);
+            var organizationId = row.TeamStatus.TeamAffiliation;
+            if (_scoreboardLogoPaths.GetValueOrDefault(organizationId) is { } path)
+            {
+                keptPaths.Add(path);
+            }
+
+            if (row.TeamLogoImage is null)
+            {
+                _ = LoadRowLogoAsync(row, organizationId);
+            }
+        }
+
+        // Rows that left the range no longer reference their bitmaps, so unpinning cannot dispose one on screen.
+        _logoCache.PinOnly(_scoreboardLogoDecodeSize, keptPaths);
+    }
+
+    /// <summary>
+    ///     Finds and decodes a scoreboard logo on the thread pool, so building the board or scrolling never waits on
+    ///     disk or image decoding; the row shows its empty logo circle until the bitmap arrives. Teams of one
+    ///     organization share the load.
+    /// </summary>
+    private async Task LoadRowLogoAsync(PreFreezeScoreboardRowViewModel row, string organizationId)
+    {
+        var isPathKnown = _scoreboardLogoPaths.TryGetValue(organizationId, out var knownPath);
+        if (isPathKnown && knownPath is null)
+        {
+            return;
+        }
+
+        if (knownPath is not null && _logoCache.TryGet(knownPath, _scoreboardLogoDecodeSize, pin: true, out var cached))
+        {
+            row.TeamLogoImage = cached;
+            return;
+        }
+
+        if (!_scoreboardLogoLoads.TryGetValue(organizationId, out var load))
+        {
+            load = LoadScoreboardLogoAsync(organizationId, knownPath);
+            _scoreboardLogoLoads[organizationId] = load;
+        }
+
+        var bitmap = await load;
+        if (_scoreboardLogoLoads.TryGetValue(organizationId, out var current) && current == load)
+        {
+            _scoreboardLogoLoads.Remove(organizationId);
+        }
+
+        if (_logoRows.Contains(row))
+        {
+            row.TeamLogoImage = bitmap;
+        }
+    }
+
+    private async Task<Bitmap?> LoadScoreboardLogoAsync(string organizationId, string? knownPath)
+    {
+        var generation = _logoCacheGeneration;
+        var decodeSize = _scoreboardLogoDecodeSize;
+        var dataPath = _dataPath;
+        var organization = _contestState?.Organizations.GetValueOrDefault(organizationId);
+        var presentation = _loadedConfig.Presentation;
+        var ffmpegPath = presentation.RecordingFfmpegPath;
+        var (path, bitmap) = await Task.Run(() =>
+        {
+            var logoPath = knownPath ?? OrganizationLogos.Resolve(dataPath, organizationId, organization, presentation);
+            return (logoPath, logoPath is null ? null : DecodeBitmap(logoPath, decodeSize, ffmpegPath, DecodeFit.Fill));
+        });
+
+        // A new contest cleared the cache, or the zoom changed the size, while this was loading.
+        if (generation != _logoCacheGeneration || decodeSize != _scoreboardLogoDecodeSize)
+        {
+            bitmap?.Dispose();
+            return null;
+        }
+
+        _scoreboardLogoPaths[organizationId] = path;
+        return path is null || bitmap is null ? null : _logoCache.Add(path, decodeSize, pin: true, bitmap);
+    }
+
+    private Bitmap? LoadBitmapDecodedToWidth(string path, int decodeWidth)
+    {
+        return DecodeBitmap(path, decodeWidth, _loadedConfig.Presentation.RecordingFfmpegPath, DecodeFit.Width);
+    }
+
+    /// <summary>
+    ///     Decodes straight to the drawn size instead of decoding at full resolution and letting the renderer scale.
+    ///     <see cref="DecodeFit.Fill" /> makes the shorter side <paramref name="size" /> for the round
+    ///     <c>UniformToFill</c> logos, so wide logos are decoded a second time by height. Logos get the high quality
+    ///     filter; they are small enough for it to be cheap.
+    /// </summary>
+    private static Bitmap? DecodeBitmap(string path, int size, string? ffmpegPath, DecodeFit fit)
+    {
+        if (string.IsNullOrWhiteSpace(path) || size <= 0)
+        {
+            return null;
+        }
+
+        try
+        {
+            using (var stream = ImageFiles.OpenRead(path, ffmpegPath))
+            {
+                if (fit != DecodeFit.Height)
+                {
+                    var bitmap = Bitmap.DecodeToWidth(stream, size, fit == DecodeFit.Width
+                        ? BitmapInterpolationMode.MediumQuality
+                        : BitmapInterpolationMode.HighQuality);
+                    if (fit == DecodeFit.Width || bitmap.PixelSize.Height >= size)
+                    {
+                        return bitmap;
+                    }
+
+                    bitmap.Dispose();
+                }
+            }
+
+            using var heightStream = ImageFiles.OpenRead(path, ffmpegPath);
+            return Bitmap.DecodeToHeight(heightStream, size, BitmapInterpolationMode.HighQuality);
+        }
+        catch (Exception ex)
+        {
+            Trace.WriteLine($"[PresentationStageVM] Failed to decode {path}: {ex.Message}");
+            return null;
+        }
+    }
+
+    private void RefreshProblemHeaders()
+    {
+        foreach (var header in ProblemHeaders)
+        {
+            var solvedCount = 0;
+            foreach (var row in PreFreezeRows)
+            {
+                if (row.TeamStatus.ProblemStats.TryGetValue(header.Id, out var stat) &&
+                    stat is { Solved: true, AttemptedDuringFreeze: false })
+                {
+                    solvedCount += 1;
+                }
+            }
+
+            header.SolvedCount = solvedCount;
+        }
     }
 
-    private Bitmap? LoadPinnedLogo(string? path, int decodeWidth)
+    private void RefreshRanks()
     {
-        return _logoCache.GetOrAdd(path, decodeWidth, pin: true, LoadBitmapDecodedToWidth);
-    }
+        for (var i = 0; i < PreFreezeRows.Count; i++)
+        {
+            PreFreezeRows[i].SetRank(i + 1);
+        }
 
-    private Bitmap? LoadLogoImage(string? path, int decodeWidth)
-    {
-        return _logoCache.GetOrAdd(path, decodeWidth, pin: false, LoadBitmapDecodedToWidth);
+        RefreshMedalTints();
     }
 
-    private void SetAwardBackgroundImage(Bitmap? newImage)
+    /// <summary>
+    ///     Tints rows by their current position using the medal award sizes, so the medal zone stays fixed while teams
+    ///     move in and out of it.
+    /// </summary>
+    private void RefreshMedalTints()
     {
-        if (ReferenceEquals(_awardBackgroundImage, newImage))
+        var colors = ActiveColors;
+        var enabled = _loadedConfig.Presentation.MedalTintRows;
+        var goldCount = enabled ? GetMedalAwardSize("gold") : 0;
+        var silverCount = enabled ? GetMedalAwardSize("silver") : 0;
+        var bronzeCount = enabled ? GetMedalAwardSize("bronze") : 0;
+
+        // Unofficial teams do not take medal places, so the zone extends past them.
+        var officialIndex = 0;
+        foreach (var row in PreFreezeRows)
         {
-            return;
-        }
+            var tint = NoMedalTint;
+            if (row.IsUnofficial)
+            {
+                row.MedalTint = tint;
+                continue;
+            }
 
-        var previous = _awardBackgroundImage;
-        _awardBackgroundImage = newImage;
-        OnPropertyChanged(nameof(AwardBackgroundImage));
-        previous?.Dispose();
+            if (officialIndex < goldCount)
+            {
+                tint = colors.GoldTint;
+            }
+            else if (officialIndex < goldCount + silverCount)
+            {
+                tint = colors.SilverTint;
+            }
+            else if (officialIndex < goldCount + silverCount + bronzeCount)
+            {
+                tint = colors.BronzeTint;
+            }
+
+            row.MedalTint = tint;
+            officialIndex += 1;
+        }
     }
 
-    private static Bitmap? LoadBitmapDecodedToWidth(string path, int decodeWidth)
+    private int GetMedalAwardSize(string medal)
     {
-        if (string.IsNullOrWhiteSpace(path) || decodeWidth <= 0)
+        if (_contestState is null)
         {
-            return null;
+            return 0;
         }
 
-        try
-        {
-            using var stream = File.OpenRead(path);
-            return Bitmap.DecodeToWidth(stream, decodeWidth, BitmapInterpolationMode.MediumQuality);
-        }
-        catch
+        // Pyrite writes medal-<color>; CLICS feeds use <color>-medal.
+        if (_contestState.Awards.TryGetValue($"medal-{medal}", out var award) ||
+            _contestState.Awards.TryGetValue($"{medal}-medal", out award))
         {
-            return null;
+            return award.TeamIds.Count;
         }
-    }
 
-    private void RefreshRanks()
-    {
-        for (var i = 0; i < PreFreezeRows.Count; i++)
-        {
-            PreFreezeRows[i].SetRank(i + 1);
-        }
+        return 0;
     }
 
     private void ResortScoreboard(string? solvedTeamId)
@@ -674,6 +3041,8 @@ public sealed class PresentationStageViewModel : ViewModelBase
             }
         }
 
+        ApplyLogoRange();
+
         // Keep focus at the same index so presentation continues with the next team at this rank.
         if (PreFreezeRows.Count == 0)
         {
@@ -681,21 +3050,7 @@ public sealed class PresentationStageViewModel : ViewModelBase
         }
         else
         {
-            var clampedIndex = Math.Clamp(preservedIndex, 0, PreFreezeRows.Count - 1);
-            if (clampedIndex == FocusedRowIndex)
-            {
-                // After collection moves, ListBox can keep selected *item* while index value is unchanged.
-                // Pulse SelectedIndex through -1 to force container :selected state refresh.
-                _focusedRowIndex = -1;
-                OnPropertyChanged(nameof(FocusedRowIndex));
-                _focusedRowIndex = clampedIndex;
-                OnPropertyChanged(nameof(FocusedRowIndex));
-                RefreshSessionStatus();
-            }
-            else
-            {
-                FocusedRowIndex = clampedIndex;
-            }
+            SetFocusedRowIndexWithRefresh(Math.Clamp(preservedIndex, 0, PreFreezeRows.Count - 1));
         }
 
         if (!string.IsNullOrWhiteSpace(solvedTeamId) &&
@@ -719,15 +3074,51 @@ public sealed class PresentationStageViewModel : ViewModelBase
                     oldIndex,
                     newIndex,
                     _moveUpAnimationRequestCounter);
+                _soundPlayer.Play(SoundEffect.RankJump);
             }
         }
 
         RefreshRanks();
+        RequestRankDeltaAnimation(oldIndexByTeamId);
         RevealCommand.NotifyCanExecuteChanged();
         MoveUpCommand.NotifyCanExecuteChanged();
         RefreshSessionStatus();
     }
 
+    /// <summary>
+    ///     Marks every row that changed position in the last resort with its rank delta, for the view to fade out.
+    /// </summary>
+    private void RequestRankDeltaAnimation(IReadOnlyDictionary<string, int> oldIndexByTeamId)
+    {
+        var durationSeconds = _loadedConfig.Presentation.RankDeltaSeconds / AnimationSpeed;
+        if (durationSeconds <= 0)
+        {
+            return;
+        }
+
+        var movedRows = new List<PreFreezeScoreboardRowViewModel>();
+        for (var i = 0; i < PreFreezeRows.Count; i++)
+        {
+            var row = PreFreezeRows[i];
+            if (oldIndexByTeamId.TryGetValue(row.TeamId, out var oldIndex) && oldIndex != i)
+            {
+                row.SetRankDelta(oldIndex - i);
+                movedRows.Add(row);
+            }
+        }
+
+        if (movedRows.Count == 0)
+        {
+            return;
+        }
+
+        _rankDeltaAnimationRequestCounter += 1;
+        RankDeltaAnimationRequest = new RankDeltaAnimationRequest(
+            movedRows,
+            durationSeconds,
+            _rankDeltaAnimationRequestCounter);
+    }
+
     private static TeamStatus CloneTeamStatus(TeamStatus source)
     {
         var clone = new TeamStatus(source.TeamId, source.TeamName, source.TeamAffiliation, source.Sortorder)
@@ -745,6 +3136,18 @@ public sealed class PresentationStageViewModel : ViewModelBase
         return clone;
     }
 
+    private static void CopyTeamStatus(TeamStatus source, TeamStatus target)
+    {
+        target.TotalPoints = source.TotalPoints;
+        target.TotalPenalty = source.TotalPenalty;
+        target.LastAcTime = source.LastAcTime;
+        target.ProblemStats.Clear();
+        foreach (var (problemId, stat) in source.ProblemStats)
+        {
+            target.ProblemStats[problemId] = CloneProblemStat(stat);
+        }
+    }
+
     private static ProblemStat CloneProblemStat(ProblemStat source)
     {
         return new ProblemStat
@@ -753,15 +3156,23 @@ public sealed class PresentationStageViewModel : ViewModelBase
             AttemptedDuringFreeze = source.AttemptedDuringFreeze,
             Penalty = source.Penalty,
             SubmissionsBeforeSolved = source.SubmissionsBeforeSolved,
+            SubmissionsDuringFreeze = source.SubmissionsDuringFreeze,
             FirstAcTime = source.FirstAcTime,
             LastSubmissionTime = source.LastSubmissionTime
         };
     }
 
+    private enum DecodeFit
+    {
+        Width,
+        Height,
+        Fill
+    }
+
     private sealed class BoundedBitmapCache
     {
         private readonly int _maxItems;
-        private readonly long _maxApproxBytes;
+        private long _maxApproxBytes;
         private readonly Dictionary<string, LinkedListNode<CacheEntry>> _entries = new(StringComparer.OrdinalIgnoreCase);
         private readonly LinkedList<CacheEntry> _lru = new();
         private long _currentApproxBytes;
@@ -772,6 +3183,19 @@ public sealed class PresentationStageViewModel : ViewModelBase
             _maxApproxBytes = Math.Max(1, maxApproxBytes);
         }
 
+        internal long MaxApproxBytes
+        {
+            get => _maxApproxBytes;
+            set
+            {
+                _maxApproxBytes = Math.Max(1, value);
+                Trim();
+            }
+        }
+
+        internal int Count => _entries.Count;
+        internal long ApproxBytes => _currentApproxBytes;
+
         internal Bitmap? GetOrAdd(string? path, int decodeWidth, bool pin, Func<string, int, Bitmap?> loader)
         {
             if (string.IsNullOrWhiteSpace(path) || decodeWidth <= 0)
@@ -779,27 +3203,53 @@ public sealed class PresentationStageViewModel : ViewModelBase
                 return null;
             }
 
-            var key = BuildCacheKey(path, decodeWidth);
-            if (_entries.TryGetValue(key, out var existingNode))
+            if (TryGet(path, decodeWidth, pin, out var cached))
             {
-                if (pin && !existingNode.Value.Pinned)
-                {
-                    existingNode.Value = existingNode.Value with { Pinned = true };
-                }
+                return cached;
+            }
+
+            var bitmap = loader(path, decodeWidth);
+            return bitmap is null ? null : Add(path, decodeWidth, pin, bitmap);
+        }
 
-                _lru.Remove(existingNode);
-                _lru.AddFirst(existingNode);
-                return existingNode.Value.Bitmap;
+        internal bool TryGet(string path, int decodeWidth, bool pin, out Bitmap? bitmap)
+        {
+            if (!_entries.TryGetValue(BuildCacheKey(path, decodeWidth), out var existingNode))
+            {
+                bitmap = null;
+                return false;
             }
 
-            var bitmap = loader(path!, decodeWidth);
-            if (bitmap is null)
+            if (pin && !existingNode.Value.Pinned)
             {
-                return null;
+                existingNode.Value = existingNode.Value with { Pinned = true };
+            }
+
+            _lru.Remove(existingNode);
+            _lru.AddFirst(existingNode);
+            bitmap = existingNode.Value.Bitmap;
+            return true;
+        }
+
+        /// <summary>
+        ///     Stores a bitmap decoded outside <see cref="GetOrAdd" />. If the key was filled meanwhile, the new bitmap is
+        ///     disposed and the cached one returned.
+        /// </summary>
+        internal Bitmap Add(string path, int decodeWidth, bool pin, Bitmap bitmap)
+        {
+            if (TryGet(path, decodeWidth, pin, out var cached) && cached is not null)
+            {
+                if (!ReferenceEquals(cached, bitmap))
+                {
+                    bitmap.Dispose();
+                }
+
+                return cached;
             }
 
+            var key = BuildCacheKey(path, decodeWidth);
             var approxBytes = EstimateApproxBytes(bitmap);
-            var entry = new CacheEntry(key, bitmap, approxBytes, pin);
+            var entry = new CacheEntry(key, path, decodeWidth, bitmap, approxBytes, pin);
             var node = _lru.AddFirst(entry);
             _entries[key] = node;
             _currentApproxBytes += approxBytes;
@@ -807,6 +3257,41 @@ public sealed class PresentationStageViewModel : ViewModelBase
             return bitmap;
         }
 
+        /// <summary>
+        ///     Pins the entries of <paramref name="decodeWidth" /> whose path is in <paramref name="paths" /> and unpins
+        ///     the rest of that width, then evicts down to the limits.
+        /// </summary>
+        internal void PinOnly(int decodeWidth, IReadOnlySet<string> paths)
+        {
+            for (var node = _lru.First; node is not null; node = node.Next)
+            {
+                if (node.Value.DecodeWidth == decodeWidth)
+                {
+                    node.Value = node.Value with { Pinned = paths.Contains(node.Value.Path) };
+                }
+            }
+
+            Trim();
+        }
+
+        /// <summary>
+        ///     Evicts every size of <paramref name="path" />, pinned or not.
+        /// </summary>
+        internal void Remove(string path)
+        {
+            var node = _lru.First;
+            while (node is not null)
+            {
+                var next = node.Next;
+                if (string.Equals(node.Value.Path, path, StringComparison.OrdinalIgnoreCase))
+                {
+                    Evict(node);
+                }
+
+                node = next;
+            }
+        }
+
         internal void Clear()
         {
             foreach (var entry in _lru)
@@ -864,8 +3349,23 @@ public sealed class PresentationStageViewModel : ViewModelBase
             return $"{decodeWidth}:{path}";
         }
 
-        private readonly record struct CacheEntry(string Key, Bitmap Bitmap, long ApproxBytes, bool Pinned);
+        private readonly record struct CacheEntry(
+            string Key,
+            string Path,
+            int DecodeWidth,
+            Bitmap Bitmap,
+            long ApproxBytes,
+            bool Pinned);
     }
+
+    private sealed record PresentationSnapshot(
+        PresentationRowState State,
+        int FocusedRowIndex,
+        string? PendingResortSolvedTeamId,
+        string? AwardOverlayTeamId,
+        IReadOnlyList<string> RowOrder,
+        IReadOnlyDictionary<string, TeamStatus> Teams,
+        IReadOnlyDictionary<string, string[]> PendingReveals);
 }
 
 public readonly record struct RevealOutcome(bool Applied, bool Solved, bool NeedResort, string? SolvedTeamId)
@@ -873,33 +3373,134 @@ public readonly record struct RevealOutcome(bool Applied, bool Solved, bool Need
     public static RevealOutcome None => new(false, false, false, null);
 }
 
+public sealed record HotkeyHelpItem(string Keys, string Description);
+
+public sealed record IntroStatisticItem(string Label, int Value);
+
+public sealed class ResultSummaryClimberItem
+{
+    public ResultSummaryClimberItem(string teamName, int fromRank, int toRank)
+    {
+        TeamName = teamName;
+        RankChangeText = $"#{fromRank} \u2192 #{toRank}";
+        ClimbText = $"\u25B2{fromRank - toRank}";
+    }
+
+    public string TeamName { get; }
+    public string RankChangeText { get; }
+    public string ClimbText { get; }
+}
+
+public sealed record ResultSummaryHighlightItem(string Label, string Value);
+
+public sealed class ResultSummaryViewModel
+{
+    public ResultSummaryViewModel(
+        IReadOnlyList<ResultSummaryClimberItem> climbers,
+        IReadOnlyList<ResultSummaryHighlightItem> highlights)
+    {
+        Climbers = climbers;
+        Highlights = highlights;
+    }
+
+    public IReadOnlyList<ResultSummaryClimberItem> Climbers { get; }
+    public IReadOnlyList<ResultSummaryHighlightItem> Highlights { get; }
+    public bool HasClimbers => Climbers.Count > 0;
+    public bool HasNoClimbers => Climbers.Count == 0;
+}
+
+public sealed class IntroProblemItem
+{
+    public IntroProblemItem(ProblemDisplayInfo problem, string name)
+    {
+        Label = problem.Label;
+        Name = name;
+        BalloonColor = problem.BalloonColor;
+        HasBalloonColor = problem.HasBalloonColor;
+    }
+
+    public string Label { get; }
+    public string Name { get; }
+    public string BalloonColor { get; }
+    public bool HasBalloonColor { get; }
+}
+
+public sealed class IntroSlideViewModel
+{
+    private IntroSlideViewModel()
+    {
+    }
+
+    public Bitmap? Image { get; private init; }
+    public string Title { get; private init; } = string.Empty;
+    public IReadOnlyList<IntroStatisticItem> Statistics { get; private init; } = [];
+    public IReadOnlyList<IntroProblemItem> Problems { get; private init; } = [];
+    public bool IsImage => Image is not null;
+    public bool IsStatistics => Statistics.Count > 0;
+    public bool IsProblems => Problems.Count > 0;
+
+    public static IntroSlideViewModel ForImage(Bitmap image) => new() { Image = image };
+
+    public static IntroSlideViewModel ForStatistics(string title, IReadOnlyList<IntroStatisticItem> statistics) =>
+        new() { Title = title, Statistics = statistics };
+
+    public static IntroSlideViewModel ForProblems(string title, IReadOnlyList<IntroProblemItem> problems) =>
+        new() { Title = title, Problems = problems };
+}
+
 public sealed record MoveUpAnimationRequest(string TeamId, int FromIndex, int ToIndex, long RequestId);
 
+public sealed record CellFlipAnimationRequest(
+    ProblemStatusCellViewModel Cell,
+    string PreviousBackground,
+    double DurationSeconds,
+    long RequestId);
+
+public sealed record RankDeltaAnimationRequest(
+    IReadOnlyList<PreFreezeScoreboardRowViewModel> Rows,
+    double DurationSeconds,
+    long RequestId);
+
 public enum PresentationRowState
 {
     RowInProgress,
     RowInProgressAwaitResort,
     RowCompleteAwardShowing,
-    RowCompleteReadyToAdvance
+    RowCompleteReadyToAdvance,
+    ChampionCelebration,
+    Summary,
+    Finished
 }
 
 public sealed class PreFreezeScoreboardRowViewModel : ViewModelBase
 {
+    private PresentationColorsConfig _colors;
+    private readonly ProblemCellNotation _cellNotation;
     private readonly IReadOnlyList<ProblemDisplayInfo> _orderedProblems;
     private readonly TeamStatus _source;
+    private string _medalTint = "#00000000";
     private int _rank;
+    private int _rankDelta;
+    private double _rankDeltaOpacity;
+    private bool _showAwardBadges;
+    private bool _showPreFreezeRank;
+    private Bitmap? _teamLogoImage;
+    private Bitmap? _countryFlagImage;
 
     public PreFreezeScoreboardRowViewModel(
         TeamStatus source,
         int rank,
         IReadOnlyList<ProblemDisplayInfo> orderedProblems,
-        Bitmap? teamLogoImage)
+        PresentationColorsConfig colors,
+        ProblemCellNotation cellNotation)
     {
         _source = source;
         _orderedProblems = orderedProblems;
+        _colors = colors;
+        _cellNotation = cellNotation;
         _rank = rank;
-        TeamLogoImage = teamLogoImage;
-        ProblemCells = BuildProblemCells(orderedProblems, source.ProblemStats);
+        PreFreezeRank = rank;
+        ProblemCells = BuildProblemCells(orderedProblems, source, colors, cellNotation);
     }
 
     public int Rank
@@ -908,7 +3509,92 @@ public sealed class PreFreezeScoreboardRowViewModel : ViewModelBase
         private set => SetProperty(ref _rank, value);
     }
 
-    public Bitmap? TeamLogoImage { get; }
+    public int PreFreezeRank { get; }
+    public string PreFreezeRankText => Localizer.Instance.Format("present.was_rank", PreFreezeRank);
+
+    public string PreFreezeRankColor => Rank < PreFreezeRank ? "#31C950"
+        : Rank > PreFreezeRank ? "#FB2C36"
+        : "#8A8A8A";
+
+    /// <summary>
+    ///     Positions gained (positive) or lost (negative) in the last resort; zero when the row did not move.
+    /// </summary>
+    public int RankDelta
+    {
+        get => _rankDelta;
+        private set
+        {
+            if (SetProperty(ref _rankDelta, value))
+            {
+                OnPropertyChanged(nameof(RankDeltaText));
+                OnPropertyChanged(nameof(RankDeltaColor));
+                OnPropertyChanged(nameof(HasRankDelta));
+            }
+        }
+    }
+
+    public string RankDeltaText => RankDelta > 0 ? $"\u25B2{RankDelta}"
+        : RankDelta < 0 ? $"\u25BC{-RankDelta}"
+        : string.Empty;
+
+    public string RankDeltaColor => RankDelta > 0 ? "#31C950" : "#FB2C36";
+    public bool HasRankDelta => RankDelta != 0;
+
+    public double RankDeltaOpacity
+    {
+        get => _rankDeltaOpacity;
+        set => SetProperty(ref _rankDeltaOpacity, value);
+    }
+
+    public bool ShowPreFreezeRank
+    {
+        get => _showPreFreezeRank;
+        set => SetProperty(ref _showPreFreezeRank, value);
+    }
+
+    public Bitmap? TeamLogoImage
+    {
+        get => _teamLogoImage;
+        set => SetProperty(ref _teamLogoImage, value);
+    }
+
+    public string? CountryCode { get; init; }
+    public Bitmap? CountryFlagImage
+    {
+        get => _countryFlagImage;
+        set
+        {
+            if (SetProperty(ref _countryFlagImage, value))
+            {
+                OnPropertyChanged(nameof(HasCountryFlag));
+                OnPropertyChanged(nameof(ShowCountryCodeBadge));
+            }
+        }
+    }
+
+    public string? OrganizationName { get; init; }
+    public string? TeamLabel { get; init; }
+    public bool ShowTeamLabel { get; init; }
+    public bool MarqueeTeamName { get; init; }
+    public bool IsUnofficial { get; init; }
+
+    public IReadOnlyList<AwardBadgeViewModel> AwardBadges { get; init; } = [];
+
+    public bool ShowAwardBadges
+    {
+        get => _showAwardBadges && AwardBadges.Count > 0;
+        set => SetProperty(ref _showAwardBadges, value);
+    }
+
+    public string MedalTint
+    {
+        get => _medalTint;
+        set => SetProperty(ref _medalTint, value);
+    }
+
+    public bool HasOrganizationName => !string.IsNullOrEmpty(OrganizationName);
+    public bool HasCountryFlag => CountryFlagImage is not null;
+    public bool ShowCountryCodeBadge => CountryFlagImage is null && !string.IsNullOrEmpty(CountryCode);
 
     internal string TeamId => _source.TeamId;
     internal TeamStatus TeamStatus => _source;
@@ -921,6 +3607,25 @@ public sealed class PreFreezeScoreboardRowViewModel : ViewModelBase
     public void SetRank(int rank)
     {
         Rank = rank;
+        OnPropertyChanged(nameof(PreFreezeRankColor));
+    }
+
+    public void SetColors(PresentationColorsConfig colors)
+    {
+        _colors = colors;
+        UpdateProblemCells();
+    }
+
+    public void SetRankDelta(int delta)
+    {
+        RankDelta = delta;
+        RankDeltaOpacity = 1;
+    }
+
+    public void ClearRankDelta()
+    {
+        RankDelta = 0;
+        RankDeltaOpacity = 0;
     }
 
     public void RefreshFromSource()
@@ -932,28 +3637,58 @@ public sealed class PreFreezeScoreboardRowViewModel : ViewModelBase
 
     private static ObservableCollection<ProblemStatusCellViewModel> BuildProblemCells(
         IReadOnlyList<ProblemDisplayInfo> orderedProblems,
-        Dictionary<string, ProblemStat> problemStats)
+        TeamStatus source,
+        PresentationColorsConfig colors,
+        ProblemCellNotation cellNotation)
     {
         var cells = new ObservableCollection<ProblemStatusCellViewModel>();
 
         foreach (var problem in orderedProblems)
         {
-            cells.Add(CreateProblemCell(problem, problemStats));
+            cells.Add(CreateProblemCell(problem, source, colors, cellNotation));
         }
 
         return cells;
     }
 
+    public ProblemStatusCellViewModel? GetProblemCell(string problemId)
+    {
+        for (var i = 0; i < _orderedProblems.Count && i < ProblemCells.Count; i++)
+        {
+            if (string.Equals(_orderedProblems[i].Id, problemId, StringComparison.Ordinal))
+            {
+                return ProblemCells[i];
+            }
+        }
+
+        return null;
+    }
+
+    public string? FindProblemId(ProblemStatusCellViewModel cell)
+    {
+        var index = ProblemCells.IndexOf(cell);
+        return index >= 0 && index < _orderedProblems.Count ? _orderedProblems[index].Id : null;
+    }
+
+    public void SetRevealTarget(string? problemId)
+    {
+        for (var i = 0; i < _orderedProblems.Count && i < ProblemCells.Count; i++)
+        {
+            ProblemCells[i].IsRevealTarget = problemId is not null &&
+                                             string.Equals(_orderedProblems[i].Id, problemId, StringComparison.Ordinal);
+        }
+    }
+
     private void UpdateProblemCells()
     {
         for (var i = 0; i < _orderedProblems.Count; i++)
         {
             var problem = _orderedProblems[i];
-            var (text, background) = BuildProblemCellValue(problem, _source.ProblemStats);
+            var (text, background) = BuildProblemCellValue(problem, _source, _colors, _cellNotation);
 
             if (i >= ProblemCells.Count)
             {
-                ProblemCells.Add(new ProblemStatusCellViewModel(text, background));
+                ProblemCells.Add(new ProblemStatusCellViewModel(text, background, problem.BalloonColor));
                 continue;
             }
 
@@ -970,56 +3705,159 @@ public sealed class PreFreezeScoreboardRowViewModel : ViewModelBase
 
     private static ProblemStatusCellViewModel CreateProblemCell(
         ProblemDisplayInfo problem,
-        Dictionary<string, ProblemStat> problemStats)
+        TeamStatus source,
+        PresentationColorsConfig colors,
+        ProblemCellNotation cellNotation)
     {
-        var (text, background) = BuildProblemCellValue(problem, problemStats);
-        return new ProblemStatusCellViewModel(text, background);
+        var (text, background) = BuildProblemCellValue(problem, source, colors, cellNotation);
+        return new ProblemStatusCellViewModel(text, background, problem.BalloonColor);
     }
 
     private static (string Text, string Background) BuildProblemCellValue(
         ProblemDisplayInfo problem,
-        Dictionary<string, ProblemStat> problemStats)
+        TeamStatus source,
+        PresentationColorsConfig colors,
+        ProblemCellNotation cellNotation)
     {
-        problemStats.TryGetValue(problem.Id, out var stat);
-        var text = stat is { SubmissionsBeforeSolved: > 0 }
-            ? $"{stat.SubmissionsBeforeSolved}-{stat.LastSubmissionTime}"
-            : problem.Label;
+        source.ProblemStats.TryGetValue(problem.Id, out var stat);
+        var isFirstSolve = stat is { Solved: true, AttemptedDuringFreeze: false } &&
+                           problem.FirstSolverTeamIds.Contains(source.TeamId);
+        var text = stat is not { SubmissionsBeforeSolved: > 0 } ? problem.Label
+            : stat.AttemptedDuringFreeze ? FormatFrozenCellText(stat, cellNotation)
+            : cellNotation == ProblemCellNotation.Classic ? FormatClassicCellText(stat)
+            : $"{stat.SubmissionsBeforeSolved}-{stat.LastSubmissionTime}";
+        if (isFirstSolve)
+        {
+            text = $"\u2605 {text}";
+        }
 
         var background = stat switch
         {
-            { AttemptedDuringFreeze: true } => "#2B7FFF",
-            { Solved: true } => "#31C950",
-            { SubmissionsBeforeSolved: > 0 } => "#FB2C36",
-            _ => "#62748E"
+            { AttemptedDuringFreeze: true } => colors.Pending,
+            { Solved: true } when isFirstSolve => colors.FirstSolve,
+            { Solved: true } => colors.Solved,
+            { SubmissionsBeforeSolved: > 0 } => colors.Rejected,
+            _ => colors.Untouched
         };
 
         return (text, background);
     }
+
+    /// <summary>
+    ///     "? +N" with N the attempts made during the freeze, prefixed by the pre-freeze attempts (already public) if any.
+    /// </summary>
+    private static string FormatFrozenCellText(ProblemStat stat, ProblemCellNotation cellNotation)
+    {
+        var pending = $"? +{stat.SubmissionsDuringFreeze}";
+        var beforeFreeze = stat.SubmissionsBeforeSolved - stat.SubmissionsDuringFreeze;
+        if (beforeFreeze <= 0)
+        {
+            return pending;
+        }
+
+        return cellNotation == ProblemCellNotation.Classic
+            ? $"\u2212{beforeFreeze} {pending}"
+            : $"{beforeFreeze} {pending}";
+    }
+
+    private static string FormatClassicCellText(ProblemStat stat)
+    {
+        if (!stat.Solved)
+        {
+            return $"\u2212{stat.SubmissionsBeforeSolved}";
+        }
+
+        var rejectedTries = stat.SubmissionsBeforeSolved - 1;
+        return rejectedTries > 0
+            ? $"+{rejectedTries} / {stat.LastSubmissionTime}"
+            : $"+ / {stat.LastSubmissionTime}";
+    }
 }
 
 public sealed class ProblemDisplayInfo
 {
-    public ProblemDisplayInfo(string id, string label)
+    private const string NoBalloonColor = "#00000000";
+
+    public ProblemDisplayInfo(
+        string id,
+        string label,
+        string? rgb = null,
+        IReadOnlySet<string>? firstSolverTeamIds = null)
     {
         Id = id;
         Label = label;
+        FirstSolverTeamIds = firstSolverTeamIds ?? new HashSet<string>(StringComparer.Ordinal);
+        if (!string.IsNullOrWhiteSpace(rgb) && PresentationColorsConfig.TryNormalizeHexColor(rgb, out var normalized))
+        {
+            BalloonColor = normalized;
+            HasBalloonColor = true;
+        }
+    }
+
+    public string Id { get; }
+    public string Label { get; }
+    public string BalloonColor { get; } = NoBalloonColor;
+    public bool HasBalloonColor { get; }
+    public IReadOnlySet<string> FirstSolverTeamIds { get; }
+}
+
+public sealed class ProblemHeaderViewModel : ViewModelBase
+{
+    private int _solvedCount;
+
+    public ProblemHeaderViewModel(ProblemDisplayInfo problem)
+    {
+        Id = problem.Id;
+        Label = problem.Label;
+        BalloonColor = problem.BalloonColor;
+        HasBalloonColor = problem.HasBalloonColor;
     }
 
     public string Id { get; }
     public string Label { get; }
+    public string BalloonColor { get; }
+    public bool HasBalloonColor { get; }
+
+    public int SolvedCount
+    {
+        get => _solvedCount;
+        set => SetProperty(ref _solvedCount, value);
+    }
+}
+
+public sealed class AwardBadgeViewModel
+{
+    public AwardBadgeViewModel(Award award, string text)
+    {
+        Text = text;
+        Background = award.Id switch
+        {
+            _ when award.Id.Contains("gold", StringComparison.OrdinalIgnoreCase) => "#C9A227",
+            _ when award.Id.Contains("silver", StringComparison.OrdinalIgnoreCase) => "#8E9AA6",
+            _ when award.Id.Contains("bronze", StringComparison.OrdinalIgnoreCase) => "#A0643B",
+            _ => "#3A3A3A"
+        };
+    }
+
+    public string Text { get; }
+    public string Background { get; }
 }
 
 public sealed class ProblemStatusCellViewModel : ViewModelBase
 {
     private string _background;
+    private bool _isRevealTarget;
     private string _text;
 
-    public ProblemStatusCellViewModel(string text, string background)
+    public ProblemStatusCellViewModel(string text, string background, string balloonColor)
     {
         _text = text;
         _background = background;
+        BalloonColor = balloonColor;
     }
 
+    public string BalloonColor { get; }
+
     public string Text
     {
         get => _text;
@@ -1032,6 +3870,12 @@ public sealed class ProblemStatusCellViewModel : ViewModelBase
         private set => SetProperty(ref _background, value);
     }
 
+    public bool IsRevealTarget
+    {
+        get => _isRevealTarget;
+        set => SetProperty(ref _isRevealTarget, value);
+    }
+
     public void Update(string text, string background)
     {
         Text = text;
@@ -1,4 +1,5 @@
 using Pyrite.Models;
+using Pyrite.Services;
 using System.Collections.Generic;
 using System.Text.Json.Serialization;
 
@@ -7,6 +8,20 @@ namespace Pyrite.ViewModels;
 [JsonSourceGenerationOptions(PropertyNameCaseInsensitive = true, WriteIndented = true)]
 [JsonSerializable(typeof(Dictionary<string, Award>))]
 [JsonSerializable(typeof(ContestState))]
+[JsonSerializable(typeof(List<ClicsAward>))]
+[JsonSerializable(typeof(ResolverScriptDocument))]
 internal sealed partial class SetMedalJsonContext : JsonSerializerContext
 {
 }
+
+/// <summary>
+///     An award as the CLICS <c>awards</c> endpoint describes it, without Pyrite's presentation fields.
+/// </summary>
+internal sealed class ClicsAward
+{
+    [JsonPropertyName("id")] public string Id { get; init; } = string.Empty;
+
+    [JsonPropertyName("citation")] public string Citation { get; init; } = string.Empty;
+
+    [JsonPropertyName("team_ids")] public List<string> TeamIds { get; init; } = [];
+}
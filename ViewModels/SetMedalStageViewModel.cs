@@ -1,6 +1,8 @@
+using Avalonia;
 using CommunityToolkit.Mvvm.ComponentModel;
 using CommunityToolkit.Mvvm.Input;
 using Pyrite.Models;
+using Pyrite.Services;
 using System;
 using System.Collections.Generic;
 using System.Collections.ObjectModel;
@@ -8,28 +10,49 @@ using System.ComponentModel;
 using System.IO;
 using System.Linq;
 using System.Text.Json;
+using System.Threading.Tasks;
 
 namespace Pyrite.ViewModels;
 
 public sealed class SetMedalStageViewModel : ViewModelBase
 {
+    private readonly Stack<AwardSnapshot> _awardRedoStack = new();
+    private readonly Stack<AwardSnapshot> _awardUndoStack = new();
+
     private ContestState? _contestState;
+    private AwardEditorViewModel? _editingMedal;
+    private string? _cdpPath;
+    private PyriteConfig _config = PyriteConfig.Default();
+    private Dictionary<string, List<string>> _groupPresets = new(StringComparer.Ordinal);
+    private string _groupPresetName = string.Empty;
+    private string? _selectedGroupPreset;
     private int _eligibleTeamCount;
+    private Dictionary<string, Award> _feedAwards = new(StringComparer.Ordinal);
     private string _finalizedCacheKey = string.Empty;
     private List<TeamStatus> _finalizedLeaderboard = [];
+    private string _groupChampionCitation = "Champion of {group}";
     private string _groupKey = string.Empty;
+    private int _honorableMentionMinSolved = 1;
     private string _manualCitation = string.Empty;
 
     private string _manualMedalId = string.Empty;
-    private string _manualTeamIdsCsv = string.Empty;
+    private List<TeamPickerItemViewModel> _allTeamPickerItems = [];
+    private string _teamSearchText = string.Empty;
+    private TeamPickerGroupOption? _teamPickerGroup;
     private string _medalBronzeCitation = "Bronze Medal";
     private int _medalBronzeCount;
     private string _medalGoldCitation = "Gold Medal";
 
     private int _medalGoldCount;
+    private int _medalGoldPercent = 10;
     private string _medalSilverCitation = "Silver Medal";
     private int _medalSilverCount;
+    private int _medalSilverPercent = 20;
+    private int _medalBronzePercent = 30;
+    private bool _shareMedalsOnTies;
+    private bool _usePercentageMedalCounts;
     private string _statusMessage = string.Empty;
+    private string _unknownAwardTeamsWarning = string.Empty;
 
     public SetMedalStageViewModel()
     {
@@ -38,22 +61,93 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         ApplyMedalsCommand = new RelayCommand(ApplyMedals);
         AddOrUpdateMedalCommand = new RelayCommand(AddOrUpdateMedal);
         DeleteMedalCommand = new RelayCommand<string>(DeleteMedal);
+        EditMedalCommand = new RelayCommand<string>(EditMedal);
+        SaveEditedMedalCommand = new RelayCommand(SaveEditedMedal);
+        CancelEditedMedalCommand = new RelayCommand(CancelEditedMedal);
+        AddEditedMedalTeamCommand = new RelayCommand(AddEditedMedalTeam);
+        RemoveEditedMedalTeamCommand = new RelayCommand<string>(RemoveEditedMedalTeam);
+        GenerateFirstSolveAwardsCommand = new RelayCommand(GenerateFirstSolveAwards);
+        GenerateFirstAcceptedAwardCommand = new RelayCommand(GenerateFirstAcceptedAward);
+        GenerateLastAcceptedAwardCommand = new RelayCommand(GenerateLastAcceptedAward);
+        GenerateGroupChampionAwardsCommand = new RelayCommand(GenerateGroupChampionAwards);
+        GenerateHonorableMentionCommand = new RelayCommand(GenerateHonorableMention);
+        UseFeedAwardCommand = new RelayCommand<string>(UseFeedAward);
+        UseLocalAwardCommand = new RelayCommand<string>(UseLocalAward);
+        UseAllFeedAwardsCommand = new RelayCommand(UseAllFeedAwards);
+        UseAllLocalAwardsCommand = new RelayCommand(UseAllLocalAwards);
+        RemoveUnknownAwardTeamsCommand = new RelayCommand(RemoveUnknownAwardTeams);
+        SaveGroupPresetCommand = new RelayCommand(SaveGroupPreset);
+        ApplyGroupPresetCommand = new RelayCommand(ApplyGroupPreset);
+        UndoAwardsCommand = new RelayCommand(UndoAwards, () => CanUndoAwards);
+        RedoAwardsCommand = new RelayCommand(RedoAwards, () => CanRedoAwards);
     }
 
     public ObservableCollection<GroupSelectionItemViewModel> Groups { get; } = [];
+    public ObservableCollection<string> GroupPresetNames { get; } = [];
     public ObservableCollection<TeamPreviewItem> GoldPreview { get; } = [];
     public ObservableCollection<TeamPreviewItem> SilverPreview { get; } = [];
     public ObservableCollection<TeamPreviewItem> BronzePreview { get; } = [];
+
+    /// <summary>
+    ///     The full finalized leaderboard with the medal of each eligible team and a line under the last team of
+    ///     each medal.
+    /// </summary>
+    public ObservableCollection<MedalStandingItem> MedalStandings { get; } = [];
     public ObservableCollection<MedalSummaryItem> Medals { get; } = [];
+    public ObservableCollection<TeamPickerItemViewModel> TeamPickerItems { get; } = [];
+    public ObservableCollection<TeamPickerGroupOption> TeamPickerGroups { get; } = [];
+
+    /// <summary>
+    ///     Awards from the event feed that clash with awards made or changed here: the same id with other content, or
+    ///     a medal whose teams also hold a medal from the other source.
+    /// </summary>
+    public ObservableCollection<AwardConflictItem> AwardConflicts { get; } = [];
 
     public RelayCommand SelectAllGroupsCommand { get; }
     public RelayCommand ClearAllGroupsCommand { get; }
     public RelayCommand ApplyMedalsCommand { get; }
     public RelayCommand AddOrUpdateMedalCommand { get; }
     public RelayCommand<string> DeleteMedalCommand { get; }
+    public RelayCommand<string> EditMedalCommand { get; }
+    public RelayCommand SaveEditedMedalCommand { get; }
+    public RelayCommand CancelEditedMedalCommand { get; }
+    public RelayCommand AddEditedMedalTeamCommand { get; }
+    public RelayCommand<string> RemoveEditedMedalTeamCommand { get; }
+    public RelayCommand GenerateFirstSolveAwardsCommand { get; }
+    public RelayCommand GenerateFirstAcceptedAwardCommand { get; }
+    public RelayCommand GenerateLastAcceptedAwardCommand { get; }
+    public RelayCommand GenerateGroupChampionAwardsCommand { get; }
+    public RelayCommand GenerateHonorableMentionCommand { get; }
+    public RelayCommand<string> UseFeedAwardCommand { get; }
+    public RelayCommand<string> UseLocalAwardCommand { get; }
+    public RelayCommand UseAllFeedAwardsCommand { get; }
+    public RelayCommand UseAllLocalAwardsCommand { get; }
+    public RelayCommand RemoveUnknownAwardTeamsCommand { get; }
+    public RelayCommand SaveGroupPresetCommand { get; }
+    public RelayCommand ApplyGroupPresetCommand { get; }
+    public RelayCommand UndoAwardsCommand { get; }
+    public RelayCommand RedoAwardsCommand { get; }
+
+    public bool CanUndoAwards => _awardUndoStack.Count > 0;
+    public bool CanRedoAwards => _awardRedoStack.Count > 0;
 
     public bool HasContestState => _contestState is not null;
 
+    public string GroupPresetName
+    {
+        get => _groupPresetName;
+        set => SetProperty(ref _groupPresetName, value);
+    }
+
+    public string? SelectedGroupPreset
+    {
+        get => _selectedGroupPreset;
+        set
+        {
+            if (SetProperty(ref _selectedGroupPreset, value) && value is not null) GroupPresetName = value;
+        }
+    }
+
     public string StatusMessage
     {
         get => _statusMessage;
@@ -65,6 +159,35 @@ public sealed class SetMedalStageViewModel : ViewModelBase
 
     public bool HasStatusMessage => !string.IsNullOrWhiteSpace(StatusMessage);
 
+    /// <summary>
+    ///     Working copy of the medal being edited; the contest state is only touched on save.
+    /// </summary>
+    public AwardEditorViewModel? EditingMedal
+    {
+        get => _editingMedal;
+        private set
+        {
+            if (SetProperty(ref _editingMedal, value)) OnPropertyChanged(nameof(IsEditingMedal));
+        }
+    }
+
+    public bool IsEditingMedal => EditingMedal is not null;
+    public bool HasAwardConflicts => AwardConflicts.Count > 0;
+
+    /// <summary>
+    ///     Awards naming team IDs that are not in the contest; the overlay would show those raw IDs.
+    /// </summary>
+    public string UnknownAwardTeamsWarning
+    {
+        get => _unknownAwardTeamsWarning;
+        private set
+        {
+            if (SetProperty(ref _unknownAwardTeamsWarning, value)) OnPropertyChanged(nameof(HasUnknownAwardTeams));
+        }
+    }
+
+    public bool HasUnknownAwardTeams => !string.IsNullOrWhiteSpace(UnknownAwardTeamsWarning);
+
     public int MedalGoldCount
     {
         get => _medalGoldCount;
@@ -92,6 +215,62 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         }
     }
 
+    /// <summary>
+    ///     Derive the medal counts from <see cref="MedalGoldPercent" /> and friends, as percentages of the eligible
+    ///     teams, instead of the fixed counts.
+    /// </summary>
+    public bool UsePercentageMedalCounts
+    {
+        get => _usePercentageMedalCounts;
+        set
+        {
+            if (SetProperty(ref _usePercentageMedalCounts, value)) RecomputeMedalPreview();
+        }
+    }
+
+    public int MedalGoldPercent
+    {
+        get => _medalGoldPercent;
+        set
+        {
+            if (SetProperty(ref _medalGoldPercent, Math.Clamp(value, 0, 100))) RecomputeMedalPreview();
+        }
+    }
+
+    public int MedalSilverPercent
+    {
+        get => _medalSilverPercent;
+        set
+        {
+            if (SetProperty(ref _medalSilverPercent, Math.Clamp(value, 0, 100))) RecomputeMedalPreview();
+        }
+    }
+
+    public int MedalBronzePercent
+    {
+        get => _medalBronzePercent;
+        set
+        {
+            if (SetProperty(ref _medalBronzePercent, Math.Clamp(value, 0, 100))) RecomputeMedalPreview();
+        }
+    }
+
+    /// <summary>
+    ///     Teams tied with the last team of a medal (same solved count and penalty) get that medal too, so a tie at a
+    ///     boundary shares the higher medal instead of being split by the tie-breaker.
+    /// </summary>
+    public bool ShareMedalsOnTies
+    {
+        get => _shareMedalsOnTies;
+        set
+        {
+            if (SetProperty(ref _shareMedalsOnTies, value)) RecomputeMedalPreview();
+        }
+    }
+
+    public string AllocatedMedalSummary =>
+        $"Allocated: {GoldPreview.Count} gold, {SilverPreview.Count} silver, {BronzePreview.Count} bronze";
+
     public string MedalGoldCitation
     {
         get => _medalGoldCitation;
@@ -110,6 +289,25 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         set => SetProperty(ref _medalBronzeCitation, value);
     }
 
+    /// <summary>
+    ///     Citation template for category champion awards; <c>{group}</c> is replaced by the category name. A category
+    ///     can override it with <see cref="GroupSelectionItemViewModel.ChampionCitation" />.
+    /// </summary>
+    public string GroupChampionCitation
+    {
+        get => _groupChampionCitation;
+        set => SetProperty(ref _groupChampionCitation, value);
+    }
+
+    /// <summary>
+    ///     Minimum number of solved problems for an honorable mention.
+    /// </summary>
+    public int HonorableMentionMinSolved
+    {
+        get => _honorableMentionMinSolved;
+        set => SetProperty(ref _honorableMentionMinSolved, Math.Max(1, value));
+    }
+
     public string ManualMedalId
     {
         get => _manualMedalId;
@@ -122,12 +320,29 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         set => SetProperty(ref _manualCitation, value);
     }
 
-    public string ManualTeamIdsCsv
+    /// <summary>
+    ///     Filters the manual medal team picker by team id, team name, or organization name.
+    /// </summary>
+    public string TeamSearchText
+    {
+        get => _teamSearchText;
+        set
+        {
+            if (SetProperty(ref _teamSearchText, value)) RefreshTeamPicker();
+        }
+    }
+
+    public TeamPickerGroupOption? TeamPickerGroup
     {
-        get => _manualTeamIdsCsv;
-        set => SetProperty(ref _manualTeamIdsCsv, value);
+        get => _teamPickerGroup;
+        set
+        {
+            if (SetProperty(ref _teamPickerGroup, value)) RefreshTeamPicker();
+        }
     }
 
+    public string PickedTeamSummary => $"{_allTeamPickerItems.Count(x => x.IsChecked)} team(s) selected";
+
     public int EligibleTeamCount
     {
         get => _eligibleTeamCount;
@@ -141,7 +356,15 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         }
     }
 
-    public int RequestedMedalCount => MedalGoldCount + MedalSilverCount + MedalBronzeCount;
+    public int RequestedMedalCount
+    {
+        get
+        {
+            var (gold, silver, bronze) = GetRequestedMedalCounts(EligibleTeamCount);
+            return gold + silver + bronze;
+        }
+    }
+
     public bool RequestedMedalsExceedEligible => RequestedMedalCount > EligibleTeamCount;
 
     public void SetStatusMessage(string message)
@@ -149,9 +372,26 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         StatusMessage = message;
     }
 
+    /// <summary>
+    ///     Presets come from <c>[group_presets]</c> in <c>config.toml</c> and from the user's saved
+    ///     <see cref="GroupPresets" />; on a name clash the saved one wins.
+    /// </summary>
+    public void SetGroupPresetSource(string? cdpPath, IReadOnlyDictionary<string, List<string>> configPresets)
+    {
+        _cdpPath = cdpPath;
+        _groupPresets = configPresets.ToDictionary(x => x.Key, x => x.Value.ToList(), StringComparer.Ordinal);
+        foreach (var (name, groupIds) in GroupPresets.Load()) _groupPresets[name] = groupIds;
+
+        RefreshGroupPresetNames();
+    }
+
     public void SetContestState(ContestState? contestState)
     {
         _contestState = contestState;
+        // Whatever the contest state holds on arrival came from the event feed.
+        _feedAwards = contestState is null
+            ? new Dictionary<string, Award>(StringComparer.Ordinal)
+            : CloneAwards(contestState.Awards);
         _groupKey = string.Empty;
         _finalizedCacheKey = string.Empty;
         _finalizedLeaderboard = [];
@@ -161,6 +401,15 @@ public sealed class SetMedalStageViewModel : ViewModelBase
 
         Groups.Clear();
         Medals.Clear();
+        MedalStandings.Clear();
+        AwardConflicts.Clear();
+        OnPropertyChanged(nameof(HasAwardConflicts));
+        UnknownAwardTeamsWarning = string.Empty;
+        _awardUndoStack.Clear();
+        _awardRedoStack.Clear();
+        NotifyAwardHistoryChanged();
+        EditingMedal = null;
+        BuildTeamPicker();
         GoldPreview.Clear();
         SilverPreview.Clear();
         BronzePreview.Clear();
@@ -184,6 +433,229 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         StatusMessage = $"Saved medals to {path}";
     }
 
+    /// <summary>
+    ///     Writes the awards as a CLICS award array, e.g. to upload them back to DOMjudge or feed them to ICPC Tools.
+    /// </summary>
+    public void ExportClicsAwardsToFile(string path)
+    {
+        if (!TryGetContestState(out var contestState)) return;
+
+        var awards = GetOrderedMedals(contestState)
+            .Select(x => new ClicsAward { Id = x.Id, Citation = x.Citation, TeamIds = x.TeamIds.ToList() })
+            .ToList();
+        File.WriteAllText(path, JsonSerializer.Serialize(awards, SetMedalJsonContext.Default.ListClicsAward));
+        StatusMessage = $"Exported {awards.Count} award(s) in CLICS format to {path}";
+    }
+
+    /// <summary>
+    ///     Pushes the awards to the <c>[contest_api]</c> endpoint, the contest given there or the loaded one.
+    /// </summary>
+    public async Task PushAwardsToContestApiAsync()
+    {
+        if (!TryGetContestState(out var contestState)) return;
+
+        var config = _config.ContestApi;
+        if (string.IsNullOrWhiteSpace(config.Url))
+        {
+            StatusMessage = "No Contest API configured; set url in the [contest_api] section of config.toml.";
+            return;
+        }
+
+        var contestId = config.ContestId ?? contestState.Contest?.Id;
+        if (string.IsNullOrWhiteSpace(contestId))
+        {
+            StatusMessage = "Unknown contest id; set contest_id in the [contest_api] section of config.toml.";
+            return;
+        }
+
+        var awards = GetOrderedMedals(contestState).ToList();
+        StatusMessage = $"Pushing {awards.Count} award(s) to contest {contestId}...";
+        var pushed = await ContestApiAwards.PushAsync(config, contestId, awards);
+        StatusMessage = $"Pushed {pushed} award(s) to contest {contestId} at {config.Url}";
+    }
+
+    /// <summary>
+    ///     Writes the final standings of the presented teams (current category selection) as CSV or TSV.
+    /// </summary>
+    public void ExportStandings(string path, char separator)
+    {
+        if (!TryBuildExportState(out var exportState)) return;
+
+        StandingsExport.WriteDelimited(exportState, path, separator);
+        StatusMessage = $"Exported standings to {path}";
+    }
+
+    public void ExportStandingsXlsx(string path)
+    {
+        if (!TryBuildExportState(out var exportState)) return;
+
+        StandingsExport.WriteXlsx(exportState, path);
+        StatusMessage = $"Exported standings workbook to {path}";
+    }
+
+    public void ExportHtmlScoreboard(string path)
+    {
+        if (!TryBuildExportState(out var exportState)) return;
+
+        HtmlScoreboardExport.Write(exportState, path);
+        StatusMessage = $"Exported HTML scoreboard to {path}";
+    }
+
+    public void ExportIcpcResults(string directory)
+    {
+        if (!TryBuildExportState(out var exportState)) return;
+
+        IcpcResultsExport.WriteAll(exportState, directory);
+        StatusMessage =
+            $"Exported {IcpcResultsExport.ResultsFileName} and {IcpcResultsExport.StandingsFileName} to {directory}";
+    }
+
+    public void ExportStatisticsReport(string path, bool asHtml)
+    {
+        if (!TryBuildExportState(out var exportState)) return;
+
+        var statistics = StatisticsReport.Build(exportState);
+        if (asHtml)
+            StatisticsReport.WriteHtml(statistics, path);
+        else
+            StatisticsReport.WriteMarkdown(statistics, path);
+        StatusMessage = $"Exported statistics report to {path}";
+    }
+
+    /// <summary>
+    ///     Writes <c>event-feed.ndjson</c> of the CDP folder again without the teams, problems, and submissions that
+    ///     the config filters and the category selection removed, and with the current awards.
+    /// </summary>
+    public void ExportEventFeed(string path)
+    {
+        if (!TryBuildExportState(out var exportState)) return;
+
+        if (string.IsNullOrWhiteSpace(_cdpPath))
+        {
+            StatusMessage = "No CDP folder loaded.";
+            return;
+        }
+
+        var written = EventFeedExport.Write(Path.Combine(_cdpPath, "event-feed.ndjson"), exportState, path);
+        StatusMessage = $"Exported {written} event(s) to {path}";
+    }
+
+    /// <summary>
+    ///     The loaded <c>config.toml</c>, for exports that follow the presentation or certificate settings. Its
+    ///     <c>[awards]</c> defaults pre-fill the medal setup.
+    /// </summary>
+    public void SetConfig(PyriteConfig config)
+    {
+        _config = config;
+
+        var awards = config.Awards;
+        if (awards.GoldCount is { } gold) MedalGoldCount = gold;
+        if (awards.SilverCount is { } silver) MedalSilverCount = silver;
+        if (awards.BronzeCount is { } bronze) MedalBronzeCount = bronze;
+        if (awards.GoldCitation is { } goldCitation) MedalGoldCitation = goldCitation;
+        if (awards.SilverCitation is { } silverCitation) MedalSilverCitation = silverCitation;
+        if (awards.BronzeCitation is { } bronzeCitation) MedalBronzeCitation = bronzeCitation;
+        if (awards.ChampionCitation is { } championCitation) GroupChampionCitation = championCitation;
+    }
+
+    /// <summary>
+    ///     Writes the final results of the presented teams in the versioned <see cref="PyriteResult" /> schema.
+    /// </summary>
+    public void ExportPyriteResult(string path)
+    {
+        if (!TryBuildExportState(out var exportState)) return;
+
+        File.WriteAllText(path, PyriteResult.Build(exportState).ToJson());
+        StatusMessage = $"Exported results (schema version {PyriteResult.CurrentSchemaVersion}) to {path}";
+    }
+
+    /// <summary>
+    ///     Writes the reveal sequence the presentation will run with the current awards and category selection.
+    /// </summary>
+    public void ExportResolverScript(string path)
+    {
+        if (!TryBuildExportState(out var exportState)) return;
+
+        var script = ResolverScript.Build(exportState, _config.Presentation);
+        File.WriteAllText(path, JsonSerializer.Serialize(script, SetMedalJsonContext.Default.ResolverScriptDocument));
+        StatusMessage = $"Exported resolver script ({script.Steps.Count} steps) to {path}";
+    }
+
+    /// <summary>
+    ///     Checks the <c>[certificates]</c> config and collects one entry per awarded team. The template and output
+    ///     directory are resolved against the CDP folder.
+    /// </summary>
+    public bool TryPrepareCertificates(
+        out CertificateConfig config,
+        out string templatePath,
+        out string outputDirectory,
+        out List<CertificateEntry> entries)
+    {
+        config = _config.Certificates;
+        templatePath = string.Empty;
+        outputDirectory = string.Empty;
+        entries = [];
+        if (!TryBuildExportState(out var exportState)) return false;
+
+        if (string.IsNullOrWhiteSpace(config.Template))
+        {
+            StatusMessage = "No certificate template; set template in the [certificates] section of config.toml.";
+            return false;
+        }
+
+        templatePath = ResolveCdpPath(config.Template);
+        if (!File.Exists(templatePath))
+        {
+            StatusMessage = $"Certificate template not found: {templatePath}";
+            return false;
+        }
+
+        if (config.TeamName is null && config.OrganizationName is null && config.Citation is null && config.Rank is null)
+        {
+            StatusMessage = "No certificate fields configured; add [certificates.team_name] or another field.";
+            return false;
+        }
+
+        entries = CertificateEntries.Build(exportState);
+        if (entries.Count == 0)
+        {
+            StatusMessage = "No awarded teams to write certificates for.";
+            return false;
+        }
+
+        outputDirectory = ResolveCdpPath(config.OutputDirectory);
+        return true;
+    }
+
+    /// <summary>
+    ///     Collects one result card per presented team, with the contest name, problems, and presentation settings
+    ///     (colors, ffmpeg for AVIF logos) the cards are drawn with.
+    /// </summary>
+    public bool TryPrepareResultCards(
+        out string contestName,
+        out IReadOnlyList<Problem> problems,
+        out PresentationConfig presentation,
+        out List<ResultCard> cards)
+    {
+        contestName = string.Empty;
+        problems = [];
+        presentation = _config.Presentation;
+        cards = [];
+        if (!TryBuildExportState(out var exportState)) return false;
+
+        cards = ResultCards.Build(exportState, _cdpPath, _config.Presentation);
+        if (cards.Count == 0)
+        {
+            StatusMessage = "No presented teams to write result cards for.";
+            return false;
+        }
+
+        contestName = new[] { exportState.Contest?.FormalName, exportState.Contest?.Name }
+            .FirstOrDefault(name => !string.IsNullOrWhiteSpace(name)) ?? string.Empty;
+        problems = StandingsExport.GetOrderedProblems(exportState);
+        return true;
+    }
+
     public void LoadMedalsFromFile(string path)
     {
         if (!TryGetContestState(out var contestState)) return;
@@ -200,13 +672,45 @@ public sealed class SetMedalStageViewModel : ViewModelBase
             normalized[medal.Id] = medal;
         }
 
+        PushAwardHistory(contestState);
         contestState.Awards = normalized;
+        EditingMedal = null;
         RefreshMedals();
         StatusMessage = $"Loaded {contestState.Awards.Count} medal(s) from {path}";
     }
 
-    public bool TryPreparePresentation(out string errorMessage)
+    /// <summary>
+    ///     Moves <paramref name="medalId" /> to the position of <paramref name="targetMedalId" /> in the medal list and
+    ///     renumbers <see cref="Award.Order" />, which decides the order citations appear in a team's award overlay.
+    /// </summary>
+    public void MoveMedal(string medalId, string targetMedalId)
+    {
+        if (!TryGetContestState(out var contestState, setStatusOnFailure: false) ||
+            string.Equals(medalId, targetMedalId, StringComparison.Ordinal))
+            return;
+
+        var ordered = GetOrderedMedals(contestState).ToList();
+        var sourceIndex = ordered.FindIndex(x => string.Equals(x.Id, medalId, StringComparison.Ordinal));
+        var targetIndex = ordered.FindIndex(x => string.Equals(x.Id, targetMedalId, StringComparison.Ordinal));
+        if (sourceIndex < 0 || targetIndex < 0) return;
+
+        PushAwardHistory(contestState);
+        var medal = ordered[sourceIndex];
+        ordered.RemoveAt(sourceIndex);
+        ordered.Insert(targetIndex, medal);
+        for (var i = 0; i < ordered.Count; i++) ordered[i].Order = i;
+
+        RefreshMedals();
+        StatusMessage = $"Moved medal {medalId} to position {targetIndex + 1}.";
+    }
+
+    /// <summary>
+    ///     Builds the contest state to present from the loaded one, filtered by the category selection. The loaded
+    ///     state is left untouched, so the setup can be changed and the presentation launched again.
+    /// </summary>
+    public bool TryPreparePresentation(out ContestState presentationState, out string errorMessage)
     {
+        presentationState = null!;
         errorMessage = string.Empty;
         if (!TryGetContestState(out var contestState))
         {
@@ -214,15 +718,18 @@ public sealed class SetMedalStageViewModel : ViewModelBase
             return false;
         }
 
+        if (HasAwardConflicts)
+        {
+            errorMessage = "Resolve the conflicts between event feed awards and local awards first.";
+            StatusMessage = errorMessage;
+            return false;
+        }
+
         try
         {
             //var dumpMessage = DumpContestStateBeforePresentation(contestState);
-            var filterMessage = ApplyGroupFilterForPresentation(contestState);
+            presentationState = BuildFilteredPresentationState(contestState, out var filterMessage);
             StatusMessage = $"{filterMessage}";
-            _finalizedCacheKey = string.Empty;
-            _finalizedLeaderboard = [];
-            RefreshMedals();
-            RecomputeMedalPreview();
             return true;
         }
         catch (Exception ex)
@@ -252,6 +759,8 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         if (_groupKey == currentKey && currentIds.SetEquals(existingIds)) return;
 
         var previousSelections = Groups.ToDictionary(x => x.Id, x => x.IsSelected, StringComparer.Ordinal);
+        var previousDisplayed = Groups.ToDictionary(x => x.Id, x => x.IsDisplayed, StringComparer.Ordinal);
+        var previousCitations = Groups.ToDictionary(x => x.Id, x => x.ChampionCitation, StringComparer.Ordinal);
         var resetToAllSelected = _groupKey != currentKey;
 
         foreach (var group in Groups) group.PropertyChanged -= OnGroupSelectionChanged;
@@ -263,7 +772,11 @@ public sealed class SetMedalStageViewModel : ViewModelBase
                 ? true
                 : oldValue;
 
-            var item = new GroupSelectionItemViewModel(group.Id, group.Name, selected);
+            var item = new GroupSelectionItemViewModel(group.Id, group.Name, selected)
+            {
+                IsDisplayed = resetToAllSelected || previousDisplayed.GetValueOrDefault(group.Id, true),
+                ChampionCitation = previousCitations.GetValueOrDefault(group.Id, string.Empty)
+            };
             item.PropertyChanged += OnGroupSelectionChanged;
             Groups.Add(item);
         }
@@ -286,6 +799,54 @@ public sealed class SetMedalStageViewModel : ViewModelBase
         foreach (var group in Groups) group.IsSelected = false;
     }
 
+    private void SaveGroupPreset()
+    {
+        var name = GroupPresetName.Trim();
+        if (string.IsNullOrWhiteSpace(name))
+        {
+            StatusMessage = "Enter a preset name.";
+            return;
+        }
+
+        var groupIds = Groups.Where(x => x.IsSelected).Select(x => x.Id).ToList();
+        var path = GroupPresets.FilePath;
+        try
+        {
+            GroupPresets.Save(name, groupIds);
+        }
+        catch (Exception ex)
+        {
+            StatusMessage = $"Failed to save group preset to {path}: {ex.Message}";
+            return;
+        }
+
+        _groupPresets[name] = groupIds;
+        RefreshGroupPresetNames();
+        SelectedGroupPreset = name;
+        StatusMessage = $"Saved group preset {name} ({groupIds.Count} categories) to {path}";
+    }
+
+    private void ApplyGroupPreset()
+    {
+        if (SelectedGroupPreset is null || !_groupPresets.TryGetValue(SelectedGroupPreset, out var groupIds)) return;
+
+        var selected = groupIds.ToHashSet(StringComparer.Ordinal);
+        foreach (var group in Groups) group.IsSelected = selected.Contains(group.Id);
+
+        var missing = groupIds.Where(id => Groups.All(x => !string.Equals(x.Id, id, StringComparison.Ordinal))).ToList();
+        StatusMessage = missing.Count == 0
+            ? $"Applied group preset {SelectedGroupPreset}."
+            : $"Applied group preset {SelectedGroupPreset}; not in this contest: {string.Join(", ", missing)}.";
+    }
+
+    private void RefreshGroupPresetNames()
+    {
+        GroupPresetNames.Clear();
+        foreach (var name in _groupPresets.Keys.Order(StringComparer.Ordinal)) GroupPresetNames.Add(name);
+
+        if (SelectedGroupPreset is not null && !_groupPresets.ContainsKey(SelectedGroupPreset)) SelectedGroupPreset = null;
+    }
+
     private void RecomputeMedalPreview()
     {
         if (_contestState is null)
@@ -293,6 +854,7 @@ public sealed class SetMedalStageViewModel : ViewModelBase
             GoldPreview.Clear();
             SilverPreview.Clear();
             BronzePreview.Clear();
+            MedalStandings.Clear();
             EligibleTeamCount = 0;
             return;
         }
@@ -309,30 +871,102 @@ public sealed class SetMedalStageViewModel : ViewModelBase
             StatusMessage = $"Failed to compute finalized leaderboard: {ex.Message}";
         }
 
-        var selectedGroupIds = Groups
-            .Where(x => x.IsSelected)
-            .Select(x => x.Id)
-            .ToHashSet(StringComparer.Ordinal);
-
-        var eligible = _finalizedLeaderboard
-            .Where(teamStatus =>
-                _contestState.Teams.TryGetValue(teamStatus.TeamId, out var team) &&
-                team.GroupIds.Any(groupId => selectedGroupIds.Contains(groupId)))
+        var eligibleTeams = GetEligibleLeaderboard(_contestState);
+        var eligible = eligibleTeams
             .Select(teamStatus => new TeamPreviewItem(teamStatus.TeamId, teamStatus.TeamName))
             .ToList();
 
         EligibleTeamCount = eligible.Count;
 
-        var goldEnd = Math.Min(MedalGoldCount, eligible.Count);
-        var silverEnd = Math.Min(goldEnd + MedalSilverCount, eligible.Count);
-        var bronzeEnd = Math.Min(silverEnd + MedalBronzeCount, eligible.Count);
+        var (goldCount, silverCount, bronzeCount) = GetRequestedMedalCounts(eligible.Count);
+        var goldEnd = ExtendMedalPastTies(eligibleTeams, Math.Min(goldCount, eligible.Count));
+        var silverEnd = ExtendMedalPastTies(eligibleTeams, Math.Min(goldEnd + silverCount, eligible.Count));
+        var bronzeEnd = ExtendMedalPastTies(eligibleTeams, Math.Min(silverEnd + bronzeCount, eligible.Count));
 
         ReplacePreviewCollection(GoldPreview, eligible.Take(goldEnd));
         ReplacePreviewCollection(SilverPreview, eligible.Skip(goldEnd).Take(silverEnd - goldEnd));
         ReplacePreviewCollection(BronzePreview, eligible.Skip(silverEnd).Take(bronzeEnd - silverEnd));
+        RebuildMedalStandings(eligibleTeams);
 
         OnPropertyChanged(nameof(RequestedMedalCount));
         OnPropertyChanged(nameof(RequestedMedalsExceedEligible));
+        OnPropertyChanged(nameof(AllocatedMedalSummary));
+    }
+
+    private void RebuildMedalStandings(IReadOnlyList<TeamStatus> eligibleTeams)
+    {
+        var eligibleTeamIds = eligibleTeams.Select(x => x.TeamId).ToHashSet(StringComparer.Ordinal);
+        var medalByTeamId = new Dictionary<string, MedalKind>(StringComparer.Ordinal);
+        var lastTeamIds = new HashSet<string>(StringComparer.Ordinal);
+        foreach (var (preview, medal) in new[]
+                 {
+                     (GoldPreview, MedalKind.Gold),
+                     (SilverPreview, MedalKind.Silver),
+                     (BronzePreview, MedalKind.Bronze)
+                 })
+        {
+            foreach (var item in preview) medalByTeamId[item.TeamId] = medal;
+            if (preview.Count > 0) lastTeamIds.Add(preview[^1].TeamId);
+        }
+
+        MedalStandings.Clear();
+        for (var i = 0; i < _finalizedLeaderboard.Count; i++)
+        {
+            var team = _finalizedLeaderboard[i];
+            MedalStandings.Add(new MedalStandingItem(
+                i + 1,
+                team,
+                medalByTeamId.GetValueOrDefault(team.TeamId, MedalKind.None),
+                eligibleTeamIds.Contains(team.TeamId),
+                lastTeamIds.Contains(team.TeamId)));
+        }
+    }
+
+    private (int Gold, int Silver, int Bronze) GetRequestedMedalCounts(int eligibleTeamCount)
+    {
+        if (!UsePercentageMedalCounts) return (MedalGoldCount, MedalSilverCount, MedalBronzeCount);
+
+        return (
+            PercentOfTeams(MedalGoldPercent, eligibleTeamCount),
+            PercentOfTeams(MedalSilverPercent, eligibleTeamCount),
+            PercentOfTeams(MedalBronzePercent, eligibleTeamCount));
+    }
+
+    private static int PercentOfTeams(int percent, int teamCount)
+    {
+        return (int)Math.Round(teamCount * percent / 100.0, MidpointRounding.AwayFromZero);
+    }
+
+    /// <summary>
+    ///     With <see cref="ShareMedalsOnTies" />, moves a medal boundary down past teams tied with the last medallist.
+    /// </summary>
+    private int ExtendMedalPastTies(IReadOnlyList<TeamStatus> leaderboard, int end)
+    {
+        if (!ShareMedalsOnTies || end <= 0) return end;
+
+        while (end < leaderboard.Count &&
+               leaderboard[end].TotalPoints == leaderboard[end - 1].TotalPoints &&
+               leaderboard[end].TotalPenalty == leaderboard[end - 1].TotalPenalty)
+            end++;
+
+        return end;
+    }
+
+    /// <summary>
+    ///     The finalized leaderboard restricted to teams in the checked (medal-eligible) categories.
+    /// </summary>
+    private List<TeamStatus> GetEligibleLeaderboard(ContestState contestState)
+    {
+        var selectedGroupIds = Groups
+            .Where(x => x.IsSelected)
+            .Select(x => x.Id)
+            .ToHashSet(StringComparer.Ordinal);
+
+        return _finalizedLeaderboard
+            .Where(teamStatus =>
+                contestState.Teams.TryGetValue(teamStatus.TeamId, out var team) &&
+                team.GroupIds.Any(groupId => selectedGroupIds.Contains(groupId)))
+            .ToList();
     }
 
     private static void ReplacePreviewCollection(
@@ -366,13 +1000,15 @@ public sealed class SetMedalStageViewModel : ViewModelBase
     {
         if (!TryGetContestState(out var contestState)) return;
 
+        PushAwardHistory(contestState);
         foreach (var (medalId, citation, winners) in BuildStandardMedalSpecifications())
-            contestState.Awards[medalId] = new Award
+            contestState.Awards[medalId] = KeepOverlayStyle(contestState, new Award
             {
                 Id = medalId,
                 Citation = citation,
-                TeamIds = winners
-            };
+                TeamIds = winners,
+                Order = GetMedalOrder(contestState, medalId)
+            });
 
         RefreshMedals();
         StatusMessage = "Medals applied to contest state.";
@@ -384,39 +1020,316 @@ public sealed class SetMedalStageViewModel : ViewModelBase
 
         var medalId = ManualMedalId.Trim();
         var citation = ManualCitation.Trim();
-        var teamIds = ManualTeamIdsCsv
-            .Split(',', StringSplitOptions.TrimEntries | StringSplitOptions.RemoveEmptyEntries)
-            .Distinct(StringComparer.Ordinal)
+        var teamIds = _allTeamPickerItems
+            .Where(x => x.IsChecked)
+            .Select(x => x.TeamId)
             .ToList();
 
         if (string.IsNullOrWhiteSpace(medalId) || string.IsNullOrWhiteSpace(citation) || teamIds.Count == 0)
         {
-            StatusMessage = "Medal ID, citation, and at least one team ID are required.";
+            StatusMessage = "Medal ID, citation, and at least one team are required.";
             return;
         }
 
-        contestState.Awards[medalId] = new Award
+        PushAwardHistory(contestState);
+        contestState.Awards[medalId] = KeepOverlayStyle(contestState, new Award
         {
             Id = medalId,
             Citation = citation,
-            TeamIds = teamIds
-        };
+            TeamIds = teamIds,
+            Order = GetMedalOrder(contestState, medalId)
+        });
 
         RefreshMedals();
         StatusMessage = "Medal upserted to contest state.";
     }
 
-    private void DeleteMedal(string? medalId)
+    private static IEnumerable<Award> GetOrderedMedals(ContestState contestState)
     {
-        if (!TryGetContestState(out var contestState, setStatusOnFailure: false) ||
-            string.IsNullOrWhiteSpace(medalId))
-            return;
+        return contestState.Awards.Values
+            .OrderBy(x => x.Order)
+            .ThenBy(x => x.Id, StringComparer.Ordinal);
+    }
 
-        if (contestState.Awards.Remove(medalId))
-        {
-            RefreshMedals();
-            StatusMessage = $"Deleted medal {medalId}.";
+    /// <summary>
+    ///     Keeps the position of a medal that is being replaced; new medals go to the end of the list.
+    /// </summary>
+    private static int GetMedalOrder(ContestState contestState, string medalId)
+    {
+        if (contestState.Awards.TryGetValue(medalId, out var existing)) return existing.Order;
+
+        return contestState.Awards.Count == 0 ? 0 : contestState.Awards.Values.Max(x => x.Order) + 1;
+    }
+
+    /// <summary>
+    ///     Carries the title, banner color, and media of a medal that is being replaced over to its replacement.
+    /// </summary>
+    private static Award KeepOverlayStyle(ContestState contestState, Award award)
+    {
+        if (!contestState.Awards.TryGetValue(award.Id, out var existing)) return award;
+
+        award.Title ??= existing.Title;
+        award.BannerColor ??= existing.BannerColor;
+        award.MediaPath ??= existing.MediaPath;
+        return award;
+    }
+
+    private void DeleteMedal(string? medalId)
+    {
+        if (!TryGetContestState(out var contestState, setStatusOnFailure: false) ||
+            string.IsNullOrWhiteSpace(medalId) ||
+            !contestState.Awards.ContainsKey(medalId))
+            return;
+
+        PushAwardHistory(contestState);
+        if (contestState.Awards.Remove(medalId))
+        {
+            if (string.Equals(EditingMedal?.Id, medalId, StringComparison.Ordinal)) EditingMedal = null;
+            RefreshMedals();
+            StatusMessage = $"Deleted medal {medalId}.";
+        }
+    }
+
+    private void GenerateFirstSolveAwards()
+    {
+        if (!TryGetContestState(out var contestState)) return;
+
+        UpsertGeneratedAwards(
+            contestState,
+            AwardTemplates.FirstSolves(GetEligibleLeaderboard(contestState), contestState.Problems));
+    }
+
+    private void GenerateFirstAcceptedAward()
+    {
+        if (!TryGetContestState(out var contestState)) return;
+
+        var award = AwardTemplates.FirstAccepted(GetEligibleLeaderboard(contestState));
+        UpsertGeneratedAwards(contestState, award is null ? [] : [award]);
+    }
+
+    private void GenerateLastAcceptedAward()
+    {
+        if (!TryGetContestState(out var contestState)) return;
+
+        var award = AwardTemplates.LastAccepted(GetEligibleLeaderboard(contestState));
+        UpsertGeneratedAwards(contestState, award is null ? [] : [award]);
+    }
+
+    private void GenerateGroupChampionAwards()
+    {
+        if (!TryGetContestState(out var contestState)) return;
+
+        if (string.IsNullOrWhiteSpace(GroupChampionCitation))
+        {
+            StatusMessage = "Category champion citation is required.";
+            return;
+        }
+
+        var citationByGroupId = Groups
+            .Where(x => x.IsSelected)
+            .ToDictionary(
+                x => x.Id,
+                x => string.IsNullOrWhiteSpace(x.ChampionCitation) ? GroupChampionCitation.Trim() : x.ChampionCitation.Trim(),
+                StringComparer.Ordinal);
+        var selectedGroups = citationByGroupId.Keys
+            .Select(groupId => contestState.Groups.GetValueOrDefault(groupId))
+            .OfType<Group>();
+        UpsertGeneratedAwards(
+            contestState,
+            AwardTemplates.GroupChampions(
+                GetEligibleLeaderboard(contestState),
+                contestState.Teams,
+                selectedGroups,
+                group => citationByGroupId[group.Id]));
+    }
+
+    private void GenerateHonorableMention()
+    {
+        if (!TryGetContestState(out var contestState)) return;
+
+        var award = AwardTemplates.HonorableMention(
+            GetEligibleLeaderboard(contestState),
+            contestState.Awards,
+            HonorableMentionMinSolved);
+        UpsertGeneratedAwards(contestState, award is null ? [] : [award]);
+    }
+
+    private void UpsertGeneratedAwards(ContestState contestState, IReadOnlyList<Award> awards)
+    {
+        if (awards.Count == 0)
+        {
+            StatusMessage = "No eligible team qualifies for this award.";
+            return;
         }
+
+        PushAwardHistory(contestState);
+        foreach (var award in awards)
+        {
+            award.Order = GetMedalOrder(contestState, award.Id);
+            contestState.Awards[award.Id] = KeepOverlayStyle(contestState, award);
+        }
+
+        RefreshMedals();
+        StatusMessage = $"Generated {awards.Count} award(s): {string.Join(", ", awards.Select(x => x.Id))}.";
+    }
+
+    private void BuildTeamPicker()
+    {
+        foreach (var item in _allTeamPickerItems) item.PropertyChanged -= OnTeamPickerItemChanged;
+
+        _allTeamPickerItems = [];
+        TeamPickerGroups.Clear();
+        _teamSearchText = string.Empty;
+        _teamPickerGroup = null;
+        OnPropertyChanged(nameof(TeamSearchText));
+
+        if (_contestState is not null)
+        {
+            foreach (var team in _contestState.Teams.Values
+                         .OrderBy(x => x.Name, StringComparer.OrdinalIgnoreCase)
+                         .ThenBy(x => x.Id, StringComparer.Ordinal))
+            {
+                var organization = team.OrganizationId is not null
+                    ? _contestState.Organizations.GetValueOrDefault(team.OrganizationId)
+                    : null;
+                var item = new TeamPickerItemViewModel(team.Id, team.Name, organization?.Name ?? string.Empty, team.GroupIds);
+                item.PropertyChanged += OnTeamPickerItemChanged;
+                _allTeamPickerItems.Add(item);
+            }
+
+            TeamPickerGroups.Add(new TeamPickerGroupOption(null, Localizer.Instance["set_medal.all_categories"]));
+            foreach (var group in _contestState.Groups.Values
+                         .OrderBy(x => x.Sortorder)
+                         .ThenBy(x => x.Name, StringComparer.Ordinal))
+                TeamPickerGroups.Add(new TeamPickerGroupOption(group.Id, group.Name));
+
+            _teamPickerGroup = TeamPickerGroups[0];
+        }
+
+        OnPropertyChanged(nameof(TeamPickerGroup));
+        RefreshTeamPicker();
+    }
+
+    private void RefreshTeamPicker()
+    {
+        var search = TeamSearchText.Trim();
+        var groupId = TeamPickerGroup?.Id;
+        TeamPickerItems.Clear();
+        foreach (var item in _allTeamPickerItems)
+        {
+            if (groupId is not null && !item.GroupIds.Contains(groupId, StringComparer.Ordinal)) continue;
+            if (search.Length > 0 && !item.Matches(search)) continue;
+
+            TeamPickerItems.Add(item);
+        }
+
+        OnPropertyChanged(nameof(PickedTeamSummary));
+    }
+
+    private void OnTeamPickerItemChanged(object? sender, PropertyChangedEventArgs args)
+    {
+        if (args.PropertyName == nameof(TeamPickerItemViewModel.IsChecked)) OnPropertyChanged(nameof(PickedTeamSummary));
+    }
+
+    private void EditMedal(string? medalId)
+    {
+        if (!TryGetContestState(out var contestState, setStatusOnFailure: false) ||
+            string.IsNullOrWhiteSpace(medalId) ||
+            !contestState.Awards.TryGetValue(medalId, out var medal))
+            return;
+
+        var editor = new AwardEditorViewModel(medal.Id, medal.Citation)
+        {
+            Title = medal.Title ?? string.Empty,
+            BannerColor = medal.BannerColor ?? string.Empty,
+            MediaPath = medal.MediaPath ?? string.Empty
+        };
+        foreach (var teamId in medal.TeamIds) editor.Teams.Add(CreateTeamPreviewItem(contestState, teamId));
+
+        EditingMedal = editor;
+    }
+
+    private void AddEditedMedalTeam()
+    {
+        if (EditingMedal is null || !TryGetContestState(out var contestState)) return;
+
+        var teamId = EditingMedal.NewTeamId.Trim();
+        if (string.IsNullOrWhiteSpace(teamId)) return;
+
+        if (!contestState.Teams.ContainsKey(teamId))
+        {
+            StatusMessage = $"Unknown team ID {teamId}.";
+            return;
+        }
+
+        if (EditingMedal.Teams.Any(x => string.Equals(x.TeamId, teamId, StringComparison.Ordinal)))
+        {
+            StatusMessage = $"Team {teamId} already has medal {EditingMedal.Id}.";
+            return;
+        }
+
+        EditingMedal.Teams.Add(CreateTeamPreviewItem(contestState, teamId));
+        EditingMedal.NewTeamId = string.Empty;
+    }
+
+    private void RemoveEditedMedalTeam(string? teamId)
+    {
+        if (EditingMedal is null || string.IsNullOrWhiteSpace(teamId)) return;
+
+        var item = EditingMedal.Teams.FirstOrDefault(x => string.Equals(x.TeamId, teamId, StringComparison.Ordinal));
+        if (item is not null) EditingMedal.Teams.Remove(item);
+    }
+
+    private void SaveEditedMedal()
+    {
+        if (EditingMedal is null || !TryGetContestState(out var contestState)) return;
+
+        var citation = EditingMedal.Citation.Trim();
+        if (string.IsNullOrWhiteSpace(citation) || EditingMedal.Teams.Count == 0)
+        {
+            StatusMessage = "Citation and at least one team are required.";
+            return;
+        }
+
+        var bannerColor = EditingMedal.BannerColor.Trim();
+        if (bannerColor.Length > 0 && !PresentationColorsConfig.TryNormalizeHexColor(bannerColor, out bannerColor))
+        {
+            StatusMessage = "Banner color must be a hex color such as #80000000.";
+            return;
+        }
+
+        PushAwardHistory(contestState);
+        if (!contestState.Awards.TryGetValue(EditingMedal.Id, out var medal))
+        {
+            medal = new Award { Id = EditingMedal.Id, Order = GetMedalOrder(contestState, EditingMedal.Id) };
+            contestState.Awards[medal.Id] = medal;
+        }
+
+        medal.Citation = citation;
+        medal.TeamIds = EditingMedal.Teams.Select(x => x.TeamId).ToList();
+        medal.Title = NullIfBlank(EditingMedal.Title);
+        medal.BannerColor = NullIfBlank(bannerColor);
+        medal.MediaPath = NullIfBlank(EditingMedal.MediaPath);
+        StatusMessage = $"Updated medal {medal.Id}.";
+        EditingMedal = null;
+        RefreshMedals();
+    }
+
+    private void CancelEditedMedal()
+    {
+        EditingMedal = null;
+    }
+
+    private static string? NullIfBlank(string value)
+    {
+        return string.IsNullOrWhiteSpace(value) ? null : value.Trim();
+    }
+
+    private static TeamPreviewItem CreateTeamPreviewItem(ContestState contestState, string teamId)
+    {
+        return new TeamPreviewItem(
+            teamId,
+            contestState.Teams.TryGetValue(teamId, out var team) ? team.Name : string.Empty);
     }
 
     private void RefreshMedals()
@@ -425,61 +1338,372 @@ public sealed class SetMedalStageViewModel : ViewModelBase
 
         if (_contestState is null) return;
 
-        foreach (var medal in _contestState.Awards.Values.OrderBy(x => x.Id, StringComparer.Ordinal))
+        foreach (var medal in GetOrderedMedals(_contestState))
+            Medals.Add(new MedalSummaryItem(medal.Id, medal.Citation, medal.TeamIds.Count, FormatTeamPreview(medal.TeamIds)));
+
+        RefreshAwardConflicts();
+        RefreshUnknownAwardTeams();
+    }
+
+    private void RefreshUnknownAwardTeams()
+    {
+        if (_contestState is null)
+        {
+            UnknownAwardTeamsWarning = string.Empty;
+            return;
+        }
+
+        var teams = _contestState.Teams;
+        var lines = GetOrderedMedals(_contestState)
+            .Select(x => (x.Id, Unknown: x.TeamIds.Where(teamId => !teams.ContainsKey(teamId)).ToList()))
+            .Where(x => x.Unknown.Count > 0)
+            .Select(x => $"{x.Id}: {string.Join(", ", x.Unknown)}")
+            .ToList();
+        UnknownAwardTeamsWarning = lines.Count == 0
+            ? string.Empty
+            : "Unknown team IDs in awards:\n" + string.Join("\n", lines);
+    }
+
+    /// <summary>
+    ///     Drops unknown team IDs from every award; awards left without a team are deleted.
+    /// </summary>
+    private void RemoveUnknownAwardTeams()
+    {
+        if (!TryGetContestState(out var contestState) || !HasUnknownAwardTeams) return;
+
+        PushAwardHistory(contestState);
+        var removedTeams = 0;
+        var removedAwards = new List<string>();
+        foreach (var award in contestState.Awards.Values.ToList())
         {
-            var preview = medal.TeamIds.Count == 0
-                ? "None"
-                : string.Join(", ", medal.TeamIds.Take(5)) + (medal.TeamIds.Count > 5 ? " ..." : string.Empty);
+            removedTeams += award.TeamIds.RemoveAll(teamId => !contestState.Teams.ContainsKey(teamId));
+            if (award.TeamIds.Count > 0) continue;
 
-            Medals.Add(new MedalSummaryItem(medal.Id, medal.Citation, medal.TeamIds.Count, preview));
+            contestState.Awards.Remove(award.Id);
+            removedAwards.Add(award.Id);
         }
+
+        EditingMedal = null;
+        RefreshMedals();
+        StatusMessage = removedAwards.Count == 0
+            ? $"Removed {removedTeams} unknown team ID(s) from awards."
+            : $"Removed {removedTeams} unknown team ID(s) from awards and deleted the now empty {string.Join(", ", removedAwards)}.";
+    }
+
+    private static string FormatTeamPreview(IReadOnlyList<string> teamIds)
+    {
+        return teamIds.Count == 0
+            ? "None"
+            : string.Join(", ", teamIds.Take(5)) + (teamIds.Count > 5 ? " ..." : string.Empty);
+    }
+
+    private void RefreshAwardConflicts()
+    {
+        AwardConflicts.Clear();
+        if (_contestState is not null)
+            foreach (var conflict in FindAwardConflicts(_contestState))
+                AwardConflicts.Add(conflict);
+
+        OnPropertyChanged(nameof(HasAwardConflicts));
     }
 
-    private string ApplyGroupFilterForPresentation(ContestState contestState)
+    private List<AwardConflictItem> FindAwardConflicts(ContestState contestState)
+    {
+        var conflicts = new List<AwardConflictItem>();
+        // Awards that still match the feed belong to the feed; everything else was made or changed here.
+        var localMedals = contestState.Awards.Values
+            .Where(x => !IsUnchangedFeedAward(x) && AwardTemplates.IsMedalAward(x.Id))
+            .ToList();
+        foreach (var feedAward in _feedAwards.Values.OrderBy(x => x.Order).ThenBy(x => x.Id, StringComparer.Ordinal))
+        {
+            // A feed award deleted here is a deliberate choice, not a conflict.
+            if (!contestState.Awards.TryGetValue(feedAward.Id, out var current)) continue;
+
+            if (!IsUnchangedFeedAward(current))
+            {
+                conflicts.Add(new AwardConflictItem(
+                    feedAward.Id,
+                    current.Id,
+                    "Same award ID with a different citation or teams",
+                    FormatAwardForConflict(feedAward),
+                    FormatAwardForConflict(current)));
+                continue;
+            }
+
+            if (!AwardTemplates.IsMedalAward(feedAward.Id)) continue;
+
+            foreach (var localMedal in localMedals)
+            {
+                var sharedTeamCount = feedAward.TeamIds.Intersect(localMedal.TeamIds, StringComparer.Ordinal).Count();
+                if (sharedTeamCount == 0) continue;
+
+                conflicts.Add(new AwardConflictItem(
+                    feedAward.Id,
+                    localMedal.Id,
+                    $"{sharedTeamCount} team(s) would hold both medals",
+                    FormatAwardForConflict(feedAward),
+                    FormatAwardForConflict(localMedal)));
+            }
+        }
+
+        return conflicts;
+    }
+
+    private bool IsUnchangedFeedAward(Award award)
+    {
+        return _feedAwards.TryGetValue(award.Id, out var feedAward) &&
+               string.Equals(feedAward.Citation, award.Citation, StringComparison.Ordinal) &&
+               feedAward.TeamIds.ToHashSet(StringComparer.Ordinal).SetEquals(award.TeamIds);
+    }
+
+    private static string FormatAwardForConflict(Award award)
+    {
+        return $"{award.Id}: {award.Citation} [{FormatTeamPreview(award.TeamIds)}]";
+    }
+
+    private void UseFeedAward(string? conflictKey)
+    {
+        if (!TryGetContestState(out var contestState, setStatusOnFailure: false)) return;
+
+        var conflict = AwardConflicts.FirstOrDefault(x => string.Equals(x.Key, conflictKey, StringComparison.Ordinal));
+        if (conflict is null) return;
+
+        PushAwardHistory(contestState);
+        ApplyFeedSide(contestState, conflict);
+        EditingMedal = null;
+        RefreshMedals();
+        StatusMessage = $"Kept feed award {conflict.FeedAwardId}.";
+    }
+
+    private void UseLocalAward(string? conflictKey)
+    {
+        if (!TryGetContestState(out var contestState, setStatusOnFailure: false)) return;
+
+        var conflict = AwardConflicts.FirstOrDefault(x => string.Equals(x.Key, conflictKey, StringComparison.Ordinal));
+        if (conflict is null) return;
+
+        PushAwardHistory(contestState);
+        ApplyLocalSide(contestState, conflict);
+        EditingMedal = null;
+        RefreshMedals();
+        StatusMessage = $"Kept local award {conflict.LocalAwardId}.";
+    }
+
+    private void UseAllFeedAwards()
+    {
+        if (!TryGetContestState(out var contestState, setStatusOnFailure: false) || AwardConflicts.Count == 0) return;
+
+        PushAwardHistory(contestState);
+        var count = AwardConflicts.Count;
+        foreach (var conflict in AwardConflicts.ToList()) ApplyFeedSide(contestState, conflict);
+
+        EditingMedal = null;
+        RefreshMedals();
+        StatusMessage = $"Resolved {count} conflict(s) in favor of the event feed.";
+    }
+
+    private void UseAllLocalAwards()
+    {
+        if (!TryGetContestState(out var contestState, setStatusOnFailure: false) || AwardConflicts.Count == 0) return;
+
+        PushAwardHistory(contestState);
+        var count = AwardConflicts.Count;
+        foreach (var conflict in AwardConflicts.ToList()) ApplyLocalSide(contestState, conflict);
+
+        EditingMedal = null;
+        RefreshMedals();
+        StatusMessage = $"Resolved {count} conflict(s) in favor of the local awards.";
+    }
+
+    /// <summary>
+    ///     Restores the feed version of a replaced award, or drops the local medal that overlaps a feed medal.
+    /// </summary>
+    private void ApplyFeedSide(ContestState contestState, AwardConflictItem conflict)
+    {
+        if (conflict.IsSameId)
+        {
+            if (!_feedAwards.TryGetValue(conflict.FeedAwardId, out var feedAward)) return;
+
+            var restored = CloneAward(feedAward);
+            restored.Order = GetMedalOrder(contestState, restored.Id);
+            contestState.Awards[restored.Id] = restored;
+            return;
+        }
+
+        contestState.Awards.Remove(conflict.LocalAwardId);
+    }
+
+    /// <summary>
+    ///     Keeps the local award and forgets the feed version, so the award is no longer compared against the feed.
+    /// </summary>
+    private void ApplyLocalSide(ContestState contestState, AwardConflictItem conflict)
+    {
+        if (!conflict.IsSameId) contestState.Awards.Remove(conflict.FeedAwardId);
+
+        _feedAwards.Remove(conflict.FeedAwardId);
+    }
+
+    private static Award CloneAward(Award award)
+    {
+        return new Award
+        {
+            Id = award.Id,
+            Citation = award.Citation,
+            TeamIds = award.TeamIds.ToList(),
+            ProblemId = award.ProblemId,
+            MediaPath = award.MediaPath,
+            BannerColor = award.BannerColor,
+            Title = award.Title,
+            Order = award.Order
+        };
+    }
+
+    /// <summary>
+    ///     Records the awards before a change so <see cref="UndoAwardsCommand" /> can bring them back.
+    /// </summary>
+    private void PushAwardHistory(ContestState contestState)
+    {
+        _awardUndoStack.Push(CaptureAwardSnapshot(contestState));
+        _awardRedoStack.Clear();
+        NotifyAwardHistoryChanged();
+    }
+
+    private void UndoAwards()
+    {
+        if (_awardUndoStack.Count == 0 || !TryGetContestState(out var contestState)) return;
+
+        _awardRedoStack.Push(CaptureAwardSnapshot(contestState));
+        RestoreAwardSnapshot(contestState, _awardUndoStack.Pop());
+        StatusMessage = "Undid the last award change.";
+    }
+
+    private void RedoAwards()
+    {
+        if (_awardRedoStack.Count == 0 || !TryGetContestState(out var contestState)) return;
+
+        _awardUndoStack.Push(CaptureAwardSnapshot(contestState));
+        RestoreAwardSnapshot(contestState, _awardRedoStack.Pop());
+        StatusMessage = "Redid the award change.";
+    }
+
+    private AwardSnapshot CaptureAwardSnapshot(ContestState contestState)
+    {
+        return new AwardSnapshot(CloneAwards(contestState.Awards), CloneAwards(_feedAwards));
+    }
+
+    private void RestoreAwardSnapshot(ContestState contestState, AwardSnapshot snapshot)
+    {
+        // Clone again so later edits do not reach into the snapshot if it is redone.
+        contestState.Awards = CloneAwards(snapshot.Awards);
+        _feedAwards = CloneAwards(snapshot.FeedAwards);
+        EditingMedal = null;
+        RefreshMedals();
+        NotifyAwardHistoryChanged();
+    }
+
+    private static Dictionary<string, Award> CloneAwards(Dictionary<string, Award> awards)
+    {
+        return awards.ToDictionary(x => x.Key, x => CloneAward(x.Value), StringComparer.Ordinal);
+    }
+
+    private void NotifyAwardHistoryChanged()
+    {
+        OnPropertyChanged(nameof(CanUndoAwards));
+        OnPropertyChanged(nameof(CanRedoAwards));
+        UndoAwardsCommand.NotifyCanExecuteChanged();
+        RedoAwardsCommand.NotifyCanExecuteChanged();
+    }
+
+    private sealed record AwardSnapshot(Dictionary<string, Award> Awards, Dictionary<string, Award> FeedAwards);
+
+    /// <summary>
+    ///     Copy of <paramref name="contestState" /> without the teams whose groups are neither eligible nor shown, with
+    ///     shown-only teams marked as unofficial so they stay on the board without taking medal places.
+    /// </summary>
+    private ContestState BuildFilteredPresentationState(ContestState contestState, out string filterMessage)
     {
         var selectedGroups = Groups
             .Where(x => x.IsSelected)
             .Select(x => x.Id)
             .ToHashSet(StringComparer.Ordinal);
 
+        var displayedGroups = Groups
+            .Where(x => x.IsSelected || x.IsDisplayed)
+            .Select(x => x.Id)
+            .ToHashSet(StringComparer.Ordinal);
+
         var allowedTeamIds = contestState.Teams.Values
-            .Where(team => team.GroupIds.Any(groupId => selectedGroups.Contains(groupId)))
+            .Where(team => team.GroupIds.Any(groupId => displayedGroups.Contains(groupId)))
             .Select(team => team.Id)
             .ToHashSet(StringComparer.Ordinal);
 
-        var originalTeamCount = contestState.Teams.Count;
-        var originalSubmissionCount = contestState.Submissions.Count;
-        var originalJudgementCount = contestState.Judgements.Count;
-
-        contestState.Teams = contestState.Teams
-            .Where(x => allowedTeamIds.Contains(x.Key))
-            .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal);
-
-        contestState.Accounts = contestState.Accounts
+        var submissions = contestState.Submissions
             .Where(x => allowedTeamIds.Contains(x.Value.TeamId))
             .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal);
 
-        contestState.Submissions = contestState.Submissions
-            .Where(x => allowedTeamIds.Contains(x.Value.TeamId))
-            .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal);
+        // Collections that the filter changes are rebuilt; the rest is shared, as the presentation only reads it.
+        var filtered = new ContestState
+        {
+            Contest = contestState.Contest,
+            JudgementTypes = contestState.JudgementTypes,
+            Groups = contestState.Groups,
+            Organizations = contestState.Organizations,
+            Teams = contestState.Teams
+                .Where(x => allowedTeamIds.Contains(x.Key))
+                .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal),
+            Accounts = contestState.Accounts
+                .Where(x => allowedTeamIds.Contains(x.Value.TeamId))
+                .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal),
+            Problems = contestState.Problems,
+            Submissions = submissions,
+            Judgements = contestState.Judgements
+                .Where(x => submissions.ContainsKey(x.Value.SubmissionId))
+                .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal),
+            Awards = contestState.Awards.ToDictionary(
+                x => x.Key,
+                x =>
+                {
+                    var medal = CloneAward(x.Value);
+                    medal.TeamIds = medal.TeamIds
+                        .Where(teamId => allowedTeamIds.Contains(teamId))
+                        .ToList();
+                    return medal;
+                },
+                StringComparer.Ordinal),
+            LeaderboardPreFreeze = contestState.LeaderboardPreFreeze
+                .Where(x => allowedTeamIds.Contains(x.TeamId))
+                .ToList(),
+            LeaderboardFinalized = contestState.LeaderboardFinalized,
+            ScoreboardHash = contestState.ScoreboardHash,
+            UnofficialTeamIds = contestState.Teams.Values
+                .Where(team => allowedTeamIds.Contains(team.Id) &&
+                               !team.GroupIds.Any(groupId => selectedGroups.Contains(groupId)))
+                .Select(team => team.Id)
+                .ToHashSet(StringComparer.Ordinal)
+        };
 
-        var allowedSubmissionIds = contestState.Submissions.Keys.ToHashSet(StringComparer.Ordinal);
+        filterMessage =
+            $"Filtered presentation set: teams {contestState.Teams.Count} -> {filtered.Teams.Count} ({filtered.UnofficialTeamIds.Count} unofficial), submissions {contestState.Submissions.Count} -> {filtered.Submissions.Count}, judgements {contestState.Judgements.Count} -> {filtered.Judgements.Count}";
+        return filtered;
+    }
 
-        contestState.Judgements = contestState.Judgements
-            .Where(x => allowedSubmissionIds.Contains(x.Value.SubmissionId))
-            .ToDictionary(k => k.Key, v => v.Value, StringComparer.Ordinal);
+    /// <summary>
+    ///     The contest as it would be presented, so exports match the board.
+    /// </summary>
+    private string ResolveCdpPath(string path)
+    {
+        if (Path.IsPathRooted(path) || string.IsNullOrWhiteSpace(_cdpPath)) return Path.GetFullPath(path);
 
-        contestState.LeaderboardPreFreeze = contestState.LeaderboardPreFreeze
-            .Where(x => allowedTeamIds.Contains(x.TeamId))
-            .ToList();
+        return Path.Combine(_cdpPath, path);
+    }
 
-        foreach (var medal in contestState.Awards.Values)
-            medal.TeamIds = medal.TeamIds
-                .Where(teamId => allowedTeamIds.Contains(teamId))
-                .ToList();
+    private bool TryBuildExportState(out ContestState exportState)
+    {
+        exportState = null!;
+        if (!TryGetContestState(out var contestState)) return false;
 
-        return
-            $"Filtered presentation set: teams {originalTeamCount} -> {contestState.Teams.Count}, submissions {originalSubmissionCount} -> {contestState.Submissions.Count}, judgements {originalJudgementCount} -> {contestState.Judgements.Count}";
+        exportState = BuildFilteredPresentationState(contestState, out _);
+        return true;
     }
 
     private bool TryGetContestState(
@@ -517,6 +1741,8 @@ public sealed class SetMedalStageViewModel : ViewModelBase
 
 public sealed class GroupSelectionItemViewModel : ObservableObject
 {
+    private string _championCitation = string.Empty;
+    private bool _isDisplayed = true;
     private bool _isSelected;
 
     public GroupSelectionItemViewModel(string id, string name, bool isSelected)
@@ -535,9 +1761,130 @@ public sealed class GroupSelectionItemViewModel : ObservableObject
         set => SetProperty(ref _isSelected, value);
     }
 
+    /// <summary>
+    ///     Whether teams of this group appear on the board even when the group is not eligible for medals.
+    /// </summary>
+    public bool IsDisplayed
+    {
+        get => _isDisplayed;
+        set => SetProperty(ref _isDisplayed, value);
+    }
+
+    /// <summary>
+    ///     Citation template for this category's champion award; empty uses the shared template.
+    /// </summary>
+    public string ChampionCitation
+    {
+        get => _championCitation;
+        set => SetProperty(ref _championCitation, value);
+    }
+
     public string DisplayLabel => $"{Name} ({Id})";
 }
 
+public sealed class TeamPickerItemViewModel : ObservableObject
+{
+    private bool _isChecked;
+
+    public TeamPickerItemViewModel(string teamId, string teamName, string organizationName, IReadOnlyList<string> groupIds)
+    {
+        TeamId = teamId;
+        TeamName = teamName;
+        OrganizationName = organizationName;
+        GroupIds = groupIds;
+    }
+
+    public string TeamId { get; }
+    public string TeamName { get; }
+    public string OrganizationName { get; }
+    public IReadOnlyList<string> GroupIds { get; }
+    public bool HasOrganizationName => !string.IsNullOrWhiteSpace(OrganizationName);
+    public string DisplayLabel => $"{TeamName} ({TeamId})";
+
+    public bool IsChecked
+    {
+        get => _isChecked;
+        set => SetProperty(ref _isChecked, value);
+    }
+
+    public bool Matches(string search)
+    {
+        return TeamId.Contains(search, StringComparison.OrdinalIgnoreCase) ||
+               TeamName.Contains(search, StringComparison.OrdinalIgnoreCase) ||
+               OrganizationName.Contains(search, StringComparison.OrdinalIgnoreCase);
+    }
+}
+
+public sealed record TeamPickerGroupOption(string? Id, string Name);
+
+public sealed class AwardEditorViewModel : ObservableObject
+{
+    private string _citation;
+    private string _newTeamId = string.Empty;
+    private string _title = string.Empty;
+    private string _bannerColor = string.Empty;
+    private string _mediaPath = string.Empty;
+
+    public AwardEditorViewModel(string id, string citation)
+    {
+        Id = id;
+        _citation = citation;
+    }
+
+    public string Id { get; }
+    public ObservableCollection<TeamPreviewItem> Teams { get; } = [];
+
+    public string Citation
+    {
+        get => _citation;
+        set => SetProperty(ref _citation, value);
+    }
+
+    public string NewTeamId
+    {
+        get => _newTeamId;
+        set => SetProperty(ref _newTeamId, value);
+    }
+
+    public string Title
+    {
+        get => _title;
+        set => SetProperty(ref _title, value);
+    }
+
+    public string BannerColor
+    {
+        get => _bannerColor;
+        set => SetProperty(ref _bannerColor, value);
+    }
+
+    public string MediaPath
+    {
+        get => _mediaPath;
+        set => SetProperty(ref _mediaPath, value);
+    }
+}
+
+public sealed class AwardConflictItem
+{
+    public AwardConflictItem(string feedAwardId, string localAwardId, string reason, string feedSummary, string localSummary)
+    {
+        FeedAwardId = feedAwardId;
+        LocalAwardId = localAwardId;
+        Reason = reason;
+        FeedSummary = feedSummary;
+        LocalSummary = localSummary;
+    }
+
+    public string FeedAwardId { get; }
+    public string LocalAwardId { get; }
+    public string Key => $"{FeedAwardId}\n{LocalAwardId}";
+    public bool IsSameId => string.Equals(FeedAwardId, LocalAwardId, StringComparison.Ordinal);
+    public string Reason { get; }
+    public string FeedSummary { get; }
+    public string LocalSummary { get; }
+}
+
 public sealed class TeamPreviewItem
 {
     public TeamPreviewItem(string teamId, string teamName)
@@ -551,6 +1898,46 @@ public sealed class TeamPreviewItem
     public string DisplayLabel => $"{TeamId} | {TeamName}";
 }
 
+public enum MedalKind
+{
+    None,
+    Gold,
+    Silver,
+    Bronze
+}
+
+public sealed class MedalStandingItem
+{
+    public MedalStandingItem(int rank, TeamStatus team, MedalKind medal, bool isEligible, bool isMedalBoundary)
+    {
+        Rank = rank;
+        TeamId = team.TeamId;
+        TeamName = team.TeamName;
+        Solved = team.TotalPoints;
+        Penalty = team.TotalPenalty;
+        IsEligible = isEligible;
+        (Background, BoundaryBrush) = medal switch
+        {
+            MedalKind.Gold => ("#33C9A227", "#C9A227"),
+            MedalKind.Silver => ("#338E9AA6", "#8E9AA6"),
+            MedalKind.Bronze => ("#33A0643B", "#A0643B"),
+            _ => ("#00000000", "#00000000")
+        };
+        BoundaryThickness = new Thickness(0, 0, 0, isMedalBoundary ? 3 : 0);
+    }
+
+    public int Rank { get; }
+    public string TeamId { get; }
+    public string TeamName { get; }
+    public int Solved { get; }
+    public long Penalty { get; }
+    public bool IsEligible { get; }
+    public string Background { get; }
+    public string BoundaryBrush { get; }
+    public Thickness BoundaryThickness { get; }
+    public double Opacity => IsEligible ? 1 : 0.45;
+}
+
 public sealed class MedalSummaryItem
 {
     public MedalSummaryItem(string id, string citation, int teamCount, string teamPreview)
@@ -0,0 +1,180 @@
+using CommunityToolkit.Mvvm.Input;
+using Pyrite.Services;
+using System;
+using System.Collections.Generic;
+using System.Collections.ObjectModel;
+using System.IO;
+using System.Linq;
+
+namespace Pyrite.ViewModels;
+
+/// <summary>
+///     Form over every <c>config.toml</c> key, grouped by TOML table. Values are edited as text, checked as they are
+///     typed, and only the changed keys are written back on save.
+/// </summary>
+public sealed class SettingsViewModel : ViewModelBase
+{
+    private readonly string _cdpPath;
+    private bool _hasSaved;
+    private string _statusText = string.Empty;
+
+    public SettingsViewModel(string cdpPath)
+    {
+        _cdpPath = cdpPath;
+        SaveCommand = new RelayCommand(Save);
+
+        Dictionary<string, string> values;
+        try
+        {
+            values = ConfigSettings.Read(cdpPath);
+        }
+        catch (Exception ex) when (ex is InvalidOperationException or IOException)
+        {
+            StatusText = $"{ex.Message} Fix the file by hand before using the settings editor.";
+            IsEditable = false;
+            return;
+        }
+
+        foreach (var group in ConfigSettings.All.GroupBy(definition => definition.Section))
+        {
+            var title = string.IsNullOrEmpty(group.Key) ? ConfigLoader.FileName : $"[{group.Key}]";
+            Sections.Add(new SettingsSectionViewModel(title,
+                group.Select(definition => new SettingItemViewModel(definition, values[definition.Path])).ToList()));
+        }
+
+        IsEditable = true;
+        StatusText = File.Exists(ConfigLoader.GetConfigPath(cdpPath))
+            ? ConfigLoader.GetConfigPath(cdpPath)
+            : $"{ConfigLoader.GetConfigPath(cdpPath)} does not exist yet and will be created on save.";
+    }
+
+    public ObservableCollection<SettingsSectionViewModel> Sections { get; } = [];
+
+    public RelayCommand SaveCommand { get; }
+
+    public bool IsEditable { get; }
+
+    /// <summary>
+    ///     Whether <c>config.toml</c> was written at least once, so the caller knows to reload it.
+    /// </summary>
+    public bool HasSaved
+    {
+        get => _hasSaved;
+        private set => SetProperty(ref _hasSaved, value);
+    }
+
+    public string StatusText
+    {
+        get => _statusText;
+        private set => SetProperty(ref _statusText, value);
+    }
+
+    private void Save()
+    {
+        if (!IsEditable) return;
+
+        var items = Sections.SelectMany(section => section.Items).ToList();
+        var invalid = items.Where(item => item.HasError).ToList();
+        if (invalid.Count > 0)
+        {
+            StatusText = $"Fix {invalid.Count} invalid setting(s) before saving, e.g. {invalid[0].Definition.Path}.";
+            return;
+        }
+
+        var changed = items.Where(item => item.IsChanged).ToList();
+        if (changed.Count == 0)
+        {
+            StatusText = "Nothing to save.";
+            return;
+        }
+
+        List<string> errors;
+        try
+        {
+            errors = ConfigSettings.Save(_cdpPath,
+                changed.ToDictionary(item => item.Definition.Path, item => item.Value ?? string.Empty));
+        }
+        catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
+        {
+            StatusText = $"Failed to write {ConfigLoader.FileName}: {ex.Message}";
+            return;
+        }
+
+        if (errors.Count > 0)
+        {
+            StatusText = $"Not saved: {string.Join(" ", errors)}";
+            return;
+        }
+
+        foreach (var item in changed) item.MarkSaved();
+        HasSaved = true;
+        StatusText = $"Saved {changed.Count} setting(s) to {ConfigLoader.GetConfigPath(_cdpPath)}.";
+    }
+}
+
+public sealed record SettingsSectionViewModel(string Title, IReadOnlyList<SettingItemViewModel> Items);
+
+public sealed class SettingItemViewModel : ViewModelBase
+{
+    private string? _error;
+    private string _savedValue;
+    private string? _value;
+
+    public SettingItemViewModel(SettingDefinition definition, string value)
+    {
+        Definition = definition;
+        _savedValue = value;
+        _value = value;
+
+        // The empty choice leaves the key out, i.e. uses the default.
+        if (definition.Kind == SettingKind.Boolean)
+            Choices = ["", "true", "false"];
+        else if (definition.Kind == SettingKind.Choice)
+            Choices = ["", ..(definition.Choices ?? [])];
+    }
+
+    public SettingDefinition Definition { get; }
+
+    public string Key => Definition.Key;
+
+    public IReadOnlyList<string> Choices { get; } = [];
+
+    public bool IsChoice => Choices.Count > 0;
+
+    public bool IsText => !IsChoice;
+
+    public bool IsMultiline => Definition.IsMultiline;
+
+    public string Watermark => string.IsNullOrEmpty(Definition.DefaultText)
+        ? IsMultiline ? "(none) - one entry per line" : "(none)"
+        : $"default: {Definition.DefaultText}";
+
+    public string? Value
+    {
+        get => _value;
+        set
+        {
+            if (!SetProperty(ref _value, value)) return;
+
+            Error = ConfigSettings.TryConvert(Definition, value ?? string.Empty, out _, out var error) ? null : error;
+        }
+    }
+
+    public string? Error
+    {
+        get => _error;
+        private set
+        {
+            if (SetProperty(ref _error, value)) OnPropertyChanged(nameof(HasError));
+        }
+    }
+
+    public bool HasError => Error is not null;
+
+    public bool IsChanged => !string.Equals((Value ?? string.Empty).Trim(), _savedValue.Trim(), StringComparison.Ordinal);
+
+    public void MarkSaved()
+    {
+        _savedValue = Value ?? string.Empty;
+    }
+}
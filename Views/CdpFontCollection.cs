@@ -0,0 +1,91 @@
+using Avalonia.Media;
+using Avalonia.Media.Fonts;
+using Avalonia.Platform;
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.IO;
+using System.Linq;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     TTF/OTF files from the CDP folder (<c>[fonts] files</c>), registered next to the embedded Noto collection.
+///     Each distinct file list gets its own <c>fonts:cdp-N</c> key, since a registered collection cannot be replaced.
+/// </summary>
+internal sealed class CdpFontCollection : FontCollectionBase
+{
+    private static readonly Dictionary<string, CdpFontCollection> Registered = new(StringComparer.Ordinal);
+
+    private readonly IReadOnlyList<string> _paths;
+    private readonly List<string> _familyNames = [];
+
+    private CdpFontCollection(Uri key, IReadOnlyList<string> paths)
+    {
+        Key = key;
+        _paths = paths;
+    }
+
+    public override Uri Key { get; }
+
+    /// <summary>
+    ///     Family names found in the files, in file order.
+    /// </summary>
+    public IReadOnlyList<string> FamilyNames => _familyNames;
+
+    public static CdpFontCollection Register(IReadOnlyList<string> paths)
+    {
+        var cacheKey = string.Join('\n', paths);
+        if (Registered.TryGetValue(cacheKey, out var collection))
+        {
+            return collection;
+        }
+
+        collection = new CdpFontCollection(new Uri($"fonts:cdp-{Registered.Count}", UriKind.Absolute), paths);
+        FontManager.Current.AddFontCollection(collection);
+        Registered[cacheKey] = collection;
+        return collection;
+    }
+
+    /// <summary>
+    ///     The family as a <see cref="FontFamily" /> of this collection, falling back to the embedded Noto for
+    ///     characters the custom font lacks (e.g. CJK team names).
+    /// </summary>
+    public FontFamily GetFontFamily(string familyName)
+    {
+        return new FontFamily($"{Key}#{familyName}, fonts:app#Noto Sans CJK SC");
+    }
+
+    public override void Initialize(IFontManagerImpl fontManager)
+    {
+        foreach (var path in _paths)
+        {
+            try
+            {
+                using var stream = new MemoryStream(File.ReadAllBytes(path));
+                if (!fontManager.TryCreateGlyphTypeface(stream, FontSimulations.None, out var glyphTypeface) ||
+                    !TryAddGlyphTypeface(glyphTypeface))
+                {
+                    Trace.WriteLine($"[CdpFontCollection] Unreadable font: {path}");
+                    continue;
+                }
+
+                if (!_familyNames.Contains(glyphTypeface.FamilyName, StringComparer.OrdinalIgnoreCase))
+                {
+                    _familyNames.Add(glyphTypeface.FamilyName);
+                }
+
+                Trace.WriteLine($"[CdpFontCollection] Loaded font: {glyphTypeface.FamilyName} ({path})");
+            }
+            catch (Exception ex) when (ex is IOException or UnauthorizedAccessException)
+            {
+                Trace.WriteLine($"[CdpFontCollection] Failed to load font {path}: {ex.Message}");
+            }
+        }
+
+        if (_familyNames.Count == 0 && _paths.Any())
+        {
+            Trace.WriteLine("[CdpFontCollection] No usable fonts; the presentation keeps the default font.");
+        }
+    }
+}
@@ -0,0 +1,123 @@
+using Avalonia;
+using Avalonia.Media;
+using Avalonia.Media.Imaging;
+using Pyrite.Models;
+using Pyrite.Services;
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+using System.Runtime.InteropServices;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     Draws the configured text fields onto the certificate template and saves each certificate as a one-page PDF.
+///     Rendering happens at the template's pixel size, so field coordinates are template pixels. A PDF template's
+///     first page is rendered at the configured dpi first.
+/// </summary>
+internal static class CertificateRenderer
+{
+    public static void WriteAll(
+        string templatePath,
+        CertificateConfig config,
+        IReadOnlyList<CertificateEntry> entries,
+        string outputDirectory)
+    {
+        using var template = LoadTemplate(templatePath, config);
+        var size = template.PixelSize;
+        var bounds = new Rect(0, 0, size.Width, size.Height);
+        Directory.CreateDirectory(outputDirectory);
+
+        using var target = new RenderTargetBitmap(size);
+        var pixels = new byte[size.Width * size.Height * 4];
+        var rgb = new byte[size.Width * size.Height * 3];
+        foreach (var entry in entries)
+        {
+            using (var context = target.CreateDrawingContext())
+            {
+                // Paper white behind templates with transparent areas.
+                context.FillRectangle(Brushes.White, bounds);
+                context.DrawImage(template, bounds);
+                DrawField(context, config.TeamName, entry.TeamName);
+                DrawField(context, config.OrganizationName, entry.OrganizationName);
+                DrawField(context, config.Citation, entry.Citation);
+                DrawField(context, config.Rank, entry.Rank?.ToString(CultureInfo.InvariantCulture));
+            }
+
+            CopyRgb(target, size, pixels, rgb);
+            PdfImageDocument.Write(Path.Combine(outputDirectory, entry.FileName), size.Width, size.Height, rgb, config.Dpi);
+        }
+    }
+
+    private static Bitmap LoadTemplate(string templatePath, CertificateConfig config)
+    {
+        if (!string.Equals(Path.GetExtension(templatePath), ".pdf", StringComparison.OrdinalIgnoreCase))
+        {
+            return new Bitmap(templatePath);
+        }
+
+        using var stream = new MemoryStream(ImageFiles.RenderPdfPage(templatePath, config.PdftoppmPath, config.Dpi), false);
+        return new Bitmap(stream);
+    }
+
+    private static void DrawField(DrawingContext context, CertificateFieldConfig? field, string? text)
+    {
+        if (field is null || string.IsNullOrWhiteSpace(text))
+        {
+            return;
+        }
+
+        var typeface = new Typeface(
+            string.IsNullOrWhiteSpace(field.FontFamily) ? FontFamily.Default : new FontFamily(field.FontFamily),
+            FontStyle.Normal,
+            field.Bold ? FontWeight.Bold : FontWeight.Normal);
+        var formatted = new FormattedText(
+            text,
+            CultureInfo.CurrentCulture,
+            FlowDirection.LeftToRight,
+            typeface,
+            field.FontSize,
+            new SolidColorBrush(Color.Parse(field.Color)));
+        if (field.MaxWidth is { } maxWidth)
+        {
+            formatted.MaxTextWidth = maxWidth;
+        }
+
+        formatted.TextAlignment = field.Align switch
+        {
+            CertificateTextAlign.Left => TextAlignment.Left,
+            CertificateTextAlign.Right => TextAlignment.Right,
+            _ => TextAlignment.Center
+        };
+
+        var width = field.MaxWidth ?? formatted.WidthIncludingTrailingWhitespace;
+        var left = field.Align switch
+        {
+            CertificateTextAlign.Left => field.X,
+            CertificateTextAlign.Right => field.X - width,
+            _ => field.X - width / 2
+        };
+        context.DrawText(formatted, new Point(left, field.Y));
+    }
+
+    private static void CopyRgb(RenderTargetBitmap bitmap, PixelSize size, byte[] bgra, byte[] rgb)
+    {
+        var handle = GCHandle.Alloc(bgra, GCHandleType.Pinned);
+        try
+        {
+            bitmap.CopyPixels(new PixelRect(size), handle.AddrOfPinnedObject(), bgra.Length, size.Width * 4);
+        }
+        finally
+        {
+            handle.Free();
+        }
+
+        for (int source = 0, destination = 0; source < bgra.Length; source += 4, destination += 3)
+        {
+            rgb[destination] = bgra[source + 2];
+            rgb[destination + 1] = bgra[source + 1];
+            rgb[destination + 2] = bgra[source];
+        }
+    }
+}
@@ -0,0 +1,171 @@
+using Avalonia;
+using Avalonia.Controls;
+using Avalonia.Media;
+using Avalonia.Media.Immutable;
+using Avalonia.Threading;
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     Lightweight confetti particle layer. Particles are drawn directly in <see cref="Render" /> so no visual tree
+///     churn happens per frame.
+/// </summary>
+public sealed class ConfettiCanvas : Control
+{
+    private const int ParticleCount = 220;
+    private const double GravityPerSecond = 120;
+
+    private static readonly IImmutableSolidColorBrush[] ParticleBrushes =
+    [
+        new ImmutableSolidColorBrush(Color.Parse("#FFD700")),
+        new ImmutableSolidColorBrush(Color.Parse("#FF4D6D")),
+        new ImmutableSolidColorBrush(Color.Parse("#4CC9F0")),
+        new ImmutableSolidColorBrush(Color.Parse("#80ED99")),
+        new ImmutableSolidColorBrush(Color.Parse("#F8F9FA")),
+        new ImmutableSolidColorBrush(Color.Parse("#B388FF"))
+    ];
+
+    public static readonly StyledProperty<bool> IsRunningProperty =
+        AvaloniaProperty.Register<ConfettiCanvas, bool>(nameof(IsRunning));
+
+    private readonly List<Particle> _particles = [];
+    private readonly Random _random = new();
+    private DispatcherTimer? _timer;
+    private long _lastTickTimestamp;
+
+    static ConfettiCanvas()
+    {
+        IsHitTestVisibleProperty.OverrideDefaultValue<ConfettiCanvas>(false);
+    }
+
+    public bool IsRunning
+    {
+        get => GetValue(IsRunningProperty);
+        set => SetValue(IsRunningProperty, value);
+    }
+
+    protected override void OnPropertyChanged(AvaloniaPropertyChangedEventArgs change)
+    {
+        base.OnPropertyChanged(change);
+        if (change.Property == IsRunningProperty)
+        {
+            if (IsRunning)
+            {
+                Start();
+            }
+            else
+            {
+                Stop();
+            }
+        }
+    }
+
+    protected override void OnDetachedFromVisualTree(VisualTreeAttachmentEventArgs e)
+    {
+        base.OnDetachedFromVisualTree(e);
+        Stop();
+    }
+
+    public override void Render(DrawingContext context)
+    {
+        foreach (var particle in _particles)
+        {
+            var transform = Matrix.CreateRotation(particle.Angle) * Matrix.CreateTranslation(particle.X, particle.Y);
+            using (context.PushTransform(transform))
+            {
+                context.FillRectangle(
+                    particle.Brush,
+                    new Rect(-particle.Width / 2, -particle.Height / 2, particle.Width, particle.Height));
+            }
+        }
+    }
+
+    private void Start()
+    {
+        _particles.Clear();
+        _lastTickTimestamp = StageClock.GetTimestamp();
+        _timer ??= new DispatcherTimer(TimeSpan.FromMilliseconds(16), DispatcherPriority.Render, OnTick);
+        _timer.Start();
+        StageClock.Advanced -= OnTick;
+        StageClock.Advanced += OnTick;
+    }
+
+    private void Stop()
+    {
+        _timer?.Stop();
+        StageClock.Advanced -= OnTick;
+        _particles.Clear();
+        InvalidateVisual();
+    }
+
+    private void OnTick(object? sender, EventArgs e)
+    {
+        var now = StageClock.GetTimestamp();
+        var deltaSeconds = Math.Min(0.1, (now - _lastTickTimestamp) / (double)Stopwatch.Frequency);
+        _lastTickTimestamp = now;
+
+        var height = Bounds.Height;
+        if (height <= 0 || Bounds.Width <= 0)
+        {
+            return;
+        }
+
+        if (_particles.Count == 0)
+        {
+            // Spawn lazily: the overlay may not have been laid out yet when the celebration starts.
+            // Spread the first wave above the top edge so it does not appear all at once.
+            for (var i = 0; i < ParticleCount; i++)
+            {
+                _particles.Add(CreateParticle(-_random.NextDouble() * height));
+            }
+        }
+
+        for (var i = 0; i < _particles.Count; i++)
+        {
+            var particle = _particles[i];
+            particle.VelocityY += GravityPerSecond * deltaSeconds;
+            particle.X += (particle.VelocityX + Math.Sin(particle.Angle) * 20) * deltaSeconds;
+            particle.Y += particle.VelocityY * deltaSeconds;
+            particle.Angle += particle.Spin * deltaSeconds;
+
+            if (particle.Y - particle.Height > height)
+            {
+                _particles[i] = CreateParticle(-particle.Height);
+            }
+        }
+
+        InvalidateVisual();
+    }
+
+    private Particle CreateParticle(double y)
+    {
+        return new Particle
+        {
+            X = _random.NextDouble() * Bounds.Width,
+            Y = y,
+            VelocityX = (_random.NextDouble() - 0.5) * 80,
+            VelocityY = 60 + _random.NextDouble() * 120,
+            Angle = _random.NextDouble() * Math.PI * 2,
+            Spin = (_random.NextDouble() - 0.5) * 8,
+            Width = 6 + _random.NextDouble() * 8,
+            Height = 10 + _random.NextDouble() * 10,
+            Brush = ParticleBrushes[_random.Next(ParticleBrushes.Length)]
+        };
+    }
+
+    private sealed class Particle
+    {
+        public double X { get; set; }
+        public double Y { get; set; }
+        public double VelocityX { get; set; }
+        public double VelocityY { get; set; }
+        public double Angle { get; set; }
+        public double Spin { get; set; }
+        public double Width { get; init; }
+        public double Height { get; init; }
+        public required IImmutableSolidColorBrush Brush { get; init; }
+    }
+}
@@ -0,0 +1,262 @@
+using Avalonia;
+using Avalonia.Layout;
+using Avalonia.Media.Imaging;
+using Avalonia.Platform;
+using Avalonia.Threading;
+using Pyrite.Models;
+using System;
+using System.Collections.Concurrent;
+using System.Diagnostics;
+using System.Globalization;
+using System.IO;
+using System.Runtime.InteropServices;
+using System.Threading.Tasks;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     Records a visual at a fixed frame rate, either as numbered PNG files or piped as raw BGRA frames into ffmpeg.
+///     While recording, the <see cref="StageClock" /> is held and advanced by one frame per rendered frame, so the
+///     video shows the animations at their configured speed even when rendering or encoding cannot keep up; the
+///     stage then runs slower on screen instead. Encoding and writing run on a background thread.
+/// </summary>
+internal sealed class FrameRecorder : IDisposable
+{
+    // Frames rendered but not yet written; when the writer falls this far behind, the stage waits for it.
+    private const int MaxQueuedFrames = 8;
+
+    private const int FfmpegExitTimeoutMilliseconds = 10_000;
+
+    private readonly Visual _source;
+    private readonly RenderTargetBitmap _bitmap;
+    private readonly PixelSize _pixelSize;
+    private readonly Vector _dpi;
+    private readonly int _framesPerSecond;
+    private readonly string _outputPath;
+    private readonly DispatcherTimer _timer;
+    private readonly Process? _ffmpeg;
+    private readonly BlockingCollection<byte[]> _frames = new(MaxQueuedFrames);
+    private readonly ConcurrentBag<byte[]> _freeBuffers = [];
+    private readonly Stopwatch _elapsed = new();
+    private readonly Task _writer;
+    private long _framesRendered;
+    private long _framesWritten;
+
+    private FrameRecorder(Visual source, PixelSize pixelSize, double scaling, PresentationConfig config)
+    {
+        _source = source;
+        _pixelSize = pixelSize;
+        _dpi = new Vector(96 * scaling, 96 * scaling);
+        _framesPerSecond = config.RecordingFramesPerSecond;
+        _bitmap = new RenderTargetBitmap(pixelSize, _dpi);
+
+        var recordingName = $"pyrite-{DateTime.Now:yyyyMMdd-HHmmss}";
+        var rootDirectory = Path.GetFullPath(config.RecordingDirectory);
+        Directory.CreateDirectory(rootDirectory);
+        if (string.IsNullOrWhiteSpace(config.RecordingFfmpegPath))
+        {
+            _outputPath = Path.Combine(rootDirectory, recordingName);
+            Directory.CreateDirectory(_outputPath);
+        }
+        else
+        {
+            _outputPath = Path.Combine(rootDirectory, $"{recordingName}.mp4");
+            _ffmpeg = StartFfmpeg(config.RecordingFfmpegPath, _outputPath, pixelSize, _framesPerSecond);
+        }
+
+        _writer = Task.Run(WriteFrames);
+        _timer = new DispatcherTimer(
+            TimeSpan.FromSeconds(1.0 / _framesPerSecond),
+            DispatcherPriority.Render,
+            OnTick);
+    }
+
+    public string OutputPath => _outputPath;
+
+    public static FrameRecorder? TryStart(Visual source, double scaling, PresentationConfig config)
+    {
+        var bounds = source.Bounds;
+        if (bounds.Width <= 0 || bounds.Height <= 0)
+        {
+            return null;
+        }
+
+        // H.264 needs even dimensions.
+        var pixelSize = new PixelSize(
+            Math.Max(2, (int)Math.Ceiling(bounds.Width * scaling) & ~1),
+            Math.Max(2, (int)Math.Ceiling(bounds.Height * scaling) & ~1));
+        try
+        {
+            var recorder = new FrameRecorder(source, pixelSize, scaling, config);
+            StageClock.Hold();
+            recorder._elapsed.Start();
+            recorder._timer.Start();
+            Trace.WriteLine(
+                $"[FrameRecorder] Recording {pixelSize.Width}x{pixelSize.Height}@{recorder._framesPerSecond} to {recorder.OutputPath}.");
+            return recorder;
+        }
+        catch (Exception ex)
+        {
+            Trace.WriteLine($"[FrameRecorder] Failed to start recording: {ex.Message}");
+            return null;
+        }
+    }
+
+    /// <summary>
+    ///     Stops rendering and releases the stage clock at once; the queued frames are written and ffmpeg is finished
+    ///     in the background.
+    /// </summary>
+    public void Dispose()
+    {
+        _timer.Stop();
+        StageClock.Release();
+        _frames.CompleteAdding();
+        _bitmap.Dispose();
+        _writer.ContinueWith(_ => FinishOutput(), TaskScheduler.Default);
+    }
+
+    private void OnTick(object? sender, EventArgs e)
+    {
+        if (_writer.IsCompleted)
+        {
+            Trace.WriteLine($"[FrameRecorder] Writer stopped, no more frames are recorded: {OutputPath}");
+            _timer.Stop();
+            StageClock.Release();
+            return;
+        }
+
+        // Never run ahead of real time, and wait for the writer instead of dropping or repeating frames.
+        var dueFrames = (long)Math.Floor(_elapsed.Elapsed.TotalSeconds * _framesPerSecond) + 1;
+        if (_framesRendered >= dueFrames || _frames.Count >= MaxQueuedFrames)
+        {
+            return;
+        }
+
+        try
+        {
+            if (_framesRendered > 0)
+            {
+                StageClock.Advance(1.0 / _framesPerSecond);
+            }
+
+            (_source as Layoutable)?.UpdateLayout();
+            _bitmap.Render(_source);
+            if (!_freeBuffers.TryTake(out var buffer))
+            {
+                buffer = new byte[_pixelSize.Width * _pixelSize.Height * 4];
+            }
+
+            var handle = GCHandle.Alloc(buffer, GCHandleType.Pinned);
+            try
+            {
+                _bitmap.CopyPixels(new PixelRect(_pixelSize), handle.AddrOfPinnedObject(), buffer.Length,
+                    _pixelSize.Width * 4);
+            }
+            finally
+            {
+                handle.Free();
+            }
+
+            _frames.Add(buffer);
+            _framesRendered += 1;
+        }
+        catch (Exception ex)
+        {
+            Trace.WriteLine($"[FrameRecorder] Frame {_framesRendered} failed, stopping: {ex.Message}");
+            _timer.Stop();
+            StageClock.Release();
+        }
+    }
+
+    private void WriteFrames()
+    {
+        try
+        {
+            foreach (var buffer in _frames.GetConsumingEnumerable())
+            {
+                _framesWritten += 1;
+                if (_ffmpeg is not null)
+                {
+                    _ffmpeg.StandardInput.BaseStream.Write(buffer);
+                }
+                else
+                {
+                    SavePng(buffer, Path.Combine(_outputPath, $"frame_{_framesWritten:D6}.png"));
+                }
+
+                _freeBuffers.Add(buffer);
+            }
+        }
+        catch (Exception ex)
+        {
+            Trace.WriteLine($"[FrameRecorder] Frame {_framesWritten} could not be written, stopping: {ex.Message}");
+        }
+    }
+
+    private void SavePng(byte[] buffer, string path)
+    {
+        var handle = GCHandle.Alloc(buffer, GCHandleType.Pinned);
+        try
+        {
+            using var frame = new Bitmap(PixelFormat.Bgra8888, AlphaFormat.Premul, handle.AddrOfPinnedObject(),
+                _pixelSize, _dpi, _pixelSize.Width * 4);
+            frame.Save(path);
+        }
+        finally
+        {
+            handle.Free();
+        }
+    }
+
+    private void FinishOutput()
+    {
+        if (_ffmpeg is not null)
+        {
+            try
+            {
+                _ffmpeg.StandardInput.Close();
+                if (!_ffmpeg.WaitForExit(FfmpegExitTimeoutMilliseconds))
+                {
+                    _ffmpeg.Kill(true);
+                    Trace.WriteLine($"[FrameRecorder] ffmpeg did not finish in time and was stopped: {OutputPath}");
+                }
+            }
+            catch (Exception ex)
+            {
+                Trace.WriteLine($"[FrameRecorder] Failed to finish ffmpeg: {ex.Message}");
+            }
+
+            _ffmpeg.Dispose();
+        }
+
+        _frames.Dispose();
+        Trace.WriteLine($"[FrameRecorder] Stopped after {_framesWritten} frames: {OutputPath}");
+    }
+
+    private static Process StartFfmpeg(string ffmpegPath, string outputPath, PixelSize pixelSize, int framesPerSecond)
+    {
+        var startInfo = new ProcessStartInfo(ffmpegPath)
+        {
+            UseShellExecute = false,
+            CreateNoWindow = true,
+            RedirectStandardInput = true,
+            RedirectStandardOutput = false,
+            RedirectStandardError = false
+        };
+        foreach (var argument in new[]
+                 {
+                     "-y", "-loglevel", "error",
+                     "-f", "rawvideo", "-pix_fmt", "bgra",
+                     "-s", $"{pixelSize.Width}x{pixelSize.Height}",
+                     "-r", framesPerSecond.ToString(CultureInfo.InvariantCulture),
+                     "-i", "-",
+                     "-c:v", "libx264", "-pix_fmt", "yuv420p",
+                     outputPath
+                 })
+        {
+            startInfo.ArgumentList.Add(argument);
+        }
+
+        return Process.Start(startInfo) ?? throw new InvalidOperationException($"Could not start {ffmpegPath}.");
+    }
+}
@@ -1,4 +1,5 @@
 using Avalonia.Controls;
+using Avalonia.Input;
 using Avalonia.Interactivity;
 using Avalonia.Platform.Storage;
 using Pyrite.ViewModels;
@@ -12,6 +13,33 @@ public partial class LoadDataStageView : UserControl
     public LoadDataStageView()
     {
         InitializeComponent();
+        AddHandler(DragDrop.DragOverEvent, OnDragOver);
+        AddHandler(DragDrop.DropEvent, OnDrop);
+    }
+
+    private void OnDragOver(object? sender, DragEventArgs e)
+    {
+        var canDrop = DataContext is LoadDataStageViewModel { IsNotParsing: true } && e.Data.Contains(DataFormats.Files);
+        e.DragEffects = canDrop ? DragDropEffects.Copy : DragDropEffects.None;
+        e.Handled = true;
+    }
+
+    private async void OnDrop(object? sender, DragEventArgs e)
+    {
+        if (DataContext is not LoadDataStageViewModel { IsNotParsing: true } viewModel) return;
+
+        var localPath = e.Data.GetFiles()?.FirstOrDefault()?.TryGetLocalPath();
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        e.Handled = true;
+        try
+        {
+            await viewModel.SelectDroppedPathAsync(localPath);
+        }
+        catch (Exception)
+        {
+            // Errors are surfaced through view model status collections.
+        }
     }
 
     private async void OnSelectFolderClick(object? sender, RoutedEventArgs e)
@@ -42,4 +70,57 @@ public partial class LoadDataStageView : UserControl
             // Errors are surfaced through view model status collections.
         }
     }
+
+    private async void OnRecentFolderClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not LoadDataStageViewModel { IsNotParsing: true } viewModel) return;
+        if (sender is not Button { DataContext: string folderPath }) return;
+
+        try
+        {
+            // Recent entries may be zips, which are extracted again.
+            await viewModel.SelectDroppedPathAsync(folderPath);
+        }
+        catch (Exception)
+        {
+            // Errors are surfaced through view model status collections.
+        }
+    }
+
+    private async void OnSettingsClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not LoadDataStageViewModel { CanEditSettings: true, CdpPath: { } cdpPath } viewModel) return;
+        if (TopLevel.GetTopLevel(this) is not Window owner) return;
+
+        var settings = new SettingsViewModel(cdpPath);
+        await new SettingsWindow { DataContext = settings }.ShowDialog(owner);
+        if (!settings.HasSaved) return;
+
+        // Pick up the saved config the same way as a fresh folder selection.
+        try
+        {
+            await viewModel.SelectCdpFolderAsync(cdpPath);
+        }
+        catch (Exception)
+        {
+            // Errors are surfaced through view model status collections.
+        }
+    }
+
+    private void OnClearImageCacheClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not LoadDataStageViewModel viewModel) return;
+
+        viewModel.ClearImageCache();
+    }
+
+    private async void OnCopyScoreboardHashClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not LoadDataStageViewModel { HasScoreboardHash: true } viewModel) return;
+
+        var clipboard = TopLevel.GetTopLevel(this)?.Clipboard;
+        if (clipboard is null) return;
+
+        await clipboard.SetTextAsync(viewModel.ScoreboardHash);
+    }
 }
@@ -0,0 +1,157 @@
+using Avalonia;
+using Avalonia.Controls;
+using Avalonia.Threading;
+using System;
+using System.Diagnostics;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     Clips its child to the available width and, when the child is wider, scrolls it back and forth instead of
+///     trimming it. With <see cref="IsMarqueeEnabled" /> off it behaves like a plain <see cref="Decorator" />.
+/// </summary>
+public sealed class MarqueeDecorator : Decorator
+{
+    public static readonly StyledProperty<bool> IsMarqueeEnabledProperty =
+        AvaloniaProperty.Register<MarqueeDecorator, bool>(nameof(IsMarqueeEnabled), true);
+
+    public static readonly StyledProperty<double> SpeedProperty =
+        AvaloniaProperty.Register<MarqueeDecorator, double>(nameof(Speed), 40);
+
+    public static readonly StyledProperty<double> EdgePauseSecondsProperty =
+        AvaloniaProperty.Register<MarqueeDecorator, double>(nameof(EdgePauseSeconds), 1.5);
+
+    private DispatcherTimer? _timer;
+    private long _startTimestamp;
+    private double _overflow;
+    private double _offset;
+
+    static MarqueeDecorator()
+    {
+        ClipToBoundsProperty.OverrideDefaultValue<MarqueeDecorator>(true);
+        AffectsMeasure<MarqueeDecorator>(IsMarqueeEnabledProperty);
+    }
+
+    public bool IsMarqueeEnabled
+    {
+        get => GetValue(IsMarqueeEnabledProperty);
+        set => SetValue(IsMarqueeEnabledProperty, value);
+    }
+
+    /// <summary>
+    ///     Scroll speed in pixels per second.
+    /// </summary>
+    public double Speed
+    {
+        get => GetValue(SpeedProperty);
+        set => SetValue(SpeedProperty, value);
+    }
+
+    public double EdgePauseSeconds
+    {
+        get => GetValue(EdgePauseSecondsProperty);
+        set => SetValue(EdgePauseSecondsProperty, value);
+    }
+
+    protected override Size MeasureOverride(Size availableSize)
+    {
+        if (!IsMarqueeEnabled || Child is null)
+        {
+            return base.MeasureOverride(availableSize);
+        }
+
+        Child.Measure(new Size(double.PositiveInfinity, availableSize.Height));
+        return new Size(Math.Min(Child.DesiredSize.Width, availableSize.Width), Child.DesiredSize.Height);
+    }
+
+    protected override Size ArrangeOverride(Size finalSize)
+    {
+        if (!IsMarqueeEnabled || Child is null)
+        {
+            UpdateOverflow(0);
+            return base.ArrangeOverride(finalSize);
+        }
+
+        var childWidth = Math.Max(Child.DesiredSize.Width, finalSize.Width);
+        UpdateOverflow(childWidth - finalSize.Width);
+        Child.Arrange(new Rect(-_offset, 0, childWidth, finalSize.Height));
+        return finalSize;
+    }
+
+    protected override void OnAttachedToVisualTree(VisualTreeAttachmentEventArgs e)
+    {
+        base.OnAttachedToVisualTree(e);
+        InvalidateArrange();
+    }
+
+    protected override void OnDetachedFromVisualTree(VisualTreeAttachmentEventArgs e)
+    {
+        base.OnDetachedFromVisualTree(e);
+        _overflow = 0;
+        _offset = 0;
+        _timer?.Stop();
+        StageClock.Advanced -= OnTick;
+    }
+
+    private void UpdateOverflow(double overflow)
+    {
+        // Sub-pixel overflow comes from layout rounding and is not worth scrolling.
+        overflow = overflow > 1 ? overflow : 0;
+        if (Math.Abs(overflow - _overflow) < 0.5)
+        {
+            return;
+        }
+
+        _overflow = overflow;
+        _offset = 0;
+        if (_overflow <= 0)
+        {
+            _timer?.Stop();
+            StageClock.Advanced -= OnTick;
+            return;
+        }
+
+        _startTimestamp = StageClock.GetTimestamp();
+        _timer ??= new DispatcherTimer(TimeSpan.FromMilliseconds(16), DispatcherPriority.Render, OnTick);
+        _timer.Start();
+        StageClock.Advanced -= OnTick;
+        StageClock.Advanced += OnTick;
+    }
+
+    private void OnTick(object? sender, EventArgs e)
+    {
+        var speed = Math.Max(1, Speed);
+        var pause = Math.Max(0, EdgePauseSeconds);
+        var scrollSeconds = _overflow / speed;
+        // Pause at the start, scroll to the end, pause, scroll back.
+        var cycleSeconds = (pause + scrollSeconds) * 2;
+        var elapsed = (StageClock.GetTimestamp() - _startTimestamp) / (double)Stopwatch.Frequency % cycleSeconds;
+
+        double offset;
+        if (elapsed < pause)
+        {
+            offset = 0;
+        }
+        else if (elapsed < pause + scrollSeconds)
+        {
+            offset = (elapsed - pause) * speed;
+        }
+        else if (elapsed < pause * 2 + scrollSeconds)
+        {
+            offset = _overflow;
+        }
+        else
+        {
+            offset = _overflow - (elapsed - pause * 2 - scrollSeconds) * speed;
+        }
+
+        offset = Math.Clamp(offset, 0, _overflow);
+        if (Math.Abs(offset - _offset) < 0.01)
+        {
+            return;
+        }
+
+        _offset = offset;
+        InvalidateArrange();
+    }
+}
@@ -0,0 +1,61 @@
+using Avalonia;
+using Avalonia.Controls;
+using Avalonia.Input;
+using Avalonia.Media;
+using Avalonia.Threading;
+using Pyrite.Models;
+using System;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     Read-only copy of the stage, painted from the source visual every frame, so the projector can show the
+///     presentation while the operator keeps the interactive window on their own screen.
+/// </summary>
+public partial class MirrorWindow : Window
+{
+    private readonly DispatcherTimer _refreshTimer;
+    private readonly PresentationKeyMap _keyMap = new(new KeysConfig());
+
+    public MirrorWindow()
+    {
+        InitializeComponent();
+        _refreshTimer = new DispatcherTimer(
+            TimeSpan.FromMilliseconds(16),
+            DispatcherPriority.Render,
+            (_, _) => MirrorSurface.InvalidateVisual());
+        KeyDown += OnKeyDown;
+        Opened += (_, _) => _refreshTimer.Start();
+        Closed += (_, _) => _refreshTimer.Stop();
+    }
+
+    public MirrorWindow(Visual source, KeysConfig keys) : this()
+    {
+        _keyMap = new PresentationKeyMap(keys);
+        MirrorSurface.Fill = new VisualBrush(source)
+        {
+            Stretch = Stretch.Uniform,
+            AlignmentX = AlignmentX.Center,
+            AlignmentY = AlignmentY.Center
+        };
+    }
+
+    private void OnKeyDown(object? sender, KeyEventArgs e)
+    {
+        switch (_keyMap.Resolve(e.Key, e.KeyModifiers))
+        {
+            case KeyAction.Fullscreen:
+                WindowPlacement.ToggleFullscreen(this);
+                e.Handled = true;
+                break;
+            case KeyAction.Borderless:
+                WindowPlacement.ToggleBorderless(this);
+                e.Handled = true;
+                break;
+            case KeyAction.NextMonitor:
+                WindowPlacement.MoveToNextScreen(this);
+                e.Handled = true;
+                break;
+        }
+    }
+}
@@ -0,0 +1,58 @@
+using Avalonia.Controls;
+using Avalonia.Input;
+using Pyrite.Models;
+using Pyrite.ViewModels;
+using System;
+
+namespace Pyrite.Views;
+
+public partial class OperatorConsoleWindow : Window
+{
+    private PresentationKeyMap? _keyMap;
+
+    public OperatorConsoleWindow()
+    {
+        InitializeComponent();
+        KeyDown += OnKeyDown;
+        Closed += OnClosed;
+    }
+
+    private void OnKeyDown(object? sender, KeyEventArgs e)
+    {
+        if (DataContext is not OperatorConsoleViewModel vm)
+        {
+            return;
+        }
+
+        // Same [keys] bindings as the presentation window, so a presenter remote works with either focused.
+        if (_keyMap is null || !ReferenceEquals(_keyMap.Config, vm.Presentation.Keys))
+        {
+            _keyMap = new PresentationKeyMap(vm.Presentation.Keys);
+        }
+
+        switch (_keyMap.Resolve(e.Key, e.KeyModifiers))
+        {
+            case KeyAction.Advance:
+                vm.AdvanceCommand.Execute(null);
+                e.Handled = true;
+                break;
+            case KeyAction.Undo:
+                vm.UndoCommand.Execute(null);
+                e.Handled = true;
+                break;
+            case KeyAction.Autopilot:
+                vm.ToggleAutopilotCommand.Execute(null);
+                e.Handled = true;
+                break;
+            case KeyAction.SkipTeam:
+                vm.SkipTeamCommand.Execute(null);
+                e.Handled = true;
+                break;
+        }
+    }
+
+    private void OnClosed(object? sender, EventArgs e)
+    {
+        (DataContext as IDisposable)?.Dispose();
+    }
+}
@@ -0,0 +1,66 @@
+using Avalonia.Input;
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.Numerics;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     Resolves key presses to <see cref="KeyAction" />s from a <see cref="KeysConfig" />. Gestures Avalonia cannot
+///     parse are logged and skipped.
+/// </summary>
+internal sealed class PresentationKeyMap
+{
+    private readonly List<(KeyGesture Gesture, KeyAction Action)> _bindings = [];
+
+    public PresentationKeyMap(KeysConfig config)
+    {
+        Config = config;
+        foreach (var action in Enum.GetValues<KeyAction>())
+        {
+            foreach (var text in config.GetGestures(action))
+            {
+                try
+                {
+                    _bindings.Add((KeyGesture.Parse(text), action));
+                }
+                catch (Exception ex) when (ex is ArgumentException or FormatException)
+                {
+                    Trace.WriteLine($"[PresentationKeyMap] Ignoring key '{text}' for {action}: {ex.Message}");
+                }
+            }
+        }
+    }
+
+    public KeysConfig Config { get; }
+
+    /// <summary>
+    ///     The action whose gesture has the most modifiers that are all held, so <c>Ctrl+Shift+Back</c> wins over
+    ///     <c>Back</c> while plain keys still fire with a modifier held.
+    /// </summary>
+    public KeyAction? Resolve(Key key, KeyModifiers modifiers)
+    {
+        KeyAction? best = null;
+        var bestModifierCount = -1;
+        foreach (var (gesture, action) in _bindings)
+        {
+            if (gesture.Key != key || (modifiers & gesture.KeyModifiers) != gesture.KeyModifiers)
+            {
+                continue;
+            }
+
+            var modifierCount = BitOperations.PopCount((uint)gesture.KeyModifiers);
+            if (modifierCount <= bestModifierCount)
+            {
+                continue;
+            }
+
+            best = action;
+            bestModifierCount = modifierCount;
+        }
+
+        return best;
+    }
+}
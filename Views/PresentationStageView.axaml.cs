@@ -1,16 +1,20 @@
 using Avalonia;
 using Avalonia.Controls;
 using Avalonia.Controls.Presenters;
+using Avalonia.Data;
 using Avalonia.Input;
+using Avalonia.Interactivity;
 using Avalonia.Media;
 using Avalonia.Media.Imaging;
 using Avalonia.Threading;
 using Avalonia.VisualTree;
+using Pyrite.Models;
 using Pyrite.ViewModels;
 using System;
 using System.Collections.Generic;
 using System.ComponentModel;
 using System.Diagnostics;
+using System.IO;
 using System.Linq;
 
 namespace Pyrite.Views;
@@ -20,76 +24,387 @@ public partial class PresentationStageView : UserControl
     private const double FocusAnchorRatio = 2.0 / 3.0;
     private const double ScrollEpsilon = 0.5;
     private static readonly TimeSpan FocusScrollDuration = TimeSpan.FromMilliseconds(180);
-    private static readonly TimeSpan AwardOverlayFadeDuration = TimeSpan.FromMilliseconds(260);
+    private const double AwardInfoSlideDistance = 60;
+    private const double FocusPulseDepth = 0.35;
     private const double DefaultRowFlyAnimationSeconds = 0.6;
     private const double DefaultScrollAnimationSeconds = 0.4;
+    private const double ManualScrollRowsPerNotch = 3;
+    private const double ManualScrollFallbackRowHeight = 48;
+    private const double AutoScaleReferenceWidth = 1920;
+    private const double AutoScaleReferenceHeight = 1080;
 
     private INotifyPropertyChanged? _subscribedViewModel;
     private DispatcherTimer? _scrollAnimationTimer;
     private DispatcherTimer? _moveUpAnimationTimer;
     private DispatcherTimer? _awardOverlayFadeTimer;
+    private DispatcherTimer? _cellFlipAnimationTimer;
+    private DispatcherTimer? _kenBurnsTimer;
+    private DispatcherTimer? _focusPulseTimer;
+    private DispatcherTimer? _rankDeltaTimer;
     private ScrollViewer? _animatedScrollViewer;
+    private OperatorConsoleWindow? _operatorConsoleWindow;
+    private PresentationKeyMap? _keyMap;
+    private MirrorWindow? _mirrorWindow;
+    private FrameRecorder? _frameRecorder;
+    private string? _titleBeforeRecording;
     private long _animationStartTimestamp;
     private long _awardOverlayFadeStartTimestamp;
+    private long _kenBurnsStartTimestamp;
+    private int _kenBurnsDirection = 1;
+    private long _focusPulseStartTimestamp;
+    private long _rankDeltaStartTimestamp;
+    private double _rankDeltaDurationSeconds;
+    private IReadOnlyList<PreFreezeScoreboardRowViewModel> _rankDeltaRows = [];
+    private SolidColorBrush? _focusBrush;
+    private SolidColorBrush? _focusGlowBrush;
     private double _animationStartOffsetY;
     private double _animationTargetOffsetY;
     private double _awardOverlayFadeStartOpacity;
     private double _awardOverlayFadeTargetOpacity;
     private bool _anchorRequestQueued;
+    private bool _canResizeBeforePresentation = true;
     private bool _deferredRetryQueued;
+    private bool _isManualScrollActive;
     private long _lastHandledMoveUpRequestId;
+    private long _lastHandledCellFlipRequestId;
+    private long _lastHandledRankDeltaRequestId;
     private readonly List<ActiveMoveUpAnimation> _activeMoveUpAnimations = [];
     private readonly List<ActiveDownShiftAnimation> _activeDownShiftAnimations = [];
+    private readonly List<ActiveCellFlipAnimation> _activeCellFlipAnimations = [];
+    private readonly ScaleTransform _kenBurnsScale = new(1, 1);
+    private readonly TranslateTransform _kenBurnsTranslate = new();
+    private readonly TranslateTransform _awardInfoSlide = new();
 
     public PresentationStageView()
     {
         InitializeComponent();
+        AwardBackgroundImageView.RenderTransform = new TransformGroup
+        {
+            Children = { _kenBurnsScale, _kenBurnsTranslate }
+        };
+        AwardInfoPanel.RenderTransform = _awardInfoSlide;
         KeyDown += OnKeyDown;
         DataContextChanged += OnDataContextChanged;
         AttachedToVisualTree += OnAttachedToVisualTree;
         DetachedFromVisualTree += OnDetachedFromVisualTree;
-        PointerPressed += (_, _) => Focus();
+        PointerPressed += OnPointerPressed;
+        // Tunnel so the wheel is handled here before the list's own ScrollViewer sees it.
+        AddHandler(PointerWheelChangedEvent, OnPointerWheelChanged, RoutingStrategies.Tunnel);
         SizeChanged += OnViewSizeChanged;
         ScoreboardList.SizeChanged += (_, _) =>
         {
             SyncViewportToViewModel();
             RequestFocusedRowAnchor();
         };
+        ScoreboardList.AddHandler(ScrollViewer.ScrollChangedEvent, (_, _) => SyncVisibleRowsToViewModel());
+    }
+
+    private void OnPointerPressed(object? sender, PointerPressedEventArgs e)
+    {
+        Focus();
+        if (DataContext is not PresentationStageViewModel vm ||
+            !e.GetCurrentPoint(this).Properties.IsLeftButtonPressed)
+        {
+            return;
+        }
+
+        // Scoreboard rows are not hit-testable (so the mouse cannot change the selection), so find the cell by bounds.
+        var cellBorder = ScoreboardList.GetVisualDescendants()
+            .OfType<Border>()
+            .FirstOrDefault(border => border.Classes.Contains("problemCell") &&
+                                      border.IsEffectivelyVisible &&
+                                      new Rect(border.Bounds.Size).Contains(e.GetPosition(border)));
+        if (cellBorder?.DataContext is ProblemStatusCellViewModel cell)
+        {
+            vm.HandleProblemCellClicked(cell);
+            e.Handled = true;
+        }
+    }
+
+    /// <summary>
+    ///     Lets the operator scroll the board by hand (e.g. to show the top teams mid-ceremony). The resolver takes the
+    ///     viewport back on the next <c>Space</c> or focus change.
+    /// </summary>
+    private void OnPointerWheelChanged(object? sender, PointerWheelEventArgs e)
+    {
+        var scrollViewer = ScoreboardList.GetVisualDescendants().OfType<ScrollViewer>().FirstOrDefault();
+        if (scrollViewer is null || Math.Abs(e.Delta.Y) < double.Epsilon)
+        {
+            return;
+        }
+
+        var rowHeight = (ScoreboardList.ContainerFromIndex(0) as Control)?.Bounds.Height ?? 0;
+        if (rowHeight <= 0)
+        {
+            rowHeight = ManualScrollFallbackRowHeight;
+        }
+
+        StopScrollAnimation();
+        var maxOffsetY = Math.Max(0, scrollViewer.Extent.Height - scrollViewer.Viewport.Height);
+        var offsetY = Math.Clamp(
+            scrollViewer.Offset.Y - (e.Delta.Y * rowHeight * ManualScrollRowsPerNotch),
+            0,
+            maxOffsetY);
+        scrollViewer.Offset = new Vector(scrollViewer.Offset.X, offsetY);
+        _isManualScrollActive = true;
+        e.Handled = true;
     }
 
     private void OnKeyDown(object? sender, KeyEventArgs e)
     {
-        if (e.Key == Key.F12)
+        var vm = DataContext as PresentationStageViewModel;
+        if (e.Key == Key.Escape && vm is { IsHotkeyHelpVisible: true })
         {
-            ToggleFullscreen();
+            vm.HandleHotkeyHelpDismissed();
             e.Handled = true;
             return;
         }
 
-        if (e.Key != Key.Space)
+        var keys = vm?.Keys ?? new KeysConfig();
+        if (_keyMap is null || !ReferenceEquals(_keyMap.Config, keys))
+        {
+            _keyMap = new PresentationKeyMap(keys);
+        }
+
+        if (_keyMap.Resolve(e.Key, e.KeyModifiers) is not { } action)
         {
             return;
         }
 
-        if (DataContext is not PresentationStageViewModel vm)
+        if (TopLevel.GetTopLevel(this) is Window window)
+        {
+            switch (action)
+            {
+                case KeyAction.Fullscreen:
+                    WindowPlacement.ToggleFullscreen(window);
+                    e.Handled = true;
+                    return;
+                case KeyAction.Borderless:
+                    WindowPlacement.ToggleBorderless(window);
+                    e.Handled = true;
+                    return;
+                case KeyAction.NextMonitor:
+                    WindowPlacement.MoveToNextScreen(window);
+                    e.Handled = true;
+                    return;
+            }
+        }
+
+        if (vm is null)
         {
             return;
         }
 
-        vm.HandleSpacePressed();
-        e.Handled = true;
+        switch (action)
+        {
+            case KeyAction.Help:
+                vm.HandleHotkeyHelpToggled();
+                e.Handled = true;
+                break;
+            case KeyAction.Advance when _isManualScrollActive:
+                // The first Space after a manual scroll only brings the focused row back into view.
+                RequestFocusedRowAnchor();
+                e.Handled = true;
+                break;
+            case KeyAction.Advance:
+                vm.HandleSpacePressed();
+                e.Handled = true;
+                break;
+            case KeyAction.BackToSetup:
+                vm.ExitCommand.Execute(null);
+                e.Handled = true;
+                break;
+            case KeyAction.Undo:
+                StopAllMoveUpAnimations();
+                StopAllCellFlipAnimations();
+                vm.HandleUndoPressed();
+                e.Handled = true;
+                break;
+            case KeyAction.Autopilot:
+                vm.HandleAutopilotToggled();
+                e.Handled = true;
+                break;
+            case KeyAction.OperatorConsole:
+                ToggleOperatorConsole(vm);
+                e.Handled = true;
+                break;
+            case KeyAction.Recording:
+                ToggleRecording(vm);
+                e.Handled = true;
+                break;
+            case KeyAction.Screenshot:
+                SaveScreenshot(vm);
+                e.Handled = true;
+                break;
+            case KeyAction.RankComparison:
+                vm.HandleRankComparisonToggled();
+                e.Handled = true;
+                break;
+            case KeyAction.Mirror:
+                ToggleMirrorWindow(vm);
+                e.Handled = true;
+                break;
+            case KeyAction.Palette:
+                vm.HandlePaletteCycled();
+                e.Handled = true;
+                break;
+            case KeyAction.Mute:
+                vm.HandleMuteToggled();
+                e.Handled = true;
+                break;
+            case KeyAction.Blank:
+                vm.HandleBlankToggled();
+                e.Handled = true;
+                break;
+            case KeyAction.SkipTeam:
+                vm.HandleSkipTeamPressed();
+                e.Handled = true;
+                break;
+            case KeyAction.SlowerAnimations:
+                vm.HandleAnimationSpeedChanged(-1);
+                e.Handled = true;
+                break;
+            case KeyAction.FasterAnimations:
+                vm.HandleAnimationSpeedChanged(1);
+                e.Handled = true;
+                break;
+            case KeyAction.NormalAnimations:
+                vm.HandleAnimationSpeedChanged(0);
+                e.Handled = true;
+                break;
+            case KeyAction.ZoomIn:
+                vm.HandleStageScaleChanged(1);
+                e.Handled = true;
+                break;
+            case KeyAction.ZoomOut:
+                vm.HandleStageScaleChanged(-1);
+                e.Handled = true;
+                break;
+            case KeyAction.ZoomReset:
+                vm.HandleStageScaleChanged(0);
+                e.Handled = true;
+                break;
+            case KeyAction.RevealAll:
+                StopAllMoveUpAnimations();
+                StopAllCellFlipAnimations();
+                vm.HandleRevealAllPressed();
+                e.Handled = true;
+                break;
+        }
+    }
+
+    private void ToggleOperatorConsole(PresentationStageViewModel vm)
+    {
+        if (_operatorConsoleWindow is not null)
+        {
+            _operatorConsoleWindow.Close();
+            return;
+        }
+
+        if (!vm.IsStarted)
+        {
+            return;
+        }
+
+        var window = new OperatorConsoleWindow
+        {
+            DataContext = new OperatorConsoleViewModel(vm)
+        };
+        window.Closed += (_, _) => _operatorConsoleWindow = null;
+        _operatorConsoleWindow = window;
+        window.Show();
+    }
+
+    private void ToggleMirrorWindow(PresentationStageViewModel vm)
+    {
+        if (_mirrorWindow is not null)
+        {
+            _mirrorWindow.Close();
+            return;
+        }
+
+        if (!vm.IsStarted)
+        {
+            return;
+        }
+
+        var window = new MirrorWindow(StageScaleHost, vm.Keys);
+        window.Closed += (_, _) => _mirrorWindow = null;
+        _mirrorWindow = window;
+        window.Show();
+        if (vm.PresentationConfig.MirrorMonitor is { } monitor)
+        {
+            WindowPlacement.MoveToScreen(window, monitor);
+        }
     }
 
-    private void ToggleFullscreen()
+    /// <summary>
+    ///     Renders the stage as it is on screen to a timestamped PNG in the configured <c>screenshot_dir</c>.
+    /// </summary>
+    private void SaveScreenshot(PresentationStageViewModel vm)
     {
-        if (TopLevel.GetTopLevel(this) is not Window window)
+        var bounds = StageScaleHost.Bounds;
+        if (bounds.Width <= 0 || bounds.Height <= 0)
         {
             return;
         }
 
-        window.WindowState = window.WindowState == WindowState.FullScreen
-            ? WindowState.Normal
-            : WindowState.FullScreen;
+        var scaling = TopLevel.GetTopLevel(this)?.RenderScaling ?? 1;
+        var pixelSize = new PixelSize(
+            Math.Max(1, (int)Math.Ceiling(bounds.Width * scaling)),
+            Math.Max(1, (int)Math.Ceiling(bounds.Height * scaling)));
+        try
+        {
+            var directory = Path.GetFullPath(vm.PresentationConfig.ScreenshotDirectory);
+            Directory.CreateDirectory(directory);
+            var path = Path.Combine(directory, $"pyrite-{DateTime.Now:yyyyMMdd-HHmmss-fff}.png");
+            using var bitmap = new RenderTargetBitmap(pixelSize, new Vector(96 * scaling, 96 * scaling));
+            bitmap.Render(StageScaleHost);
+            bitmap.Save(path);
+            Trace.WriteLine($"[PresentationStageView] Screenshot saved to {path}.");
+        }
+        catch (Exception ex)
+        {
+            Trace.WriteLine($"[PresentationStageView] Screenshot failed: {ex.Message}");
+        }
+    }
+
+    private void ToggleRecording(PresentationStageViewModel vm)
+    {
+        var window = TopLevel.GetTopLevel(this) as Window;
+        if (_frameRecorder is not null)
+        {
+            StopRecording();
+            return;
+        }
+
+        _frameRecorder = FrameRecorder.TryStart(
+            StageScaleHost,
+            TopLevel.GetTopLevel(this)?.RenderScaling ?? 1,
+            vm.PresentationConfig);
+        if (_frameRecorder is not null && window is not null)
+        {
+            // The title is the only indicator: anything drawn on the stage would end up in the video.
+            _titleBeforeRecording = window.Title;
+            window.Title = $"{window.Title} - Recording";
+        }
+    }
+
+    private void StopRecording()
+    {
+        if (_frameRecorder is null)
+        {
+            return;
+        }
+
+        _frameRecorder.Dispose();
+        _frameRecorder = null;
+        if (TopLevel.GetTopLevel(this) is Window window && _titleBeforeRecording is not null)
+        {
+            window.Title = _titleBeforeRecording;
+        }
+
+        _titleBeforeRecording = null;
     }
 
     private void OnAttachedToVisualTree(object? sender, VisualTreeAttachmentEventArgs e)
@@ -97,19 +412,53 @@ public partial class PresentationStageView : UserControl
         Focus();
         SyncViewportToViewModel();
         RequestFocusedRowAnchor();
+        StageClock.Advanced += OnStageClockAdvanced;
     }
 
     private void OnDetachedFromVisualTree(object? sender, VisualTreeAttachmentEventArgs e)
     {
         StopScrollAnimation();
         StopAllMoveUpAnimations();
+        StopAllCellFlipAnimations();
         StopAwardOverlayFadeAnimation();
+        StopKenBurnsAnimation();
+        StopFocusPulseAnimation();
+        StopRankDeltaAnimation();
+        StopRecording();
+        StageClock.Advanced -= OnStageClockAdvanced;
+    }
+
+    /// <summary>
+    ///     While recording, steps every running animation to the time of the frame about to be rendered.
+    /// </summary>
+    private void OnStageClockAdvanced(object? sender, EventArgs e)
+    {
+        foreach (var (timer, tick) in new (DispatcherTimer?, EventHandler)[]
+                 {
+                     (_scrollAnimationTimer, OnScrollAnimationTick),
+                     (_moveUpAnimationTimer, OnMoveUpAnimationTick),
+                     (_cellFlipAnimationTimer, OnCellFlipAnimationTick),
+                     (_awardOverlayFadeTimer, OnAwardOverlayFadeTick),
+                     (_kenBurnsTimer, OnKenBurnsTick),
+                     (_focusPulseTimer, OnFocusPulseTick),
+                     (_rankDeltaTimer, OnRankDeltaTick)
+                 })
+        {
+            if (timer is { IsEnabled: true })
+            {
+                tick(timer, e);
+            }
+        }
     }
 
     private void OnDataContextChanged(object? sender, EventArgs e)
     {
         StopAllMoveUpAnimations();
+        StopAllCellFlipAnimations();
+        StopRankDeltaAnimation();
         _lastHandledMoveUpRequestId = 0;
+        _lastHandledCellFlipRequestId = 0;
+        _lastHandledRankDeltaRequestId = 0;
 
         if (_subscribedViewModel is not null)
         {
@@ -126,6 +475,9 @@ public partial class PresentationStageView : UserControl
         if (DataContext is PresentationStageViewModel vm)
         {
             SetAwardOverlayVisibilityImmediate(vm.IsAwardOverlayVisible);
+            ApplyThemeColors();
+            UpdateBaseStageScale();
+            ApplyStageScale();
         }
         else
         {
@@ -138,6 +490,7 @@ public partial class PresentationStageView : UserControl
 
     private void OnViewSizeChanged(object? sender, SizeChangedEventArgs e)
     {
+        UpdateBaseStageScale();
         SyncViewportToViewModel();
     }
 
@@ -173,10 +526,64 @@ public partial class PresentationStageView : UserControl
         }
 
         vm.UpdateViewport(viewportWidth, viewportHeight, totalHeight);
+        vm.RenderScaling = TopLevel.GetTopLevel(this)?.RenderScaling ?? 1;
+    }
+
+    /// <summary>
+    ///     Tells the view model which rows are on screen, so it can release the logos of rows far away.
+    /// </summary>
+    private void SyncVisibleRowsToViewModel()
+    {
+        if (DataContext is not PresentationStageViewModel vm)
+        {
+            return;
+        }
+
+        var scrollViewer = ScoreboardList.GetVisualDescendants().OfType<ScrollViewer>().FirstOrDefault();
+        if (scrollViewer is null || scrollViewer.Viewport.Height <= 0)
+        {
+            return;
+        }
+
+        var rowHeight = ScoreboardList.GetRealizedContainers().FirstOrDefault()?.Bounds.Height ?? 0;
+        if (rowHeight <= 0)
+        {
+            rowHeight = ManualScrollFallbackRowHeight;
+        }
+
+        vm.UpdateVisibleRows(
+            (int)(scrollViewer.Offset.Y / rowHeight),
+            (int)((scrollViewer.Offset.Y + scrollViewer.Viewport.Height) / rowHeight));
     }
 
     private void OnViewModelPropertyChanged(object? sender, PropertyChangedEventArgs e)
     {
+        if (e.PropertyName == nameof(PresentationStageViewModel.IsStarted))
+        {
+            HandleStartedChanged();
+            return;
+        }
+
+        if (e.PropertyName is nameof(PresentationStageViewModel.PresentationConfig)
+            or nameof(PresentationStageViewModel.ActiveColors))
+        {
+            ApplyThemeColors();
+            UpdateBaseStageScale();
+            return;
+        }
+
+        if (e.PropertyName == nameof(PresentationStageViewModel.FontFilePaths))
+        {
+            ApplyFonts();
+            return;
+        }
+
+        if (e.PropertyName == nameof(PresentationStageViewModel.StageScale))
+        {
+            ApplyStageScale();
+            return;
+        }
+
         if (e.PropertyName == nameof(PresentationStageViewModel.IsAwardOverlayVisible))
         {
             var isVisible = (DataContext as PresentationStageViewModel)?.IsAwardOverlayVisible ?? false;
@@ -184,12 +591,34 @@ public partial class PresentationStageView : UserControl
             return;
         }
 
+        if (e.PropertyName == nameof(PresentationStageViewModel.AwardBackgroundImage))
+        {
+            if ((DataContext as PresentationStageViewModel)?.IsAwardOverlayVisible == true)
+            {
+                StartKenBurnsAnimation();
+            }
+
+            return;
+        }
+
         if (e.PropertyName == nameof(PresentationStageViewModel.MoveUpAnimationRequest))
         {
             HandleMoveUpAnimationRequest();
             return;
         }
 
+        if (e.PropertyName == nameof(PresentationStageViewModel.CellFlipAnimationRequest))
+        {
+            HandleCellFlipAnimationRequest();
+            return;
+        }
+
+        if (e.PropertyName == nameof(PresentationStageViewModel.RankDeltaAnimationRequest))
+        {
+            HandleRankDeltaAnimationRequest();
+            return;
+        }
+
         if (!string.IsNullOrEmpty(e.PropertyName) &&
             e.PropertyName != nameof(PresentationStageViewModel.FocusedRowIndex))
         {
@@ -205,6 +634,246 @@ public partial class PresentationStageView : UserControl
         RequestFocusedRowAnchor();
     }
 
+    private void ApplyThemeColors()
+    {
+        if (DataContext is not PresentationStageViewModel vm)
+        {
+            return;
+        }
+
+        var colors = vm.ActiveColors;
+        if (colors.BackgroundGradient.Count >= 2)
+        {
+            Resources["PresentationBackgroundBrush"] = CreateBackgroundGradient(colors.BackgroundGradient);
+        }
+        else
+        {
+            SetBrushResource("PresentationBackgroundBrush", colors.Background);
+        }
+
+        SetBrushResource("PresentationHeaderBackgroundBrush", colors.HeaderBackground);
+        // Translucent rows let the background image or gradient show through the stripes.
+        foreach (var rowBrush in new[]
+                 {
+                     SetBrushResource("PresentationRowBackgroundBrush", colors.RowBackground),
+                     SetBrushResource("PresentationRowAlternateBackgroundBrush", colors.RowAlternateBackground)
+                 })
+        {
+            if (rowBrush is not null)
+            {
+                rowBrush.Opacity = vm.PresentationConfig.RowOpacity;
+            }
+        }
+        SetBrushResource("PresentationTextBrush", colors.Text);
+        _focusBrush = SetBrushResource("PresentationFocusBrush", colors.Focus);
+        _focusGlowBrush = SetBrushResource("PresentationFocusGlowBrush", colors.FocusGlow);
+        if (vm.IsStarted)
+        {
+            StartFocusPulseAnimation();
+        }
+        else
+        {
+            StopFocusPulseAnimation();
+        }
+    }
+
+    /// <summary>
+    ///     Loads the <c>[fonts]</c> files and sets the stage font and the per-style font size resources.
+    /// </summary>
+    private void ApplyFonts()
+    {
+        if (DataContext is not PresentationStageViewModel vm)
+        {
+            return;
+        }
+
+        var fonts = vm.Fonts;
+        ClearValue(FontFamilyProperty);
+        if (vm.FontFilePaths.Count > 0)
+        {
+            try
+            {
+                var collection = CdpFontCollection.Register(vm.FontFilePaths);
+                var family = fonts.Family ?? collection.FamilyNames.FirstOrDefault();
+                if (family is not null)
+                {
+                    FontFamily = collection.GetFontFamily(family);
+                }
+            }
+            catch (Exception ex)
+            {
+                Trace.WriteLine($"[PresentationStageView] Failed to load custom fonts: {ex.Message}");
+            }
+        }
+
+        var sizes = fonts.Sizes;
+        Resources["PresentationHeaderFontSize"] = (double)sizes.Header;
+        Resources["PresentationRankFontSize"] = (double)sizes.Rank;
+        Resources["PresentationTeamNameFontSize"] = (double)sizes.TeamName;
+        Resources["PresentationOrganizationFontSize"] = (double)sizes.Organization;
+        Resources["PresentationScoreFontSize"] = (double)sizes.Score;
+        Resources["PresentationAwardTitleFontSize"] = (double)sizes.AwardTitle;
+        Resources["PresentationAwardTeamNameFontSize"] = (double)sizes.AwardTeamName;
+        Resources["PresentationAwardCitationFontSize"] = (double)sizes.AwardCitation;
+        Resources["PresentationChampionTeamNameFontSize"] = (double)sizes.ChampionTeamName;
+    }
+
+    /// <summary>
+    ///     Hands <c>ui_scale</c> to the view model, or for <c>"auto"</c> the fit of the view into 1920x1080. The view
+    ///     size is in logical pixels, so the OS scale factor is already applied: a 4K projector at 100% gets 2x, the
+    ///     same projector at 200% or a 1366x768 laptop gets about 1x and 0.7x.
+    /// </summary>
+    private void UpdateBaseStageScale()
+    {
+        if (DataContext is not PresentationStageViewModel vm)
+        {
+            return;
+        }
+
+        var config = vm.PresentationConfig;
+        if (!config.UiScaleAuto)
+        {
+            vm.SetBaseStageScale(config.UiScale);
+            return;
+        }
+
+        var size = Bounds.Size;
+        if (size.Width <= 0 || size.Height <= 0)
+        {
+            return;
+        }
+
+        vm.SetBaseStageScale(Math.Min(size.Width / AutoScaleReferenceWidth, size.Height / AutoScaleReferenceHeight));
+    }
+
+    private void ApplyStageScale()
+    {
+        var scale = (DataContext as PresentationStageViewModel)?.StageScale ?? 1;
+        StageScaleHost.LayoutTransform = Math.Abs(scale - 1) < 0.001 ? null : new ScaleTransform(scale, scale);
+
+        // The row count per screen changes with the scale, so re-measure and keep the focused row anchored.
+        StopScrollAnimation();
+        Dispatcher.UIThread.Post(() =>
+        {
+            SyncViewportToViewModel();
+            RequestFocusedRowAnchor();
+        }, DispatcherPriority.Render);
+    }
+
+    /// <summary>
+    ///     Top-to-bottom gradient with the configured colors spread evenly, drawn behind the (possibly translucent) rows.
+    /// </summary>
+    private static LinearGradientBrush CreateBackgroundGradient(IReadOnlyList<string> hexColors)
+    {
+        var brush = new LinearGradientBrush
+        {
+            StartPoint = new RelativePoint(0.5, 0, RelativeUnit.Relative),
+            EndPoint = new RelativePoint(0.5, 1, RelativeUnit.Relative)
+        };
+        for (var i = 0; i < hexColors.Count; i++)
+        {
+            if (Color.TryParse(hexColors[i], out var color))
+            {
+                brush.GradientStops.Add(new GradientStop(color, i / (double)(hexColors.Count - 1)));
+            }
+        }
+
+        return brush;
+    }
+
+    private SolidColorBrush? SetBrushResource(string key, string hexColor)
+    {
+        if (!Color.TryParse(hexColor, out var color))
+        {
+            return null;
+        }
+
+        var brush = new SolidColorBrush(color);
+        Resources[key] = brush;
+        return brush;
+    }
+
+    /// <summary>
+    ///     Breathes the focused row's highlight and glow outline. The shared brushes are animated in place, so whichever
+    ///     row is selected picks the pulse up without touching its container.
+    /// </summary>
+    private void StartFocusPulseAnimation()
+    {
+        StopFocusPulseAnimation();
+        if (DataContext is not PresentationStageViewModel { PresentationConfig.FocusPulseSeconds: > 0 })
+        {
+            return;
+        }
+
+        _focusPulseStartTimestamp = StageClock.GetTimestamp();
+        _focusPulseTimer ??= new DispatcherTimer(
+            TimeSpan.FromMilliseconds(16),
+            DispatcherPriority.Render,
+            OnFocusPulseTick);
+        _focusPulseTimer.Start();
+    }
+
+    private void OnFocusPulseTick(object? sender, EventArgs e)
+    {
+        if (DataContext is not PresentationStageViewModel vm)
+        {
+            StopFocusPulseAnimation();
+            return;
+        }
+
+        var elapsedSeconds = (StageClock.GetTimestamp() - _focusPulseStartTimestamp) / (double)Stopwatch.Frequency;
+        var wave = 0.5 - (0.5 * Math.Cos(2 * Math.PI * elapsedSeconds / vm.PresentationConfig.FocusPulseSeconds));
+        if (_focusBrush is not null)
+        {
+            _focusBrush.Opacity = 1 - (FocusPulseDepth * wave);
+        }
+
+        if (_focusGlowBrush is not null)
+        {
+            _focusGlowBrush.Opacity = 0.25 + (0.75 * wave);
+        }
+    }
+
+    private void StopFocusPulseAnimation()
+    {
+        _focusPulseTimer?.Stop();
+        if (_focusBrush is not null)
+        {
+            _focusBrush.Opacity = 1;
+        }
+
+        if (_focusGlowBrush is not null)
+        {
+            _focusGlowBrush.Opacity = 0;
+        }
+    }
+
+    private void HandleStartedChanged()
+    {
+        var vm = DataContext as PresentationStageViewModel;
+        var window = TopLevel.GetTopLevel(this) as Window;
+        if (vm?.IsStarted == true)
+        {
+            if (window is not null)
+            {
+                _canResizeBeforePresentation = window.CanResize;
+                WindowPlacement.ApplyPresentationConfig(window, vm.PresentationConfig);
+            }
+
+            StartFocusPulseAnimation();
+            return;
+        }
+
+        StopFocusPulseAnimation();
+        _operatorConsoleWindow?.Close();
+        _mirrorWindow?.Close();
+        StopRecording();
+        if (window is not null)
+        {
+            window.CanResize = _canResizeBeforePresentation;
+        }
+    }
+
     private void HandleMoveUpAnimationRequest()
     {
         Trace.WriteLine("[MoveUpAnim] Property changed for MoveUpAnimationRequest.");
@@ -229,8 +898,159 @@ public partial class PresentationStageView : UserControl
             DispatcherPriority.Render);
     }
 
+    private void HandleCellFlipAnimationRequest()
+    {
+        if (DataContext is not PresentationStageViewModel { CellFlipAnimationRequest: { } request } ||
+            request.RequestId <= _lastHandledCellFlipRequestId)
+        {
+            return;
+        }
+
+        _lastHandledCellFlipRequestId = request.RequestId;
+        Dispatcher.UIThread.Post(() => TryStartCellFlipAnimation(request), DispatcherPriority.Render);
+    }
+
+    private void TryStartCellFlipAnimation(CellFlipAnimationRequest request)
+    {
+        var cellBorder = ScoreboardList.GetVisualDescendants()
+            .OfType<Border>()
+            .FirstOrDefault(border => border.Classes.Contains("problemCell") &&
+                                      ReferenceEquals(border.DataContext, request.Cell));
+        if (cellBorder is null || !Color.TryParse(request.PreviousBackground, out var previousColor))
+        {
+            Trace.WriteLine($"[CellFlipAnim] Skip request: id={request.RequestId}, cellFound={cellBorder is not null}.");
+            return;
+        }
+
+        StopCellFlipAnimation(cellBorder);
+
+        var transform = new ScaleTransform(1, 1);
+        cellBorder.RenderTransform = transform;
+        // Hold the frozen color until the cell is edge-on, then let the bound result color show through.
+        var backgroundOverride = cellBorder.SetValue(
+            Border.BackgroundProperty,
+            new SolidColorBrush(previousColor),
+            BindingPriority.Animation);
+        _activeCellFlipAnimations.Add(new ActiveCellFlipAnimation(
+            cellBorder,
+            transform,
+            backgroundOverride,
+            StageClock.GetTimestamp(),
+            request.DurationSeconds));
+
+        _cellFlipAnimationTimer ??= new DispatcherTimer(
+            TimeSpan.FromMilliseconds(16),
+            DispatcherPriority.Render,
+            OnCellFlipAnimationTick);
+        _cellFlipAnimationTimer.Start();
+    }
+
+    private void OnCellFlipAnimationTick(object? sender, EventArgs e)
+    {
+        var now = StageClock.GetTimestamp();
+        for (var i = _activeCellFlipAnimations.Count - 1; i >= 0; i--)
+        {
+            var animation = _activeCellFlipAnimations[i];
+            var progress = ComputeAnimationProgress(now, animation.StartTimestamp, animation.DurationSeconds);
+            animation.Transform.ScaleX = Math.Abs(Math.Cos(progress * Math.PI));
+
+            if (progress >= 0.5)
+            {
+                animation.ReleaseBackgroundOverride();
+            }
+
+            if (progress >= 1)
+            {
+                animation.Complete();
+                _activeCellFlipAnimations.RemoveAt(i);
+            }
+        }
+
+        if (_activeCellFlipAnimations.Count == 0)
+        {
+            _cellFlipAnimationTimer?.Stop();
+        }
+    }
+
+    private void StopCellFlipAnimation(Border cellBorder)
+    {
+        for (var i = _activeCellFlipAnimations.Count - 1; i >= 0; i--)
+        {
+            if (ReferenceEquals(_activeCellFlipAnimations[i].Cell, cellBorder))
+            {
+                _activeCellFlipAnimations[i].Complete();
+                _activeCellFlipAnimations.RemoveAt(i);
+            }
+        }
+    }
+
+    private void StopAllCellFlipAnimations()
+    {
+        _cellFlipAnimationTimer?.Stop();
+        foreach (var animation in _activeCellFlipAnimations)
+        {
+            animation.Complete();
+        }
+
+        _activeCellFlipAnimations.Clear();
+    }
+
+    private void HandleRankDeltaAnimationRequest()
+    {
+        if (DataContext is not PresentationStageViewModel { RankDeltaAnimationRequest: { } request } ||
+            request.RequestId <= _lastHandledRankDeltaRequestId)
+        {
+            return;
+        }
+
+        _lastHandledRankDeltaRequestId = request.RequestId;
+        // Rows that moved again already carry their new delta; only the ones left behind are cleared.
+        foreach (var row in _rankDeltaRows.Except(request.Rows))
+        {
+            row.ClearRankDelta();
+        }
+
+        _rankDeltaRows = request.Rows;
+        _rankDeltaDurationSeconds = request.DurationSeconds;
+        _rankDeltaStartTimestamp = StageClock.GetTimestamp();
+        _rankDeltaTimer ??= new DispatcherTimer(
+            TimeSpan.FromMilliseconds(16),
+            DispatcherPriority.Render,
+            OnRankDeltaTick);
+        _rankDeltaTimer.Start();
+    }
+
+    private void OnRankDeltaTick(object? sender, EventArgs e)
+    {
+        var progress = ComputeAnimationProgress(_rankDeltaStartTimestamp, _rankDeltaDurationSeconds);
+        if (progress >= 1)
+        {
+            StopRankDeltaAnimation();
+            return;
+        }
+
+        // Stay readable for most of the duration, then fade quickly.
+        var opacity = 1 - (progress * progress * progress);
+        foreach (var row in _rankDeltaRows)
+        {
+            row.RankDeltaOpacity = opacity;
+        }
+    }
+
+    private void StopRankDeltaAnimation()
+    {
+        _rankDeltaTimer?.Stop();
+        foreach (var row in _rankDeltaRows)
+        {
+            row.ClearRankDelta();
+        }
+
+        _rankDeltaRows = [];
+    }
+
     private void RequestFocusedRowAnchor()
     {
+        _isManualScrollActive = false;
         if (_anchorRequestQueued)
         {
             return;
@@ -317,7 +1137,7 @@ public partial class PresentationStageView : UserControl
         _animatedScrollViewer = scrollViewer;
         _animationStartOffsetY = currentOffsetY;
         _animationTargetOffsetY = targetOffsetY;
-        _animationStartTimestamp = Stopwatch.GetTimestamp();
+        _animationStartTimestamp = StageClock.GetTimestamp();
 
         if (_scrollAnimationTimer is null)
         {
@@ -339,7 +1159,8 @@ public partial class PresentationStageView : UserControl
             return;
         }
 
-        var progress = ComputeAnimationProgress(_animationStartTimestamp, FocusScrollDuration.TotalSeconds);
+        var speed = (DataContext as PresentationStageViewModel)?.AnimationSpeed ?? 1;
+        var progress = ComputeAnimationProgress(_animationStartTimestamp, FocusScrollDuration.TotalSeconds / speed);
         var eased = EaseOutCubic(progress);
         var nextOffsetY = _animationStartOffsetY +
                           ((_animationTargetOffsetY - _animationStartOffsetY) * eased);
@@ -378,7 +1199,7 @@ public partial class PresentationStageView : UserControl
     private static double ComputeAnimationProgress(long startTimestamp, double durationSeconds)
     {
         return ComputeAnimationProgress(
-            Stopwatch.GetTimestamp(),
+            StageClock.GetTimestamp(),
             startTimestamp,
             durationSeconds);
     }
@@ -572,7 +1393,7 @@ public partial class PresentationStageView : UserControl
             hiddenRow,
             startY,
             visualTargetY,
-            Stopwatch.GetTimestamp(),
+            StageClock.GetTimestamp(),
             Math.Max(0.001, durationMs / 1000.0)));
         Trace.WriteLine($"[MoveUpAnim] Animation queued: id={request.RequestId}, hiddenRow={hiddenRow is not null}, activeCount={_activeMoveUpAnimations.Count}.");
 
@@ -589,7 +1410,7 @@ public partial class PresentationStageView : UserControl
 
         var startIndex = Math.Max(0, request.ToIndex + 1);
         var endIndex = Math.Min(request.FromIndex, ScoreboardList.ItemCount - 1);
-        var timestamp = Stopwatch.GetTimestamp();
+        var timestamp = StageClock.GetTimestamp();
         for (var i = startIndex; i <= endIndex; i++)
         {
             var row = ScoreboardList.ContainerFromIndex(i) as Control;
@@ -648,7 +1469,7 @@ public partial class PresentationStageView : UserControl
             return;
         }
 
-        var now = Stopwatch.GetTimestamp();
+        var now = StageClock.GetTimestamp();
         for (var i = _activeMoveUpAnimations.Count - 1; i >= 0; i--)
         {
             var animation = _activeMoveUpAnimations[i];
@@ -715,11 +1536,12 @@ public partial class PresentationStageView : UserControl
     {
         _awardOverlayFadeStartOpacity = AwardOverlayRoot.Opacity;
         _awardOverlayFadeTargetOpacity = visible ? 1 : 0;
-        _awardOverlayFadeStartTimestamp = Stopwatch.GetTimestamp();
+        _awardOverlayFadeStartTimestamp = StageClock.GetTimestamp();
 
         if (visible)
         {
             AwardOverlayRoot.IsVisible = true;
+            StartKenBurnsAnimation();
         }
 
         if (_awardOverlayFadeTimer is null)
@@ -741,19 +1563,82 @@ public partial class PresentationStageView : UserControl
             return;
         }
 
-        var progress = ComputeAnimationProgress(_awardOverlayFadeStartTimestamp, AwardOverlayFadeDuration.TotalSeconds);
+        var progress = ComputeAnimationProgress(_awardOverlayFadeStartTimestamp, GetAwardFadeSeconds());
         var eased = EaseInOutCubic(progress);
         AwardOverlayRoot.Opacity =
             _awardOverlayFadeStartOpacity + ((_awardOverlayFadeTargetOpacity - _awardOverlayFadeStartOpacity) * eased);
+        _awardInfoSlide.Y = (1 - AwardOverlayRoot.Opacity) * AwardInfoSlideDistance;
 
         if (progress >= 1)
         {
             AwardOverlayRoot.Opacity = _awardOverlayFadeTargetOpacity;
             AwardOverlayRoot.IsVisible = _awardOverlayFadeTargetOpacity > 0;
+            _awardInfoSlide.Y = 0;
             _awardOverlayFadeTimer.Stop();
+            if (!AwardOverlayRoot.IsVisible)
+            {
+                StopKenBurnsAnimation();
+            }
+        }
+    }
+
+    private double GetAwardFadeSeconds()
+    {
+        return DataContext is PresentationStageViewModel vm
+            ? Math.Max(0.01, vm.PresentationConfig.AwardFadeSeconds)
+            : 0.01;
+    }
+
+    private void StartKenBurnsAnimation()
+    {
+        StopKenBurnsAnimation();
+        if (DataContext is not PresentationStageViewModel { PresentationConfig.AwardKenBurnsSeconds: > 0 })
+        {
+            return;
+        }
+
+        // Alternate the pan direction so consecutive awards do not all drift the same way.
+        _kenBurnsDirection = -_kenBurnsDirection;
+        _kenBurnsStartTimestamp = StageClock.GetTimestamp();
+        _kenBurnsTimer ??= new DispatcherTimer(
+            TimeSpan.FromMilliseconds(16),
+            DispatcherPriority.Render,
+            OnKenBurnsTick);
+        _kenBurnsTimer.Start();
+    }
+
+    private void OnKenBurnsTick(object? sender, EventArgs e)
+    {
+        if (DataContext is not PresentationStageViewModel vm)
+        {
+            StopKenBurnsAnimation();
+            return;
+        }
+
+        var config = vm.PresentationConfig;
+        var progress = ComputeAnimationProgress(_kenBurnsStartTimestamp, config.AwardKenBurnsSeconds);
+        var zoom = 1 + ((config.AwardKenBurnsZoom - 1) * progress);
+        _kenBurnsScale.ScaleX = zoom;
+        _kenBurnsScale.ScaleY = zoom;
+
+        // Pan at most as far as the zoom leaves spare image on each side, so no black edge shows.
+        var spareWidth = AwardBackgroundImageView.Bounds.Width * (zoom - 1) / 2;
+        _kenBurnsTranslate.X = _kenBurnsDirection * spareWidth * progress;
+
+        if (progress >= 1)
+        {
+            _kenBurnsTimer?.Stop();
         }
     }
 
+    private void StopKenBurnsAnimation()
+    {
+        _kenBurnsTimer?.Stop();
+        _kenBurnsScale.ScaleX = 1;
+        _kenBurnsScale.ScaleY = 1;
+        _kenBurnsTranslate.X = 0;
+    }
+
     private void StopAwardOverlayFadeAnimation()
     {
         if (_awardOverlayFadeTimer is not null)
@@ -767,6 +1652,15 @@ public partial class PresentationStageView : UserControl
         StopAwardOverlayFadeAnimation();
         AwardOverlayRoot.IsVisible = visible;
         AwardOverlayRoot.Opacity = visible ? 1 : 0;
+        _awardInfoSlide.Y = 0;
+        if (visible)
+        {
+            StartKenBurnsAnimation();
+        }
+        else
+        {
+            StopKenBurnsAnimation();
+        }
     }
 
     private void CompleteMoveUpAnimation(ActiveMoveUpAnimation animation)
@@ -868,6 +1762,33 @@ public partial class PresentationStageView : UserControl
         long StartTimestamp,
         double DurationSeconds);
 
+    private sealed class ActiveCellFlipAnimation(
+        Border cell,
+        ScaleTransform transform,
+        IDisposable? backgroundOverride,
+        long startTimestamp,
+        double durationSeconds)
+    {
+        private IDisposable? _backgroundOverride = backgroundOverride;
+
+        public Border Cell { get; } = cell;
+        public ScaleTransform Transform { get; } = transform;
+        public long StartTimestamp { get; } = startTimestamp;
+        public double DurationSeconds { get; } = durationSeconds;
+
+        public void ReleaseBackgroundOverride()
+        {
+            _backgroundOverride?.Dispose();
+            _backgroundOverride = null;
+        }
+
+        public void Complete()
+        {
+            ReleaseBackgroundOverride();
+            Transform.ScaleX = 1;
+        }
+    }
+
     private sealed record ActiveDownShiftAnimation(
         Control Row,
         TranslateTransform Transform,
@@ -0,0 +1,219 @@
+using Avalonia;
+using Avalonia.Media;
+using Avalonia.Media.Imaging;
+using Pyrite.Models;
+using Pyrite.Services;
+using System;
+using System.Collections.Generic;
+using System.Globalization;
+using System.IO;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     Draws a 1200×630 PNG card per team (the common link-preview size of social networks) in the presentation
+///     colors: logo, rank, team and organization, solved and penalty, award citations, and one cell per problem
+///     colored like the scoreboard.
+/// </summary>
+internal static class ResultCardRenderer
+{
+    private const int Width = 1200;
+    private const int Height = 630;
+    private const double Margin = 48;
+    private const double LogoSize = 220;
+    private const double CellGap = 8;
+    private const double CellMaxWidth = 96;
+    private const double CellHeight = 96;
+
+    public static void WriteAll(
+        string contestName,
+        IReadOnlyList<Problem> problems,
+        IReadOnlyList<ResultCard> cards,
+        PresentationConfig presentation,
+        string outputDirectory)
+    {
+        Directory.CreateDirectory(outputDirectory);
+        using var target = new RenderTargetBitmap(new PixelSize(Width, Height));
+        foreach (var card in cards)
+        {
+            using var logo = LoadLogo(card.LogoPath, presentation.RecordingFfmpegPath);
+            using (var context = target.CreateDrawingContext())
+            {
+                DrawCard(context, contestName, problems, card, presentation.Colors, logo);
+            }
+
+            target.Save(Path.Combine(outputDirectory, card.FileName));
+        }
+    }
+
+    private static void DrawCard(
+        DrawingContext context,
+        string contestName,
+        IReadOnlyList<Problem> problems,
+        ResultCard card,
+        PresentationColorsConfig colors,
+        Bitmap? logo)
+    {
+        var textBrush = Brush(colors.Text);
+        context.FillRectangle(Brush(colors.Background), new Rect(0, 0, Width, Height));
+        var tint = card.MedalColor switch
+        {
+            "gold" => colors.GoldTint,
+            "silver" => colors.SilverTint,
+            "bronze" => colors.BronzeTint,
+            _ => null
+        };
+        if (tint is not null)
+        {
+            context.FillRectangle(Brush(tint), new Rect(0, 0, Width, Height));
+        }
+
+        context.FillRectangle(Brush(colors.HeaderBackground), new Rect(0, 0, Width, 72));
+        DrawText(context, contestName, 26, FontWeight.Normal, textBrush, new Point(Margin, 20), Width - 2 * Margin);
+
+        var top = 110.0;
+        if (logo is not null)
+        {
+            var scale = Math.Min(LogoSize / logo.Size.Width, LogoSize / logo.Size.Height);
+            var logoWidth = logo.Size.Width * scale;
+            var logoHeight = logo.Size.Height * scale;
+            context.DrawImage(logo, new Rect(
+                Margin + (LogoSize - logoWidth) / 2,
+                top + (LogoSize - logoHeight) / 2,
+                logoWidth,
+                logoHeight));
+        }
+
+        var left = Margin + LogoSize + 40;
+        var textWidth = Width - left - Margin;
+        var rank = card.Rank is { } value
+            ? $"#{value.ToString(CultureInfo.InvariantCulture)}"
+            : Localizer.Instance["result_card.unofficial"];
+        var y = top - 12;
+        y += DrawText(context, rank, 72, FontWeight.Bold, textBrush, new Point(left, y), textWidth);
+        y += DrawText(context, card.TeamName, 44, FontWeight.Bold, textBrush, new Point(left, y), textWidth);
+        if (!string.IsNullOrWhiteSpace(card.OrganizationName))
+        {
+            y += DrawText(context, card.OrganizationName, 28, FontWeight.Normal, textBrush, new Point(left, y), textWidth);
+        }
+
+        var summary = string.Format(
+            CultureInfo.CurrentCulture,
+            Localizer.Instance["result_card.summary"],
+            card.Solved,
+            card.Penalty);
+        y += 8 + DrawText(context, summary, 30, FontWeight.SemiBold, Brush(colors.Focus), new Point(left, y + 8), textWidth);
+        if (card.Citations.Count > 0)
+        {
+            DrawText(context, string.Join(" · ", card.Citations), 26, FontWeight.Normal, textBrush, new Point(left, y + 4),
+                textWidth);
+        }
+
+        DrawProblemRow(context, problems, card, colors);
+    }
+
+    private static void DrawProblemRow(
+        DrawingContext context,
+        IReadOnlyList<Problem> problems,
+        ResultCard card,
+        PresentationColorsConfig colors)
+    {
+        if (problems.Count == 0)
+        {
+            return;
+        }
+
+        var cellWidth = Math.Min(CellMaxWidth, (Width - 2 * Margin - CellGap * (problems.Count - 1)) / problems.Count);
+        var rowWidth = cellWidth * problems.Count + CellGap * (problems.Count - 1);
+        var x = (Width - rowWidth) / 2;
+        var y = Height - Margin - CellHeight;
+        var labelSize = Math.Min(32, cellWidth * 0.45);
+        var resultSize = Math.Min(20, cellWidth * 0.28);
+        for (var index = 0; index < problems.Count; index++)
+        {
+            var cell = index < card.Cells.Count ? card.Cells[index] : new StandingsCell(false, 0, null, false);
+            var fill = cell.IsFirstSolve ? colors.FirstSolve
+                : cell.Solved ? colors.Solved
+                : cell.Attempted ? colors.Rejected
+                : colors.Untouched;
+            var bounds = new Rect(x, y, cellWidth, CellHeight);
+            context.DrawRectangle(Brush(fill), null, bounds, 8, 8);
+            if (PresentationColorsConfig.TryNormalizeHexColor(problems[index].Rgb, out var balloon))
+            {
+                context.FillRectangle(Brush(balloon), new Rect(x + 8, y + 6, cellWidth - 16, 5));
+            }
+
+            DrawCentered(context, StandingsExport.GetProblemLabel(problems[index]), labelSize, FontWeight.Bold,
+                Brush(colors.Text), new Point(x + cellWidth / 2, y + 16));
+            if (cell.Attempted)
+            {
+                DrawCentered(context, cell.ToResultText(), resultSize, FontWeight.Normal, Brush(colors.Text),
+                    new Point(x + cellWidth / 2, y + CellHeight - resultSize - 16));
+            }
+
+            x += cellWidth + CellGap;
+        }
+    }
+
+    /// <summary>
+    ///     Draws one line, cut with an ellipsis at <paramref name="maxWidth" />, and returns its height.
+    /// </summary>
+    private static double DrawText(
+        DrawingContext context,
+        string text,
+        double size,
+        FontWeight weight,
+        IBrush brush,
+        Point origin,
+        double maxWidth)
+    {
+        var formatted = Format(text, size, weight, brush);
+        formatted.MaxTextWidth = maxWidth;
+        formatted.MaxLineCount = 1;
+        formatted.Trimming = TextTrimming.CharacterEllipsis;
+        context.DrawText(formatted, origin);
+        return formatted.Height;
+    }
+
+    private static void DrawCentered(DrawingContext context, string text, double size, FontWeight weight, IBrush brush,
+        Point topCenter)
+    {
+        var formatted = Format(text, size, weight, brush);
+        context.DrawText(formatted, new Point(topCenter.X - formatted.WidthIncludingTrailingWhitespace / 2, topCenter.Y));
+    }
+
+    private static FormattedText Format(string text, double size, FontWeight weight, IBrush brush)
+    {
+        return new FormattedText(
+            text,
+            CultureInfo.CurrentCulture,
+            FlowDirection.LeftToRight,
+            new Typeface(FontFamily.Default, FontStyle.Normal, weight),
+            size,
+            brush);
+    }
+
+    private static IBrush Brush(string color)
+    {
+        return new SolidColorBrush(Color.Parse(color));
+    }
+
+    private static Bitmap? LoadLogo(string? path, string? ffmpegPath)
+    {
+        if (path is null)
+        {
+            return null;
+        }
+
+        try
+        {
+            using var stream = ImageFiles.OpenRead(path, ffmpegPath);
+            return Bitmap.DecodeToWidth(stream, (int)LogoSize * 2, BitmapInterpolationMode.HighQuality);
+        }
+        catch (Exception)
+        {
+            // A broken logo should not cost the team its card.
+            return null;
+        }
+    }
+}
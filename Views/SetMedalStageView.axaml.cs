@@ -1,17 +1,46 @@
+using Avalonia;
 using Avalonia.Controls;
+using Avalonia.Input;
 using Avalonia.Interactivity;
 using Avalonia.Platform.Storage;
+using Avalonia.VisualTree;
 using Pyrite.ViewModels;
 using System;
 using System.Linq;
+using System.Threading.Tasks;
 
 namespace Pyrite.Views;
 
 public partial class SetMedalStageView : UserControl
 {
+    private const string MedalCardClass = "medalCard";
+    private const double DraggedMedalCardOpacity = 0.5;
+
+    private Border? _draggedMedalCard;
+
     public SetMedalStageView()
     {
         InitializeComponent();
+        KeyDown += OnKeyDown;
+    }
+
+    private void OnKeyDown(object? sender, KeyEventArgs e)
+    {
+        // Focused text boxes handle Ctrl+Z themselves, so this only sees the shortcut outside them.
+        if (DataContext is not SetMedalStageViewModel viewModel || !e.KeyModifiers.HasFlag(KeyModifiers.Control)) return;
+
+        switch (e.Key)
+        {
+            case Key.Z when e.KeyModifiers.HasFlag(KeyModifiers.Shift):
+            case Key.Y:
+                viewModel.RedoAwardsCommand.Execute(null);
+                e.Handled = true;
+                break;
+            case Key.Z:
+                viewModel.UndoAwardsCommand.Execute(null);
+                e.Handled = true;
+                break;
+        }
     }
 
     private async void OnSaveMedalsClick(object? sender, RoutedEventArgs e)
@@ -48,6 +77,287 @@ public partial class SetMedalStageView : UserControl
         }
     }
 
+    private async void OnExportClicsAwardsClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var topLevel = TopLevel.GetTopLevel(this);
+        if (topLevel?.StorageProvider is null) return;
+
+        var file = await topLevel.StorageProvider.SaveFilePickerAsync(new FilePickerSaveOptions
+        {
+            Title = "Export CLICS Awards",
+            SuggestedFileName = "awards",
+            DefaultExtension = "json",
+            FileTypeChoices =
+            [
+                new FilePickerFileType("JSON")
+                {
+                    Patterns = ["*.json"]
+                }
+            ]
+        });
+
+        var localPath = file?.TryGetLocalPath();
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportClicsAwardsToFile(localPath);
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export awards to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportCsvClick(object? sender, RoutedEventArgs e)
+    {
+        await ExportStandingsAsync("csv", "CSV", ',');
+    }
+
+    private async void OnExportTsvClick(object? sender, RoutedEventArgs e)
+    {
+        await ExportStandingsAsync("tsv", "TSV", '\t');
+    }
+
+    private async Task ExportStandingsAsync(string extension, string typeName, char separator)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var localPath = await PickSavePathAsync($"Export Standings ({typeName})", "standings", extension, typeName);
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportStandings(localPath, separator);
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export standings to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportXlsxClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var localPath = await PickSavePathAsync("Export Standings (Excel)", "standings", "xlsx", "Excel Workbook");
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportStandingsXlsx(localPath);
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export standings to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportHtmlClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var localPath = await PickSavePathAsync("Export HTML Scoreboard", "scoreboard", "html", "HTML");
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportHtmlScoreboard(localPath);
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export scoreboard to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportPyriteResultClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var localPath = await PickSavePathAsync("Export Results JSON", "results", "json", "JSON");
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportPyriteResult(localPath);
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export results to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportResolverScriptClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var localPath = await PickSavePathAsync("Export Resolver Script", "resolver-script", "json", "JSON");
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportResolverScript(localPath);
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export resolver script to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportEventFeedClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var localPath = await PickSavePathAsync("Export Event Feed", "event-feed-filtered", "ndjson", "NDJSON");
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportEventFeed(localPath);
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export event feed to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportStatisticsMarkdownClick(object? sender, RoutedEventArgs e)
+    {
+        await ExportStatisticsAsync("md", "Markdown");
+    }
+
+    private async void OnExportStatisticsHtmlClick(object? sender, RoutedEventArgs e)
+    {
+        await ExportStatisticsAsync("html", "HTML");
+    }
+
+    private async Task ExportStatisticsAsync(string extension, string typeName)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var localPath = await PickSavePathAsync($"Export Statistics Report ({typeName})", "statistics", extension,
+            typeName);
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportStatisticsReport(localPath, asHtml: extension == "html");
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export statistics to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportIcpcResultsClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var topLevel = TopLevel.GetTopLevel(this);
+        if (topLevel?.StorageProvider is null) return;
+
+        var folders = await topLevel.StorageProvider.OpenFolderPickerAsync(new FolderPickerOpenOptions
+        {
+            Title = "Export ICPC Results",
+            AllowMultiple = false
+        });
+
+        var localPath = folders.FirstOrDefault()?.TryGetLocalPath();
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+
+        try
+        {
+            viewModel.ExportIcpcResults(localPath);
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export ICPC results to {localPath}: {ex.Message}");
+        }
+    }
+
+    private async void OnExportResultCardsClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        var topLevel = TopLevel.GetTopLevel(this);
+        if (topLevel?.StorageProvider is null) return;
+
+        var folders = await topLevel.StorageProvider.OpenFolderPickerAsync(new FolderPickerOpenOptions
+        {
+            Title = "Export Result Cards",
+            AllowMultiple = false
+        });
+
+        var localPath = folders.FirstOrDefault()?.TryGetLocalPath();
+        if (string.IsNullOrWhiteSpace(localPath)) return;
+        if (!viewModel.TryPrepareResultCards(out var contestName, out var problems, out var presentation, out var cards)) return;
+
+        try
+        {
+            ResultCardRenderer.WriteAll(contestName, problems, cards, presentation, localPath);
+            viewModel.SetStatusMessage($"Wrote {cards.Count} result card(s) to {localPath}");
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to export result cards to {localPath}: {ex.Message}");
+        }
+    }
+
+    private void OnGenerateCertificatesClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+        if (!viewModel.TryPrepareCertificates(out var config, out var templatePath, out var outputDirectory,
+                out var entries)) return;
+
+        try
+        {
+            CertificateRenderer.WriteAll(templatePath, config, entries, outputDirectory);
+            viewModel.SetStatusMessage($"Wrote {entries.Count} certificate(s) to {outputDirectory}");
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to generate certificates: {ex.Message}");
+        }
+    }
+
+    private async void OnPushAwardsToContestApiClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        try
+        {
+            await viewModel.PushAwardsToContestApiAsync();
+        }
+        catch (Exception ex)
+        {
+            viewModel.SetStatusMessage($"Failed to push awards to the Contest API: {ex.Message}");
+        }
+    }
+
+    private async Task<string?> PickSavePathAsync(string title, string suggestedName, string extension, string typeName)
+    {
+        var topLevel = TopLevel.GetTopLevel(this);
+        if (topLevel?.StorageProvider is null) return null;
+
+        var file = await topLevel.StorageProvider.SaveFilePickerAsync(new FilePickerSaveOptions
+        {
+            Title = title,
+            SuggestedFileName = suggestedName,
+            DefaultExtension = extension,
+            FileTypeChoices =
+            [
+                new FilePickerFileType(typeName)
+                {
+                    Patterns = [$"*.{extension}"]
+                }
+            ]
+        });
+
+        return file?.TryGetLocalPath();
+    }
+
     private async void OnLoadMedalsClick(object? sender, RoutedEventArgs e)
     {
         if (DataContext is not SetMedalStageViewModel viewModel) return;
@@ -89,4 +399,81 @@ public partial class SetMedalStageView : UserControl
 
         viewModel.DeleteMedalCommand.Execute(medalId);
     }
+
+    private void OnMedalCardPointerPressed(object? sender, PointerPressedEventArgs e)
+    {
+        if (sender is not Border { Tag: string } card || !e.GetCurrentPoint(card).Properties.IsLeftButtonPressed) return;
+
+        _draggedMedalCard = card;
+        card.Opacity = DraggedMedalCardOpacity;
+        e.Pointer.Capture(card);
+        e.Handled = true;
+    }
+
+    private void OnMedalCardPointerReleased(object? sender, PointerReleasedEventArgs e)
+    {
+        if (_draggedMedalCard is not { Tag: string medalId } card) return;
+
+        EndMedalCardDrag();
+        e.Pointer.Capture(null);
+        e.Handled = true;
+
+        // The pressed card holds the capture, so find the card under the pointer by hit testing.
+        var target = this.InputHitTest(e.GetPosition(this)) as Visual;
+        var targetCard = target?.GetSelfAndVisualAncestors()
+            .OfType<Border>()
+            .FirstOrDefault(x => x.Classes.Contains(MedalCardClass));
+        if (targetCard is not { Tag: string targetMedalId } || ReferenceEquals(targetCard, card)) return;
+
+        if (DataContext is SetMedalStageViewModel viewModel) viewModel.MoveMedal(medalId, targetMedalId);
+    }
+
+    private void OnMedalCardPointerCaptureLost(object? sender, PointerCaptureLostEventArgs e)
+    {
+        EndMedalCardDrag();
+    }
+
+    private void EndMedalCardDrag()
+    {
+        if (_draggedMedalCard is null) return;
+
+        _draggedMedalCard.Opacity = 1;
+        _draggedMedalCard = null;
+    }
+
+    private void OnEditMedalClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        if (sender is not Button { Tag: string medalId } || string.IsNullOrWhiteSpace(medalId)) return;
+
+        viewModel.EditMedalCommand.Execute(medalId);
+    }
+
+    private void OnRemoveEditedMedalTeamClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        if (sender is not Button { Tag: string teamId } || string.IsNullOrWhiteSpace(teamId)) return;
+
+        viewModel.RemoveEditedMedalTeamCommand.Execute(teamId);
+    }
+
+    private void OnUseFeedAwardClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        if (sender is not Button { Tag: string conflictKey } || string.IsNullOrWhiteSpace(conflictKey)) return;
+
+        viewModel.UseFeedAwardCommand.Execute(conflictKey);
+    }
+
+    private void OnUseLocalAwardClick(object? sender, RoutedEventArgs e)
+    {
+        if (DataContext is not SetMedalStageViewModel viewModel) return;
+
+        if (sender is not Button { Tag: string conflictKey } || string.IsNullOrWhiteSpace(conflictKey)) return;
+
+        viewModel.UseLocalAwardCommand.Execute(conflictKey);
+    }
 }
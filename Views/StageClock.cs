@@ -0,0 +1,55 @@
+using System;
+using System.Diagnostics;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     The time the stage animations run on, in <see cref="Stopwatch" /> ticks. It follows the system clock, except
+///     while a <see cref="FrameRecorder" /> holds it: then it only moves when the recorder renders a frame, by exactly
+///     one frame, so the video shows every animation at its configured duration however slow rendering is.
+/// </summary>
+internal static class StageClock
+{
+    private static long _offset;
+    private static long? _heldTimestamp;
+
+    /// <summary>
+    ///     Raised after <see cref="Advance" />, so running animations can update before the frame is rendered.
+    /// </summary>
+    public static event EventHandler? Advanced;
+
+    public static long GetTimestamp()
+    {
+        return _heldTimestamp ?? Stopwatch.GetTimestamp() + _offset;
+    }
+
+    public static void Hold()
+    {
+        _heldTimestamp = GetTimestamp();
+    }
+
+    public static void Advance(double seconds)
+    {
+        if (_heldTimestamp is null)
+        {
+            return;
+        }
+
+        _heldTimestamp += (long)Math.Round(seconds * Stopwatch.Frequency);
+        Advanced?.Invoke(null, EventArgs.Empty);
+    }
+
+    /// <summary>
+    ///     Lets the clock run again from the held time, so animations in flight continue instead of jumping.
+    /// </summary>
+    public static void Release()
+    {
+        if (_heldTimestamp is not { } held)
+        {
+            return;
+        }
+
+        _offset = held - Stopwatch.GetTimestamp();
+        _heldTimestamp = null;
+    }
+}
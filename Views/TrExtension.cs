@@ -0,0 +1,58 @@
+using Avalonia;
+using Avalonia.Markup.Xaml;
+using Pyrite.Services;
+using System;
+using System.Collections.Generic;
+
+namespace Pyrite.Views;
+
+/// <summary>
+///     <c>{views:Tr key}</c> markup extension: sets the localized string for <see cref="Key" /> and re-applies it
+///     whenever <see cref="Localizer.LanguageChanged" /> fires. Targets are held weakly so closed windows are not kept
+///     alive.
+/// </summary>
+public sealed class TrExtension : MarkupExtension
+{
+    private static readonly List<(WeakReference<AvaloniaObject> Target, AvaloniaProperty Property, string Key)> Targets = [];
+
+    static TrExtension()
+    {
+        Localizer.Instance.LanguageChanged += OnLanguageChanged;
+    }
+
+    public TrExtension(string key)
+    {
+        Key = key;
+    }
+
+    public string Key { get; set; }
+
+    public override object ProvideValue(IServiceProvider serviceProvider)
+    {
+        if (serviceProvider.GetService(typeof(IProvideValueTarget)) is IProvideValueTarget
+            {
+                TargetObject: AvaloniaObject target,
+                TargetProperty: AvaloniaProperty property
+            })
+        {
+            Targets.Add((new WeakReference<AvaloniaObject>(target), property, Key));
+        }
+
+        return Localizer.Instance[Key];
+    }
+
+    private static void OnLanguageChanged()
+    {
+        for (var i = Targets.Count - 1; i >= 0; i--)
+        {
+            var (reference, property, key) = Targets[i];
+            if (!reference.TryGetTarget(out var target))
+            {
+                Targets.RemoveAt(i);
+                continue;
+            }
+
+            target.SetValue(property, Localizer.Instance[key]);
+        }
+    }
+}
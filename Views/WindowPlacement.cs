@@ -0,0 +1,106 @@
+using Avalonia;
+using Avalonia.Controls;
+using Avalonia.Platform;
+using Pyrite.Models;
+using System;
+using System.Collections.Generic;
+using System.Diagnostics;
+using System.Linq;
+
+namespace Pyrite.Views;
+
+internal static class WindowPlacement
+{
+    public static void ToggleFullscreen(Window window)
+    {
+        window.WindowState = window.WindowState == WindowState.FullScreen
+            ? WindowState.Normal
+            : WindowState.FullScreen;
+    }
+
+    public static void ToggleBorderless(Window window)
+    {
+        if (window.SystemDecorations == SystemDecorations.None)
+        {
+            window.SystemDecorations = SystemDecorations.Full;
+            window.WindowState = WindowState.Normal;
+            return;
+        }
+
+        window.SystemDecorations = SystemDecorations.None;
+        window.WindowState = WindowState.Maximized;
+    }
+
+    public static void ApplyPresentationConfig(Window window, PresentationConfig config)
+    {
+        if (config.WindowWidth is { } width)
+        {
+            window.Width = width;
+        }
+
+        if (config.WindowHeight is { } height)
+        {
+            window.Height = height;
+        }
+
+        window.CanResize = config.WindowResizable;
+
+        if (config.Monitor is { } monitor)
+        {
+            MoveToScreen(window, monitor);
+        }
+    }
+
+    public static void MoveToNextScreen(Window window)
+    {
+        var screens = window.Screens.All;
+        if (screens.Count <= 1)
+        {
+            return;
+        }
+
+        var current = window.Screens.ScreenFromPoint(window.Position);
+        var currentIndex = current is null ? -1 : IndexOf(screens, current);
+        MoveToScreen(window, (currentIndex + 1) % screens.Count);
+    }
+
+    public static void MoveToScreen(Window window, int screenIndex)
+    {
+        var screens = window.Screens.All;
+        if (screenIndex < 0 || screenIndex >= screens.Count)
+        {
+            Trace.WriteLine($"[WindowPlacement] Screen index {screenIndex} out of range (screens={screens.Count}).");
+            return;
+        }
+
+        var target = screens[screenIndex];
+        var previousState = window.WindowState;
+
+        // Platforms ignore position changes while maximized/fullscreen, so restore first and re-apply afterwards.
+        if (previousState != WindowState.Normal)
+        {
+            window.WindowState = WindowState.Normal;
+        }
+
+        var workingArea = target.WorkingArea;
+        var width = (int)Math.Round(window.ClientSize.Width * target.Scaling);
+        var height = (int)Math.Round(window.ClientSize.Height * target.Scaling);
+        var x = workingArea.X + Math.Max(0, (workingArea.Width - width) / 2);
+        var y = workingArea.Y + Math.Max(0, (workingArea.Height - height) / 2);
+        window.Position = new PixelPoint(x, y);
+
+        if (previousState != WindowState.Normal)
+        {
+            window.WindowState = previousState;
+        }
+
+        Trace.WriteLine($"[WindowPlacement] Moved window to screen {screenIndex} at {window.Position}.");
+    }
+
+    private static int IndexOf(IReadOnlyList<Screen> screens, Screen screen)
+    {
+        var match = screens.Select((item, index) => (item, index))
+            .FirstOrDefault(x => x.item.Bounds == screen.Bounds);
+        return match.item is null ? -1 : match.index;
+    }
+}